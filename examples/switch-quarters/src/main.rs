@@ -14,7 +14,7 @@ async fn main() {
     let cse100_fa23 = wrapper
         .req("WI24")
         .parsed()
-        .get_course_info("CSE", "100")
+        .get_course_info(("CSE", "100"))
         .await;
 
     match cse100_fa23 {
@@ -32,7 +32,7 @@ async fn main() {
     let cse100_s223 = wrapper
         .req("S223")
         .parsed()
-        .get_course_info("CSE", "100")
+        .get_course_info(("CSE", "100"))
         .await;
 
     match cse100_s223 {
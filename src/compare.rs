@@ -0,0 +1,324 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+use crate::types::{self, MeetingDay, ScheduledSection, SectionId, TimeType};
+
+/// The order that days appear in a week, using the same abbreviations as WebReg.
+const WEEK_DAYS: [&str; 7] = ["M", "Tu", "W", "Th", "F", "Sa", "Su"];
+
+/// A section in a schedule, with just the information needed to compare it against a
+/// section in another schedule.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct ScheduleEntry {
+    /// The subject, course ID. For example, `CSE 100`.
+    pub subj_course_id: String,
+    /// The section ID. For example, `079912`.
+    pub section_id: SectionId,
+    /// The section code. For example, `B01`.
+    pub section_code: String,
+    /// The grading option. This can be one of `L`, `P`, or `S`.
+    pub grade_option: String,
+    /// The number of units.
+    pub units: i64,
+}
+
+impl From<&ScheduledSection> for ScheduleEntry {
+    fn from(section: &ScheduledSection) -> Self {
+        Self {
+            subj_course_id: format!("{} {}", section.subject_code, section.course_code),
+            section_id: section.section_id,
+            section_code: section.section_code.clone(),
+            grade_option: section.grade_option.clone(),
+            units: section.units,
+        }
+    }
+}
+
+impl Display for ScheduleEntry {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{} {} / {}] {} units, {}",
+            self.subj_course_id, self.section_code, self.section_id, self.units, self.grade_option
+        )
+    }
+}
+
+/// A section that is in both schedules, but with a different grading option or number of
+/// units.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct EntryDifference {
+    /// The section, as it appears in the first schedule.
+    pub a: ScheduleEntry,
+    /// The section, as it appears in the second schedule.
+    pub b: ScheduleEntry,
+}
+
+impl Display for EntryDifference {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{} {} / {}]",
+            self.a.subj_course_id, self.a.section_code, self.a.section_id
+        )?;
+        if self.a.grade_option != self.b.grade_option {
+            write!(
+                f,
+                " Grade Option: {} -> {}",
+                self.a.grade_option, self.b.grade_option
+            )?;
+        }
+
+        if self.a.units != self.b.units {
+            write!(f, " Units: {} -> {}", self.a.units, self.b.units)?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Statistics about a single schedule.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct ScheduleStats {
+    /// The total number of units.
+    pub total_units: i64,
+    /// The days (e.g., `M`, `Tu`) with at least one weekly meeting, in week order.
+    pub days_on_campus: Vec<String>,
+    /// The earliest start time of any weekly meeting (e.g., `8:00`), if any.
+    pub earliest_start: Option<String>,
+    /// The latest end time of any weekly meeting (e.g., `21:50`), if any.
+    pub latest_end: Option<String>,
+    /// The number of final exams on each date (in the form `YYYY-MM-DD`).
+    pub finals_per_day: BTreeMap<String, usize>,
+}
+
+impl ScheduleStats {
+    /// Computes the statistics of a schedule.
+    ///
+    /// Only weekly meetings (e.g., lectures and discussions) count towards the days on
+    /// campus and the earliest and latest times; one-time meetings like midterms and finals
+    /// do not.
+    ///
+    /// # Parameters
+    /// - `schedule`: The schedule.
+    ///
+    /// # Returns
+    /// The statistics.
+    pub fn new(schedule: &[ScheduledSection]) -> Self {
+        let mut days = BTreeSet::new();
+        let mut earliest: Option<(TimeType, TimeType)> = None;
+        let mut latest: Option<(TimeType, TimeType)> = None;
+        let mut finals_per_day = BTreeMap::new();
+
+        for meeting in schedule.iter().flat_map(|s| s.meetings.iter()) {
+            match &meeting.meeting_days {
+                MeetingDay::Repeated(r) if !r.is_empty() => {
+                    days.extend(
+                        r.iter()
+                            .filter_map(|d| WEEK_DAYS.iter().position(|w| w == d)),
+                    );
+
+                    let start = (meeting.start_hr, meeting.start_min);
+                    let end = (meeting.end_hr, meeting.end_min);
+                    earliest = Some(earliest.map_or(start, |e| e.min(start)));
+                    latest = Some(latest.map_or(end, |l| l.max(end)));
+                }
+                MeetingDay::OneTime(date) if meeting.meeting_type == "FI" => {
+                    *finals_per_day.entry(date.to_string()).or_insert(0) += 1;
+                }
+                _ => {}
+            }
+        }
+
+        Self {
+            total_units: schedule.iter().map(|s| s.units).sum(),
+            days_on_campus: days.into_iter().map(|d| WEEK_DAYS[d].to_string()).collect(),
+            earliest_start: earliest.map(|(h, m)| format!("{h}:{m:02}")),
+            latest_end: latest.map(|(h, m)| format!("{h}:{m:02}")),
+            finals_per_day,
+        }
+    }
+}
+
+impl Display for ScheduleStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "\tTotal Units: {}", self.total_units)?;
+        if self.days_on_campus.is_empty() {
+            writeln!(f, "\tDays on Campus: N/A")?;
+        } else {
+            writeln!(f, "\tDays on Campus: {}", self.days_on_campus.join(", "))?;
+        }
+
+        writeln!(
+            f,
+            "\tEarliest Start: {}",
+            self.earliest_start.as_deref().unwrap_or("N/A")
+        )?;
+        writeln!(
+            f,
+            "\tLatest End: {}",
+            self.latest_end.as_deref().unwrap_or("N/A")
+        )?;
+        write!(f, "\tFinals:")?;
+        if self.finals_per_day.is_empty() {
+            write!(f, " N/A")?;
+        }
+
+        for (date, count) in &self.finals_per_day {
+            write!(f, " {date} ({count})")?;
+        }
+
+        writeln!(f)
+    }
+}
+
+/// A side-by-side comparison of two schedules.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct ScheduleComparison {
+    /// The name of the first schedule.
+    pub name_a: String,
+    /// The name of the second schedule.
+    pub name_b: String,
+    /// Sections that are only in the first schedule.
+    pub only_in_a: Vec<ScheduleEntry>,
+    /// Sections that are only in the second schedule.
+    pub only_in_b: Vec<ScheduleEntry>,
+    /// Sections that are in both schedules, but with a different grading option or number
+    /// of units.
+    pub different: Vec<EntryDifference>,
+    /// Statistics about the first schedule.
+    pub stats_a: ScheduleStats,
+    /// Statistics about the second schedule.
+    pub stats_b: ScheduleStats,
+}
+
+impl Display for ScheduleComparison {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Only in {}:", self.name_a)?;
+        for entry in &self.only_in_a {
+            writeln!(f, "\t{entry}")?;
+        }
+
+        writeln!(f, "Only in {}:", self.name_b)?;
+        for entry in &self.only_in_b {
+            writeln!(f, "\t{entry}")?;
+        }
+
+        writeln!(f, "Different:")?;
+        for diff in &self.different {
+            writeln!(f, "\t{diff}")?;
+        }
+
+        writeln!(f, "{}:", self.name_a)?;
+        write!(f, "{}", self.stats_a)?;
+        writeln!(f, "{}:", self.name_b)?;
+        write!(f, "{}", self.stats_b)
+    }
+}
+
+/// Compares two schedules, for example two results of `get_schedule` for different
+/// schedule names.
+///
+/// Sections are matched by their section ID.
+///
+/// # Parameters
+/// - `name_a`: The name of the first schedule.
+/// - `a`: The first schedule.
+/// - `name_b`: The name of the second schedule.
+/// - `b`: The second schedule.
+///
+/// # Returns
+/// The comparison. All sections in the comparison are ordered by course and then by
+/// section code.
+pub fn compare_schedules(
+    name_a: impl Into<String>,
+    a: &[ScheduledSection],
+    name_b: impl Into<String>,
+    b: &[ScheduledSection],
+) -> ScheduleComparison {
+    let a_map = a
+        .iter()
+        .map(|s| (s.section_id, ScheduleEntry::from(s)))
+        .collect::<HashMap<_, _>>();
+    let b_map = b
+        .iter()
+        .map(|s| (s.section_id, ScheduleEntry::from(s)))
+        .collect::<HashMap<_, _>>();
+
+    let mut only_in_a = a_map
+        .iter()
+        .filter(|(id, _)| !b_map.contains_key(id))
+        .map(|(_, e)| e.clone())
+        .collect::<Vec<_>>();
+    let mut only_in_b = b_map
+        .iter()
+        .filter(|(id, _)| !a_map.contains_key(id))
+        .map(|(_, e)| e.clone())
+        .collect::<Vec<_>>();
+    let mut different = a_map
+        .iter()
+        .filter_map(|(id, a_entry)| {
+            let b_entry = b_map.get(id)?;
+            (a_entry.grade_option != b_entry.grade_option || a_entry.units != b_entry.units).then(
+                || EntryDifference {
+                    a: a_entry.clone(),
+                    b: b_entry.clone(),
+                },
+            )
+        })
+        .collect::<Vec<_>>();
+
+    only_in_a.sort_unstable_by(entry_order);
+    only_in_b.sort_unstable_by(entry_order);
+    different.sort_unstable_by(|x, y| entry_order(&x.a, &y.a));
+
+    ScheduleComparison {
+        name_a: name_a.into(),
+        name_b: name_b.into(),
+        only_in_a,
+        only_in_b,
+        different,
+        stats_a: ScheduleStats::new(a),
+        stats_b: ScheduleStats::new(b),
+    }
+}
+
+/// Renders the comparison as text.
+///
+/// # Parameters
+/// - `comparison`: The comparison.
+///
+/// # Returns
+/// The text.
+pub fn render_text(comparison: &ScheduleComparison) -> String {
+    comparison.to_string()
+}
+
+/// Renders the comparison as a JSON object.
+///
+/// # Parameters
+/// - `comparison`: The comparison.
+///
+/// # Returns
+/// The JSON string, or an error if the comparison could not be serialized.
+pub fn render_json(comparison: &ScheduleComparison) -> types::Result<String> {
+    Ok(serde_json::to_string(comparison)?)
+}
+
+/// The order that entries should appear in a comparison.
+///
+/// # Parameters
+/// - `x`: The first entry.
+/// - `y`: The second entry.
+///
+/// # Returns
+/// The ordering of the two entries, by course and then by section code.
+fn entry_order(x: &ScheduleEntry, y: &ScheduleEntry) -> std::cmp::Ordering {
+    (&x.subj_course_id, &x.section_code, x.section_id).cmp(&(
+        &y.subj_course_id,
+        &y.section_code,
+        y.section_id,
+    ))
+}
@@ -6,10 +6,18 @@ pub(crate) const STATUS_ENROLL: &str = "EN";
 pub(crate) const STATUS_WAITLIST: &str = "WT";
 pub(crate) const STATUS_PLANNED: &str = "PL";
 
+/// The default number of transports that a wrapper keeps for requests that override the
+/// cookies.
+pub(crate) const OVERRIDE_TRANSPORT_CACHE_SIZE: usize = 16;
+
+/// The number of courses to get information for at once when looking up many sections.
+pub(crate) const BATCH_CONCURRENCY: usize = 4;
+
 /// The default schedule name.
 pub(crate) const DEFAULT_SCHEDULE_NAME: &str = "My Schedule";
 
 // URLs for WebReg
+pub(crate) const WRADAPTER_BASE: &str = "https://act.ucsd.edu/webreg2/svc/wradapter/";
 pub(crate) const WEBREG_SEARCH: &str =
     "https://act.ucsd.edu/webreg2/svc/wradapter/secure/search-by-all?";
 pub(crate) const WEBREG_SEARCH_SEC: &str =
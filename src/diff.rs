@@ -0,0 +1,463 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::{Display, Formatter};
+
+use serde::Serialize;
+
+use crate::types::{self, CourseSection, Courses, Meeting, MeetingDay, SectionId};
+
+/// A change in a count (e.g., the number of students enrolled).
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+pub struct CountChange {
+    /// The old count.
+    pub old: i64,
+    /// The new count.
+    pub new: i64,
+    /// The difference between the new and old count (i.e., `new - old`).
+    pub delta: i64,
+}
+
+impl CountChange {
+    /// Creates a new count change.
+    ///
+    /// # Parameters
+    /// - `old`: The old count.
+    /// - `new`: The new count.
+    ///
+    /// # Returns
+    /// The count change.
+    pub fn new(old: i64, new: i64) -> Self {
+        Self {
+            old,
+            new,
+            delta: new - old,
+        }
+    }
+}
+
+impl Display for CountChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} -> {} ({:+})", self.old, self.new, self.delta)
+    }
+}
+
+/// The part of a meeting that changed.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MeetingField {
+    /// The day(s) that the meeting occurs on.
+    Days,
+    /// The start or end time of the meeting.
+    Time,
+    /// The building where the meeting is held.
+    Building,
+    /// The room where the meeting is held.
+    Room,
+    /// The instructors assigned to the meeting.
+    Instructors,
+}
+
+impl Display for MeetingField {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            MeetingField::Days => write!(f, "Days"),
+            MeetingField::Time => write!(f, "Time"),
+            MeetingField::Building => write!(f, "Building"),
+            MeetingField::Room => write!(f, "Room"),
+            MeetingField::Instructors => write!(f, "Instructors"),
+        }
+    }
+}
+
+/// The kind of change that happened to a section.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+#[serde(tag = "change", rename_all = "snake_case")]
+pub enum ChangeKind {
+    /// The section was added (i.e., it only appears in the new snapshot).
+    Added,
+    /// The section was canceled (i.e., it only appears in the old snapshot).
+    Canceled,
+    /// The number of students enrolled changed.
+    Enrolled(CountChange),
+    /// The number of available seats changed.
+    Available(CountChange),
+    /// The total number of seats changed.
+    TotalSeats(CountChange),
+    /// The number of students on the waitlist changed.
+    Waitlist(CountChange),
+    /// The instructors of the section changed.
+    Instructors { old: Vec<String>, new: Vec<String> },
+    /// A meeting was added to the section.
+    MeetingAdded { meeting: Meeting },
+    /// A meeting was removed from the section.
+    MeetingRemoved { meeting: Meeting },
+    /// Some part of a meeting changed.
+    MeetingChanged {
+        /// The meeting type (e.g., `LE`).
+        meeting_type: String,
+        /// What changed.
+        field: MeetingField,
+        /// The old value.
+        old: String,
+        /// The new value.
+        new: String,
+    },
+}
+
+/// A single change to a section between two snapshots.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct SectionChange {
+    /// The subject, course ID. For example, `CSE 100`.
+    pub subj_course_id: String,
+    /// The section ID. For example, `079912`.
+    pub section_id: SectionId,
+    /// The section code. For example, `B01`.
+    pub section_code: String,
+    /// What changed.
+    #[serde(flatten)]
+    pub kind: ChangeKind,
+}
+
+impl Display for SectionChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{} {} / {}] ",
+            self.subj_course_id, self.section_code, self.section_id
+        )?;
+        match &self.kind {
+            ChangeKind::Added => write!(f, "Section added"),
+            ChangeKind::Canceled => write!(f, "Section canceled"),
+            ChangeKind::Enrolled(c) => write!(f, "Enrolled: {c}"),
+            ChangeKind::Available(c) => write!(f, "Available: {c}"),
+            ChangeKind::TotalSeats(c) => write!(f, "Total Seats: {c}"),
+            ChangeKind::Waitlist(c) => write!(f, "Waitlist: {c}"),
+            ChangeKind::Instructors { old, new } => write!(
+                f,
+                "Instructors: [{}] -> [{}]",
+                old.join(", "),
+                new.join(", ")
+            ),
+            ChangeKind::MeetingAdded { meeting } => {
+                write!(f, "Meeting added: {}", meeting_summary(meeting))
+            }
+            ChangeKind::MeetingRemoved { meeting } => {
+                write!(f, "Meeting removed: {}", meeting_summary(meeting))
+            }
+            ChangeKind::MeetingChanged {
+                meeting_type,
+                field,
+                old,
+                new,
+            } => write!(f, "{meeting_type} {field}: {old} -> {new}"),
+        }
+    }
+}
+
+/// Summarizes a meeting on one line, without a trailing newline.
+///
+/// # Parameters
+/// - `meeting`: The meeting.
+///
+/// # Returns
+/// The summary.
+fn meeting_summary(meeting: &Meeting) -> String {
+    format!(
+        "[{}] {} at {} in {} {}",
+        meeting.meeting_type,
+        format_days(&meeting.meeting_days),
+        format_time(meeting),
+        meeting.building,
+        meeting.room
+    )
+}
+
+/// Formats the meeting days of a meeting.
+///
+/// # Parameters
+/// - `days`: The meeting days.
+///
+/// # Returns
+/// The formatted meeting days.
+fn format_days(days: &MeetingDay) -> String {
+    match days {
+        MeetingDay::Repeated(r) => r.join(""),
+        MeetingDay::OneTime(r) => r.to_string(),
+        MeetingDay::None => "N/A".to_string(),
+    }
+}
+
+/// Formats the start and end time of a meeting.
+///
+/// # Parameters
+/// - `meeting`: The meeting.
+///
+/// # Returns
+/// The formatted time range.
+fn format_time(meeting: &Meeting) -> String {
+    format!(
+        "{}:{:02} - {}:{:02}",
+        meeting.start_hr, meeting.start_min, meeting.end_hr, meeting.end_min
+    )
+}
+
+/// Computes the changes between two snapshots of the same course(s), for example two
+/// results of `get_course_info` taken at different times.
+///
+/// Sections are matched by their section ID. Meetings are matched by their meeting type and
+/// their position among meetings of that type (e.g., the second `MI` meeting of the old
+/// section is compared against the second `MI` meeting of the new section). If either
+/// snapshot of a section has no meetings at all (e.g., it came from `get_enrollment_count`),
+/// the meetings of that section are not compared.
+///
+/// # Parameters
+/// - `old`: The older snapshot.
+/// - `new`: The newer snapshot.
+///
+/// # Returns
+/// All changes, ordered by course and then by section code.
+pub fn diff_courses(old: &[CourseSection], new: &[CourseSection]) -> Vec<SectionChange> {
+    diff_sections(old.iter(), new.iter())
+}
+
+/// Computes the changes between two snapshots of a full catalog (i.e., the sections of
+/// many courses). See `diff_courses` for more information.
+///
+/// # Parameters
+/// - `old`: The older catalog, where each element is all sections of one course.
+/// - `new`: The newer catalog, where each element is all sections of one course.
+///
+/// # Returns
+/// All changes, ordered by course and then by section code.
+pub fn diff_catalogs<'a, I, J>(old: I, new: J) -> Vec<SectionChange>
+where
+    I: IntoIterator<Item = &'a Courses>,
+    J: IntoIterator<Item = &'a Courses>,
+{
+    diff_sections(
+        old.into_iter().flat_map(|c| c.iter()),
+        new.into_iter().flat_map(|c| c.iter()),
+    )
+}
+
+/// Renders the changes as text, one change per line.
+///
+/// # Parameters
+/// - `changes`: The changes.
+///
+/// # Returns
+/// The text. Each line, including the last one, ends with `\n`.
+pub fn render_text(changes: &[SectionChange]) -> String {
+    changes.iter().map(|c| format!("{c}\n")).collect()
+}
+
+/// Renders the changes as a JSON array.
+///
+/// # Parameters
+/// - `changes`: The changes.
+///
+/// # Returns
+/// The JSON string, or an error if the changes could not be serialized.
+pub fn render_json(changes: &[SectionChange]) -> types::Result<String> {
+    Ok(serde_json::to_string(changes)?)
+}
+
+/// Computes the changes between two sets of sections.
+///
+/// # Parameters
+/// - `old`: The older sections.
+/// - `new`: The newer sections.
+///
+/// # Returns
+/// All changes, ordered by course and then by section code.
+fn diff_sections<'a>(
+    old: impl Iterator<Item = &'a CourseSection>,
+    new: impl Iterator<Item = &'a CourseSection>,
+) -> Vec<SectionChange> {
+    let old_map = old.map(|s| (s.section_id, s)).collect::<HashMap<_, _>>();
+    let new_map = new.map(|s| (s.section_id, s)).collect::<HashMap<_, _>>();
+
+    // Group the changes by (course, section code, section ID) so that the output is in a
+    // stable, readable order.
+    let mut grouped: BTreeMap<(&str, &str, SectionId), Vec<SectionChange>> = BTreeMap::new();
+    for (id, old_sec) in &old_map {
+        let key = (
+            old_sec.subj_course_id.as_str(),
+            old_sec.section_code.as_str(),
+            *id,
+        );
+        match new_map.get(id) {
+            Some(new_sec) => {
+                let changes = diff_section(old_sec, new_sec);
+                if !changes.is_empty() {
+                    grouped.insert(
+                        (
+                            new_sec.subj_course_id.as_str(),
+                            new_sec.section_code.as_str(),
+                            *id,
+                        ),
+                        changes,
+                    );
+                }
+            }
+            None => {
+                grouped.insert(key, vec![change(old_sec, ChangeKind::Canceled)]);
+            }
+        }
+    }
+
+    for (id, new_sec) in &new_map {
+        if !old_map.contains_key(id) {
+            grouped.insert(
+                (
+                    new_sec.subj_course_id.as_str(),
+                    new_sec.section_code.as_str(),
+                    *id,
+                ),
+                vec![change(new_sec, ChangeKind::Added)],
+            );
+        }
+    }
+
+    grouped.into_values().flatten().collect()
+}
+
+/// Creates a change record for the specified section.
+///
+/// # Parameters
+/// - `section`: The section.
+/// - `kind`: The kind of change.
+///
+/// # Returns
+/// The change record.
+fn change(section: &CourseSection, kind: ChangeKind) -> SectionChange {
+    SectionChange {
+        subj_course_id: section.subj_course_id.clone(),
+        section_id: section.section_id,
+        section_code: section.section_code.clone(),
+        kind,
+    }
+}
+
+/// Computes the changes between two snapshots of the same section.
+///
+/// # Parameters
+/// - `old`: The older snapshot of the section.
+/// - `new`: The newer snapshot of the section.
+///
+/// # Returns
+/// All changes to this section.
+fn diff_section(old: &CourseSection, new: &CourseSection) -> Vec<SectionChange> {
+    let mut changes = vec![];
+    let counts = [
+        (
+            old.enrolled_ct,
+            new.enrolled_ct,
+            ChangeKind::Enrolled as fn(_) -> _,
+        ),
+        (
+            old.available_seats,
+            new.available_seats,
+            ChangeKind::Available,
+        ),
+        (old.total_seats, new.total_seats, ChangeKind::TotalSeats),
+        (old.waitlist_ct, new.waitlist_ct, ChangeKind::Waitlist),
+    ];
+
+    for (o, n, kind) in counts {
+        if o != n {
+            changes.push(change(new, kind(CountChange::new(o, n))));
+        }
+    }
+
+    if old.all_instructors != new.all_instructors {
+        changes.push(change(
+            new,
+            ChangeKind::Instructors {
+                old: old.all_instructors.clone(),
+                new: new.all_instructors.clone(),
+            },
+        ));
+    }
+
+    if old.meetings.is_empty() || new.meetings.is_empty() {
+        return changes;
+    }
+
+    // Key each meeting by its type and the number of meetings of the same type that came
+    // before it.
+    let key_meetings = |meetings: &'_ [Meeting]| {
+        let mut seen: HashMap<&str, usize> = HashMap::new();
+        meetings
+            .iter()
+            .map(|m| {
+                let idx = seen.entry(m.meeting_type.as_str()).or_default();
+                *idx += 1;
+                ((m.meeting_type.clone(), *idx), m.clone())
+            })
+            .collect::<BTreeMap<_, _>>()
+    };
+
+    let old_meetings = key_meetings(&old.meetings);
+    let new_meetings = key_meetings(&new.meetings);
+    for (key, old_m) in &old_meetings {
+        let new_m = match new_meetings.get(key) {
+            Some(m) => m,
+            None => {
+                changes.push(change(
+                    new,
+                    ChangeKind::MeetingRemoved {
+                        meeting: old_m.clone(),
+                    },
+                ));
+                continue;
+            }
+        };
+
+        let fields = [
+            (
+                MeetingField::Days,
+                format_days(&old_m.meeting_days),
+                format_days(&new_m.meeting_days),
+            ),
+            (MeetingField::Time, format_time(old_m), format_time(new_m)),
+            (
+                MeetingField::Building,
+                old_m.building.clone(),
+                new_m.building.clone(),
+            ),
+            (MeetingField::Room, old_m.room.clone(), new_m.room.clone()),
+            (
+                MeetingField::Instructors,
+                old_m.instructors.join(", "),
+                new_m.instructors.join(", "),
+            ),
+        ];
+
+        for (field, o, n) in fields {
+            if o != n {
+                changes.push(change(
+                    new,
+                    ChangeKind::MeetingChanged {
+                        meeting_type: key.0.clone(),
+                        field,
+                        old: o,
+                        new: n,
+                    },
+                ));
+            }
+        }
+    }
+
+    for (key, new_m) in &new_meetings {
+        if !old_meetings.contains_key(key) {
+            changes.push(change(
+                new,
+                ChangeKind::MeetingAdded {
+                    meeting: new_m.clone(),
+                },
+            ));
+        }
+    }
+
+    changes
+}
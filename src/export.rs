@@ -0,0 +1,483 @@
+use serde::Serialize;
+
+use crate::types::{
+    self, CourseSection, Courses, Meeting, MeetingDay, ScheduledSection, SectionId,
+};
+use crate::util;
+
+/// The column layout for section rows, in order.
+///
+/// | Column           | Description                                          |
+/// |------------------|------------------------------------------------------|
+/// | `timestamp`      | When the snapshot was taken (milliseconds since epoch). |
+/// | `term`           | The term (e.g., `FA23`).                             |
+/// | `subject_code`   | The subject code (e.g., `CSE`).                      |
+/// | `course_code`    | The course code (e.g., `100`).                       |
+/// | `section_id`     | The section ID (e.g., `079912`).                     |
+/// | `section_code`   | The section code (e.g., `A01`).                      |
+/// | `enrolled`       | The number of students enrolled.                     |
+/// | `available`      | The number of available seats.                       |
+/// | `waitlist`       | The number of students on the waitlist.              |
+/// | `total`          | The total number of seats.                           |
+/// | `instructors`    | All instructors, separated by `; `.                  |
+pub const SECTION_COLUMNS: [&str; 11] = [
+    "timestamp",
+    "term",
+    "subject_code",
+    "course_code",
+    "section_id",
+    "section_code",
+    "enrolled",
+    "available",
+    "waitlist",
+    "total",
+    "instructors",
+];
+
+/// The column layout for meeting rows, in order.
+///
+/// | Column           | Description                                          |
+/// |------------------|------------------------------------------------------|
+/// | `timestamp`      | When the snapshot was taken (milliseconds since epoch). |
+/// | `term`           | The term (e.g., `FA23`).                             |
+/// | `subject_code`   | The subject code (e.g., `CSE`).                      |
+/// | `course_code`    | The course code (e.g., `100`).                       |
+/// | `section_id`     | The section ID that this meeting belongs to.         |
+/// | `section_code`   | The section code that this meeting belongs to.       |
+/// | `meeting_type`   | The meeting type (e.g., `LE`, `DI`, `FI`).           |
+/// | `meeting_days`   | Either the days (e.g., `MWF`), a date (`YYYY-MM-DD`), or empty. |
+/// | `start_time`     | The start time, in `HH:MM` form.                     |
+/// | `end_time`       | The end time, in `HH:MM` form.                       |
+/// | `building`       | The building code (e.g., `CENTR`).                   |
+/// | `room`           | The room (e.g., `115`).                              |
+/// | `instructors`    | The instructors for this meeting, separated by `; `. |
+pub const MEETING_COLUMNS: [&str; 13] = [
+    "timestamp",
+    "term",
+    "subject_code",
+    "course_code",
+    "section_id",
+    "section_code",
+    "meeting_type",
+    "meeting_days",
+    "start_time",
+    "end_time",
+    "building",
+    "room",
+    "instructors",
+];
+
+/// The separator used when joining multiple instructors into one column.
+const INSTRUCTOR_SEPARATOR: &str = "; ";
+
+/// A row that can be exported.
+pub trait ExportRow: Serialize {
+    /// The names of the columns, in the order that `fields` returns them.
+    const COLUMNS: &'static [&'static str];
+
+    /// The values of this row, in the same order as `COLUMNS`.
+    ///
+    /// # Returns
+    /// The values of this row.
+    fn fields(&self) -> Vec<String>;
+}
+
+/// The context in which a snapshot was taken. This is attached to every exported row.
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct SnapshotContext {
+    /// When the snapshot was taken, in milliseconds since the Unix epoch.
+    pub timestamp: u128,
+    /// The term that the snapshot is for (e.g., `FA23`).
+    pub term: String,
+}
+
+impl SnapshotContext {
+    /// Creates a new snapshot context with the specified term and timestamp.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `timestamp`: When the snapshot was taken, in milliseconds since the Unix epoch.
+    ///
+    /// # Returns
+    /// The snapshot context.
+    pub fn new(term: impl Into<String>, timestamp: u128) -> Self {
+        Self {
+            timestamp,
+            term: term.into(),
+        }
+    }
+
+    /// Creates a new snapshot context with the specified term, using the current time as
+    /// the timestamp.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    ///
+    /// # Returns
+    /// The snapshot context.
+    pub fn now(term: impl Into<String>) -> Self {
+        Self::new(term, util::get_epoch_time())
+    }
+}
+
+/// A flattened section. See [`SECTION_COLUMNS`] for the column layout.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct SectionRow {
+    pub timestamp: u128,
+    pub term: String,
+    pub subject_code: String,
+    pub course_code: String,
+    pub section_id: String,
+    pub section_code: String,
+    pub enrolled: i64,
+    pub available: i64,
+    pub waitlist: i64,
+    pub total: i64,
+    pub instructors: String,
+}
+
+impl ExportRow for SectionRow {
+    const COLUMNS: &'static [&'static str] = &SECTION_COLUMNS;
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.timestamp.to_string(),
+            self.term.clone(),
+            self.subject_code.clone(),
+            self.course_code.clone(),
+            self.section_id.clone(),
+            self.section_code.clone(),
+            self.enrolled.to_string(),
+            self.available.to_string(),
+            self.waitlist.to_string(),
+            self.total.to_string(),
+            self.instructors.clone(),
+        ]
+    }
+}
+
+/// A flattened meeting. See [`MEETING_COLUMNS`] for the column layout.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct MeetingRow {
+    pub timestamp: u128,
+    pub term: String,
+    pub subject_code: String,
+    pub course_code: String,
+    pub section_id: String,
+    pub section_code: String,
+    pub meeting_type: String,
+    pub meeting_days: String,
+    pub start_time: String,
+    pub end_time: String,
+    pub building: String,
+    pub room: String,
+    pub instructors: String,
+}
+
+impl ExportRow for MeetingRow {
+    const COLUMNS: &'static [&'static str] = &MEETING_COLUMNS;
+
+    fn fields(&self) -> Vec<String> {
+        vec![
+            self.timestamp.to_string(),
+            self.term.clone(),
+            self.subject_code.clone(),
+            self.course_code.clone(),
+            self.section_id.clone(),
+            self.section_code.clone(),
+            self.meeting_type.clone(),
+            self.meeting_days.clone(),
+            self.start_time.clone(),
+            self.end_time.clone(),
+            self.building.clone(),
+            self.room.clone(),
+            self.instructors.clone(),
+        ]
+    }
+}
+
+/// Splits a subject, course ID (e.g., `CSE 100`) into its subject and course code.
+///
+/// # Parameters
+/// - `subj_course_id`: The subject, course ID.
+///
+/// # Returns
+/// A tuple containing the subject code and course code.
+fn split_subj_course_id(subj_course_id: &str) -> (String, String) {
+    match subj_course_id.trim().split_once(' ') {
+        Some((subj, crsc)) => (subj.trim().to_string(), crsc.trim().to_string()),
+        None => (subj_course_id.trim().to_string(), String::new()),
+    }
+}
+
+/// Formats the meeting days of a meeting into a single column value.
+///
+/// # Parameters
+/// - `meeting_days`: The meeting days.
+///
+/// # Returns
+/// The days joined together (e.g., `MWF`), the date for one-time meetings, or an empty
+/// string if there is no meeting.
+fn format_meeting_days(meeting_days: &MeetingDay) -> String {
+    match meeting_days {
+        MeetingDay::Repeated(days) => days.join(""),
+        MeetingDay::OneTime(date) => date.to_string(),
+        MeetingDay::None => String::new(),
+    }
+}
+
+/// Builds a meeting row for a meeting belonging to the specified section.
+///
+/// # Parameters
+/// - `ctx`: The snapshot context.
+/// - `subject_code`: The subject code of the section.
+/// - `course_code`: The course code of the section.
+/// - `section_id`: The section ID.
+/// - `section_code`: The section code.
+/// - `meeting`: The meeting.
+///
+/// # Returns
+/// The meeting row.
+fn meeting_row(
+    ctx: &SnapshotContext,
+    subject_code: &str,
+    course_code: &str,
+    section_id: SectionId,
+    section_code: &str,
+    meeting: &Meeting,
+) -> MeetingRow {
+    MeetingRow {
+        timestamp: ctx.timestamp,
+        term: ctx.term.clone(),
+        subject_code: subject_code.to_string(),
+        course_code: course_code.to_string(),
+        section_id: section_id.to_string(),
+        section_code: section_code.to_string(),
+        meeting_type: meeting.meeting_type.clone(),
+        meeting_days: format_meeting_days(&meeting.meeting_days),
+        start_time: format!("{:02}:{:02}", meeting.start_hr, meeting.start_min),
+        end_time: format!("{:02}:{:02}", meeting.end_hr, meeting.end_min),
+        building: meeting.building.clone(),
+        room: meeting.room.clone(),
+        instructors: meeting.instructors.join(INSTRUCTOR_SEPARATOR),
+    }
+}
+
+/// Flattens the specified sections (e.g., the result of `get_course_info` or
+/// `get_enrollment_count`) into section rows.
+///
+/// # Parameters
+/// - `ctx`: The snapshot context.
+/// - `courses`: The sections to flatten.
+///
+/// # Returns
+/// One row per section.
+pub fn section_rows(ctx: &SnapshotContext, courses: &[CourseSection]) -> Vec<SectionRow> {
+    courses
+        .iter()
+        .map(|section| {
+            let (subject_code, course_code) = split_subj_course_id(&section.subj_course_id);
+            SectionRow {
+                timestamp: ctx.timestamp,
+                term: ctx.term.clone(),
+                subject_code,
+                course_code,
+                section_id: section.section_id.to_string(),
+                section_code: section.section_code.clone(),
+                enrolled: section.enrolled_ct,
+                available: section.available_seats,
+                waitlist: section.waitlist_ct,
+                total: section.total_seats,
+                instructors: section.all_instructors.join(INSTRUCTOR_SEPARATOR),
+            }
+        })
+        .collect()
+}
+
+/// Flattens the meetings of the specified sections into meeting rows.
+///
+/// # Parameters
+/// - `ctx`: The snapshot context.
+/// - `courses`: The sections whose meetings should be flattened.
+///
+/// # Returns
+/// One row per meeting, per section.
+pub fn meeting_rows(ctx: &SnapshotContext, courses: &[CourseSection]) -> Vec<MeetingRow> {
+    courses
+        .iter()
+        .flat_map(|section| {
+            let (subject_code, course_code) = split_subj_course_id(&section.subj_course_id);
+            section
+                .meetings
+                .iter()
+                .map(|meeting| {
+                    meeting_row(
+                        ctx,
+                        &subject_code,
+                        &course_code,
+                        section.section_id,
+                        &section.section_code,
+                        meeting,
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Flattens a schedule into section rows.
+///
+/// # Parameters
+/// - `ctx`: The snapshot context.
+/// - `schedule`: The schedule to flatten.
+///
+/// # Returns
+/// One row per scheduled section.
+pub fn schedule_section_rows(
+    ctx: &SnapshotContext,
+    schedule: &[ScheduledSection],
+) -> Vec<SectionRow> {
+    schedule
+        .iter()
+        .map(|section| SectionRow {
+            timestamp: ctx.timestamp,
+            term: ctx.term.clone(),
+            subject_code: section.subject_code.clone(),
+            course_code: section.course_code.clone(),
+            section_id: section.section_id.to_string(),
+            section_code: section.section_code.clone(),
+            enrolled: section.enrolled_count,
+            available: section.available_seats,
+            waitlist: section.waitlist_ct,
+            total: section.section_capacity,
+            instructors: section.all_instructors.join(INSTRUCTOR_SEPARATOR),
+        })
+        .collect()
+}
+
+/// Flattens the meetings of a schedule into meeting rows.
+///
+/// # Parameters
+/// - `ctx`: The snapshot context.
+/// - `schedule`: The schedule whose meetings should be flattened.
+///
+/// # Returns
+/// One row per meeting, per scheduled section.
+pub fn schedule_meeting_rows(
+    ctx: &SnapshotContext,
+    schedule: &[ScheduledSection],
+) -> Vec<MeetingRow> {
+    schedule
+        .iter()
+        .flat_map(|section| {
+            section
+                .meetings
+                .iter()
+                .map(|meeting| {
+                    meeting_row(
+                        ctx,
+                        &section.subject_code,
+                        &section.course_code,
+                        section.section_id,
+                        &section.section_code,
+                        meeting,
+                    )
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect()
+}
+
+/// Flattens a full catalog (i.e., the sections of many courses) into section rows.
+///
+/// # Parameters
+/// - `ctx`: The snapshot context.
+/// - `catalog`: The catalog, where each element is all sections of one course.
+///
+/// # Returns
+/// One row per section, across every course in the catalog.
+pub fn catalog_section_rows<'a, I>(ctx: &SnapshotContext, catalog: I) -> Vec<SectionRow>
+where
+    I: IntoIterator<Item = &'a Courses>,
+{
+    catalog
+        .into_iter()
+        .flat_map(|courses| section_rows(ctx, courses))
+        .collect()
+}
+
+/// Flattens the meetings of a full catalog into meeting rows.
+///
+/// # Parameters
+/// - `ctx`: The snapshot context.
+/// - `catalog`: The catalog, where each element is all sections of one course.
+///
+/// # Returns
+/// One row per meeting, across every section of every course in the catalog.
+pub fn catalog_meeting_rows<'a, I>(ctx: &SnapshotContext, catalog: I) -> Vec<MeetingRow>
+where
+    I: IntoIterator<Item = &'a Courses>,
+{
+    catalog
+        .into_iter()
+        .flat_map(|courses| meeting_rows(ctx, courses))
+        .collect()
+}
+
+/// Escapes a single CSV field. Fields containing a comma, a quote, or a line break are
+/// quoted, with any quotes inside doubled.
+///
+/// # Parameters
+/// - `field`: The field to escape.
+///
+/// # Returns
+/// The escaped field.
+fn escape_csv_field(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+/// Writes the specified rows as CSV, including a header line.
+///
+/// # Parameters
+/// - `rows`: The rows to write.
+///
+/// # Returns
+/// The CSV string. Each line, including the last one, ends with `\n`.
+pub fn to_csv<R: ExportRow>(rows: &[R]) -> String {
+    let mut csv = R::COLUMNS.join(",");
+    csv.push('\n');
+    for row in rows {
+        csv.push_str(
+            &row.fields()
+                .iter()
+                .map(|f| escape_csv_field(f))
+                .collect::<Vec<_>>()
+                .join(","),
+        );
+        csv.push('\n');
+    }
+
+    csv
+}
+
+/// Writes the specified rows as JSON Lines (NDJSON), one JSON object per line.
+///
+/// # Parameters
+/// - `rows`: The rows to write.
+///
+/// # Returns
+/// The NDJSON string, or an error if a row could not be serialized. Each line, including
+/// the last one, ends with `\n`.
+pub fn to_ndjson<R: ExportRow>(rows: &[R]) -> types::Result<String> {
+    let mut ndjson = String::new();
+    for row in rows {
+        ndjson.push_str(&serde_json::to_string(row)?);
+        ndjson.push('\n');
+    }
+
+    Ok(ndjson)
+}
@@ -1,5 +1,12 @@
+pub mod compare;
 mod constants;
+pub mod diff;
+pub mod export;
+pub mod prereq;
 pub mod raw_types;
+pub mod schema;
+#[cfg(feature = "storage-sqlite")]
+pub mod storage;
 pub mod types;
 pub mod util;
 pub mod wrapper;
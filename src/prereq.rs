@@ -0,0 +1,364 @@
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::fmt::Write;
+use std::future::Future;
+
+use serde::Serialize;
+
+use crate::types::{self, CompletedCourse, CoursePrerequisite, PrerequisiteInfo};
+use crate::util;
+
+/// A course in a prerequisite graph.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct PrereqNode {
+    /// The subject, course ID. For example, `MATH 20C`.
+    pub subj_course_id: String,
+
+    /// The course title, for example `Calculus and Analytic Geometry`. This will be empty for
+    /// the root course since WebReg only gives us the titles of prerequisites.
+    pub course_title: String,
+
+    /// The distance from the root course. The root course has a depth of `0`, its direct
+    /// prerequisites have a depth of `1`, and so on.
+    pub depth: usize,
+
+    /// Whether the prerequisites of this course were fetched. This is `false` if the course
+    /// is at the depth limit.
+    pub expanded: bool,
+
+    /// The course prerequisites, stored as the subject, course IDs of each course. Like
+    /// `PrerequisiteInfo`, this is a vector of groups, where you need _one_ course from
+    /// _every_ group.
+    pub course_prerequisites: Vec<Vec<String>>,
+
+    /// Any exam prerequisites. Satisfying one of these satisfies all course prerequisites.
+    pub exam_prerequisites: Vec<String>,
+}
+
+/// A graph of prerequisites, starting from one course.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct PrereqGraph {
+    /// The subject, course ID of the course that the graph was built from.
+    pub root: String,
+
+    /// All courses in the graph, keyed by their subject, course ID.
+    pub nodes: BTreeMap<String, PrereqNode>,
+
+    /// Any cycles found in the graph. Each cycle is a list of subject, course IDs where each
+    /// course requires the next one, and the last course requires the first one.
+    pub cycles: Vec<Vec<String>>,
+}
+
+impl PrereqGraph {
+    /// Builds a prerequisite graph, starting from the specified course, using the given
+    /// function to fetch prerequisites. Each course is fetched at most once.
+    ///
+    /// Most users will want `WrapperTermRequest::prereq_graph` instead, which uses WebReg to
+    /// fetch the prerequisites.
+    ///
+    /// # Parameters
+    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 20C`, you
+    ///   would put `MATH`.
+    /// - `course_code`: The course code. For example, if you wanted to check `MATH 20C`, you
+    ///   would put `20C`.
+    /// - `depth`: The number of levels of prerequisites to fetch. A depth of `1` only fetches
+    ///   the prerequisites of the specified course, a depth of `2` also fetches the
+    ///   prerequisites of those prerequisites, and so on.
+    /// - `fetch`: A function that takes a subject code and course code, and returns the
+    ///   prerequisites for that course.
+    ///
+    /// # Returns
+    /// The prerequisite graph, or the first error returned by `fetch`.
+    pub async fn build<F, Fut>(
+        subject_code: impl AsRef<str>,
+        course_code: impl AsRef<str>,
+        depth: usize,
+        mut fetch: F,
+    ) -> types::Result<Self>
+    where
+        F: FnMut(String, String) -> Fut,
+        Fut: Future<Output = types::Result<PrerequisiteInfo>>,
+    {
+        let root = util::normalize_subj_course_id(&format!(
+            "{} {}",
+            subject_code.as_ref(),
+            course_code.as_ref()
+        ));
+
+        let mut nodes: BTreeMap<String, PrereqNode> = BTreeMap::new();
+        nodes.insert(root.clone(), PrereqNode::new(root.clone(), "", 0));
+
+        let mut queue = VecDeque::from([root.clone()]);
+        while let Some(id) = queue.pop_front() {
+            let node_depth = nodes[&id].depth;
+            if node_depth >= depth {
+                continue;
+            }
+
+            let (subj, crsc) = id.split_once(' ').unwrap_or((id.as_str(), ""));
+            let info = fetch(subj.to_string(), crsc.to_string()).await?;
+
+            let mut groups = vec![];
+            for group in info.course_prerequisites {
+                let mut ids = vec![];
+                for prereq in group {
+                    let prereq_id = util::normalize_subj_course_id(&prereq.subj_course_id);
+                    if !nodes.contains_key(&prereq_id) {
+                        nodes.insert(
+                            prereq_id.clone(),
+                            PrereqNode::new(prereq_id.clone(), prereq.course_title, node_depth + 1),
+                        );
+                        queue.push_back(prereq_id.clone());
+                    }
+
+                    ids.push(prereq_id);
+                }
+
+                ids.sort();
+                ids.dedup();
+                groups.push(ids);
+            }
+
+            // WebReg doesn't return the groups in any particular order.
+            groups.sort();
+
+            let node = nodes.get_mut(&id).unwrap();
+            node.expanded = true;
+            node.course_prerequisites = groups;
+            node.exam_prerequisites = info.exam_prerequisites;
+            node.exam_prerequisites.sort();
+        }
+
+        let cycles = find_cycles(&nodes);
+        Ok(Self {
+            root,
+            nodes,
+            cycles,
+        })
+    }
+
+    /// Whether the graph has any cycles.
+    ///
+    /// # Returns
+    /// `true` if there is at least one cycle, and `false` otherwise.
+    pub fn has_cycles(&self) -> bool {
+        !self.cycles.is_empty()
+    }
+
+    /// Exports the graph in the DOT language, which can be rendered by Graphviz.
+    ///
+    /// Each course is drawn as a box, with an edge pointing to each of its prerequisites. If a
+    /// requirement can be satisfied by one of several courses, the course points to an `or`
+    /// node, which then points to each of the options. Exam prerequisites are drawn as dashed
+    /// edges to a note.
+    ///
+    /// # Returns
+    /// The graph, in the DOT language.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph prerequisites {\n    rankdir=LR;\n");
+        for node in self.nodes.values() {
+            let label = if node.course_title.is_empty() {
+                escape_dot(&node.subj_course_id)
+            } else {
+                format!(
+                    "{}\\n{}",
+                    escape_dot(&node.subj_course_id),
+                    escape_dot(&node.course_title)
+                )
+            };
+
+            let _ = writeln!(
+                dot,
+                "    \"{}\" [shape=box, label=\"{}\"{}];",
+                escape_dot(&node.subj_course_id),
+                label,
+                if node.subj_course_id == self.root {
+                    ", style=bold"
+                } else {
+                    ""
+                }
+            );
+        }
+
+        for node in self.nodes.values() {
+            let from = escape_dot(&node.subj_course_id);
+            for (idx, group) in node.course_prerequisites.iter().enumerate() {
+                if let [only] = group.as_slice() {
+                    let _ = writeln!(dot, "    \"{from}\" -> \"{}\";", escape_dot(only));
+                    continue;
+                }
+
+                let or_node = format!("{from}#or{idx}");
+                let _ = writeln!(dot, "    \"{or_node}\" [shape=circle, label=\"or\"];");
+                let _ = writeln!(dot, "    \"{from}\" -> \"{or_node}\";");
+                for option in group {
+                    let _ = writeln!(dot, "    \"{or_node}\" -> \"{}\";", escape_dot(option));
+                }
+            }
+
+            for (idx, exam) in node.exam_prerequisites.iter().enumerate() {
+                let exam_node = format!("{from}#exam{idx}");
+                let _ = writeln!(
+                    dot,
+                    "    \"{exam_node}\" [shape=note, label=\"{}\"];",
+                    escape_dot(exam)
+                );
+                let _ = writeln!(dot, "    \"{from}\" -> \"{exam_node}\" [style=dashed];");
+            }
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+
+    /// Exports the graph as JSON.
+    ///
+    /// # Returns
+    /// The JSON string, or an error if the graph could not be serialized.
+    pub fn to_json(&self) -> types::Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+}
+
+/// Whether a student can take a course, as determined by `plan_next_term`.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct CourseEligibility {
+    /// The subject, course ID. For example, `CSE 100`.
+    pub subj_course_id: String,
+
+    /// Whether the student already completed this course.
+    pub already_completed: bool,
+
+    /// The groups of prerequisites that the student still needs to satisfy. The student
+    /// needs to complete one course from each group.
+    pub unmet: Vec<Vec<CoursePrerequisite>>,
+}
+
+impl CourseEligibility {
+    /// Whether the student can take this course.
+    ///
+    /// # Returns
+    /// `true` if the student hasn't completed this course yet and satisfies all of its
+    /// prerequisites, and `false` otherwise.
+    pub fn can_take(&self) -> bool {
+        !self.already_completed && self.unmet.is_empty()
+    }
+}
+
+/// Determines which of the target courses a student can take next term, based on the
+/// courses they completed and the exams they passed.
+///
+/// # Parameters
+/// - `targets`: The courses that the student wants to take, along with the prerequisites
+///   for each course (e.g., from `get_prerequisites`).
+/// - `completed`: The courses that the student has completed.
+/// - `exams_passed`: The exams that the student has passed (e.g., `AP-Math BC`).
+///
+/// # Returns
+/// The eligibility of each target course, in the same order as `targets`.
+pub fn plan_next_term<'a, S>(
+    targets: impl IntoIterator<Item = (S, &'a PrerequisiteInfo)>,
+    completed: &[CompletedCourse],
+    exams_passed: &[impl AsRef<str>],
+) -> Vec<CourseEligibility>
+where
+    S: AsRef<str>,
+{
+    targets
+        .into_iter()
+        .map(|(subj_course_id, prereqs)| {
+            let subj_course_id = subj_course_id.as_ref();
+            CourseEligibility {
+                subj_course_id: subj_course_id.to_string(),
+                already_completed: completed
+                    .iter()
+                    .any(|c| util::is_same_subj_course_id(&c.subj_course_id, subj_course_id)),
+                unmet: prereqs.unmet_requirements(completed, exams_passed),
+            }
+        })
+        .collect()
+}
+
+impl PrereqNode {
+    /// Creates a new, unexpanded node.
+    ///
+    /// # Parameters
+    /// - `subj_course_id`: The subject, course ID.
+    /// - `course_title`: The course title.
+    /// - `depth`: The distance from the root course.
+    ///
+    /// # Returns
+    /// The new node.
+    fn new(subj_course_id: String, course_title: impl Into<String>, depth: usize) -> Self {
+        Self {
+            subj_course_id,
+            course_title: course_title.into(),
+            depth,
+            expanded: false,
+            course_prerequisites: vec![],
+            exam_prerequisites: vec![],
+        }
+    }
+}
+
+/// Escapes a string so that it can be put in a quoted DOT identifier.
+///
+/// # Parameters
+/// - `s`: The string to escape.
+///
+/// # Returns
+/// The escaped string.
+fn escape_dot(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
+
+/// Finds all cycles in the graph, using a depth-first search.
+///
+/// # Parameters
+/// - `nodes`: The nodes of the graph.
+///
+/// # Returns
+/// The cycles, where each cycle starts from the course in the cycle that was reached first.
+fn find_cycles(nodes: &BTreeMap<String, PrereqNode>) -> Vec<Vec<String>> {
+    #[derive(Clone, Copy, PartialEq)]
+    enum State {
+        Visiting,
+        Done,
+    }
+
+    fn visit<'a>(
+        id: &'a str,
+        nodes: &'a BTreeMap<String, PrereqNode>,
+        state: &mut HashMap<&'a str, State>,
+        path: &mut Vec<&'a str>,
+        cycles: &mut Vec<Vec<String>>,
+    ) {
+        state.insert(id, State::Visiting);
+        path.push(id);
+
+        if let Some(node) = nodes.get(id) {
+            for next in node.course_prerequisites.iter().flatten() {
+                match state.get(next.as_str()) {
+                    Some(State::Visiting) => {
+                        let start = path.iter().position(|p| p == next).unwrap_or(0);
+                        cycles.push(path[start..].iter().map(|s| s.to_string()).collect());
+                    }
+                    Some(State::Done) => {}
+                    None => visit(next, nodes, state, path, cycles),
+                }
+            }
+        }
+
+        path.pop();
+        state.insert(id, State::Done);
+    }
+
+    let mut state = HashMap::new();
+    let mut cycles = vec![];
+    for id in nodes.keys() {
+        if !state.contains_key(id.as_str()) {
+            visit(id, nodes, &mut state, &mut vec![], &mut cycles);
+        }
+    }
+
+    cycles
+}
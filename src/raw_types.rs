@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
 use std::fmt::Display;
 
+use crate::schema::{struct_fields, RawSchema};
+
 /// One possible result you can get by searching for a particular course.
 #[derive(Debug, Serialize, Deserialize)]
 pub struct RawWebRegSearchResultItem {
@@ -431,3 +434,77 @@ pub struct RawSectionTextItem {
     #[serde(rename = "TEXT")]
     pub text: String,
 }
+
+impl RawSchema for RawWebRegSearchResultItem {
+    const NAME: &'static str = "RawWebRegSearchResultItem";
+}
+
+impl RawSchema for RawWebRegMeeting {
+    const NAME: &'static str = "RawWebRegMeeting";
+    const IGNORED_FIELDS: &'static [&'static str] = &[
+        "BEFORE_DESC",
+        "LONG_DESC",
+        "PRIMARY_INSTR_FLAG",
+        "SECTION_END_DATE",
+        "STP_ENRLT_FLAG",
+    ];
+}
+
+impl RawSchema for RawScheduledMeeting {
+    const NAME: &'static str = "RawScheduledMeeting";
+    const IGNORED_FIELDS: &'static [&'static str] = &[
+        "END_DATE",
+        "FK_PCH_INTRL_REFID",
+        "FK_SEC_SCTN_NUM",
+        "GRADE_OPTN_CD_PLUS",
+        "LONG_DESC",
+        "NEED_HEADROW",
+        "PB_FRIEND",
+        "PERSON_ID",
+        "PRIMARY_INSTR_FLAG",
+        "SECTION_NUMBER",
+        "SECT_CREDIT_HRS_PL",
+        "STP_ENRLT_FLAG",
+        "TERM_CODE",
+    ];
+    const OPTIONAL_FIELDS: &'static [&'static str] =
+        &["SCTN_CPCTY_QTY", "SCTN_ENRLT_QTY", "COUNT_ON_WAITLIST"];
+}
+
+impl RawSchema for RawPrerequisite {
+    const NAME: &'static str = "RawPrerequisite";
+    // The tag is used by serde to pick the variant, so it isn't a field of either variant.
+    const IGNORED_FIELDS: &'static [&'static str] = &["TYPE"];
+
+    fn fields(record: &Map<String, Value>) -> &'static [&'static str] {
+        match record.get("TYPE").and_then(Value::as_str) {
+            Some("TEST") => struct_fields::<RawTestPrerequisite>(),
+            Some("COURSE") => struct_fields::<RawCoursePrerequisite>(),
+            _ => &[],
+        }
+    }
+}
+
+impl RawSchema for RawEvent {
+    const NAME: &'static str = "RawEvent";
+}
+
+impl RawSchema for RawSubjectElement {
+    const NAME: &'static str = "RawSubjectElement";
+}
+
+impl RawSchema for RawDepartmentElement {
+    const NAME: &'static str = "RawDepartmentElement";
+}
+
+impl RawSchema for RawTermListItem {
+    const NAME: &'static str = "RawTermListItem";
+}
+
+impl RawSchema for RawCourseTextItem {
+    const NAME: &'static str = "RawCourseTextItem";
+}
+
+impl RawSchema for RawSectionTextItem {
+    const NAME: &'static str = "RawSectionTextItem";
+}
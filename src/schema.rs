@@ -0,0 +1,269 @@
+use std::cell::Cell;
+use std::collections::{BTreeMap, BTreeSet};
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use serde::de::{self, DeserializeOwned, Visitor};
+use serde::{forward_to_deserialize_any, Deserializer};
+use serde_json::{Map, Value};
+
+use crate::types::{self, WrapperError};
+
+/// A callback that is given any schema drift found in a WebReg response. See
+/// [`with_schema_observer`](crate::wrapper::wrapper_builder::WebRegWrapperBuilder::with_schema_observer).
+pub type SchemaObserver = Arc<dyn Fn(&SchemaDrift) + Send + Sync>;
+
+/// A raw type whose JSON keys can be checked against what WebReg actually returns.
+pub trait RawSchema: DeserializeOwned {
+    /// The name of the raw type, for use in reports.
+    const NAME: &'static str;
+
+    /// Keys that WebReg is known to return, but that this type doesn't read.
+    const IGNORED_FIELDS: &'static [&'static str] = &[];
+
+    /// Keys that this type reads, but that WebReg doesn't always return.
+    const OPTIONAL_FIELDS: &'static [&'static str] = &[];
+
+    /// The keys that this type reads from a record.
+    ///
+    /// # Parameters
+    /// - `record`: The record. This is only needed for types whose keys depend on the
+    ///   record itself (e.g., tagged enums).
+    ///
+    /// # Returns
+    /// The keys.
+    fn fields(_record: &Map<String, Value>) -> &'static [&'static str] {
+        struct_fields::<Self>()
+    }
+}
+
+/// The differences between the keys that a raw type expects and the keys that WebReg
+/// returned.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SchemaDrift {
+    /// The endpoint that was called (e.g., `get_schedule`).
+    pub endpoint: &'static str,
+    /// The name of the raw type that the response was deserialized into.
+    pub type_name: &'static str,
+    /// The number of records in the response.
+    pub records: usize,
+    /// Keys that WebReg returned but that this crate doesn't know about, along with the
+    /// first value seen for each key.
+    pub unknown_fields: BTreeMap<String, Value>,
+    /// Keys that this crate expects but that were absent from at least one record.
+    pub missing_fields: BTreeSet<String>,
+}
+
+impl SchemaDrift {
+    /// Whether the response matched the expected schema.
+    ///
+    /// # Returns
+    /// `true` if there are no unknown or missing keys, and `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.unknown_fields.is_empty() && self.missing_fields.is_empty()
+    }
+}
+
+impl Display for SchemaDrift {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "[{} / {}] {} record(s)",
+            self.endpoint, self.type_name, self.records
+        )?;
+        if self.is_empty() {
+            return write!(f, ", no drift");
+        }
+
+        if !self.unknown_fields.is_empty() {
+            let keys = self
+                .unknown_fields
+                .iter()
+                .map(|(k, v)| format!("{k}={v}"))
+                .collect::<Vec<_>>();
+            write!(f, ", unknown: {}", keys.join(", "))?;
+        }
+
+        if !self.missing_fields.is_empty() {
+            let keys = self
+                .missing_fields
+                .iter()
+                .map(String::as_str)
+                .collect::<Vec<_>>();
+            write!(f, ", missing: {}", keys.join(", "))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// A summary of the schema drift found across several WebReg endpoints.
+#[derive(Debug, Default)]
+pub struct SchemaReport {
+    /// The drift found for each endpoint that was successfully called.
+    pub drifts: Vec<SchemaDrift>,
+    /// The endpoints that couldn't be checked, along with why.
+    pub errors: Vec<(&'static str, WrapperError)>,
+}
+
+impl SchemaReport {
+    /// Whether any endpoint returned a response that didn't match the expected schema.
+    ///
+    /// # Returns
+    /// `true` if there is any drift, and `false` otherwise.
+    pub fn has_drift(&self) -> bool {
+        self.drifts.iter().any(|d| !d.is_empty())
+    }
+
+    /// Checks a response from an endpoint, adding either its drift or why it couldn't be
+    /// checked to the report.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The endpoint that was called (e.g., `get_schedule`).
+    /// - `res`: The response, or the error from calling the endpoint.
+    ///
+    /// # Returns
+    /// The deserialized response, if the endpoint was successfully called and the response
+    /// could be deserialized.
+    pub(crate) fn check<T: RawSchema>(
+        &mut self,
+        endpoint: &'static str,
+        res: types::Result<String>,
+    ) -> Option<Vec<T>> {
+        let checked = res.and_then(|text| {
+            let drift = check_schema::<T>(endpoint, &text)?;
+            Ok((drift, serde_json::from_str::<Vec<T>>(&text)))
+        });
+
+        match checked {
+            Ok((drift, parsed)) => {
+                self.drifts.push(drift);
+                match parsed {
+                    Ok(parsed) => Some(parsed),
+                    Err(e) => {
+                        self.errors.push((endpoint, e.into()));
+                        None
+                    }
+                }
+            }
+            Err(e) => {
+                self.errors.push((endpoint, e));
+                None
+            }
+        }
+    }
+}
+
+impl Display for SchemaReport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        for drift in &self.drifts {
+            writeln!(f, "{drift}")?;
+        }
+
+        for (endpoint, err) in &self.errors {
+            writeln!(f, "[{endpoint}] not checked: {err}")?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Checks a WebReg response against the schema of a raw type.
+///
+/// # Parameters
+/// - `endpoint`: The endpoint that was called (e.g., `get_schedule`).
+/// - `json`: The response. This can either be a single record or an array of records.
+///
+/// # Returns
+/// The drift, or an error if the response isn't valid JSON.
+///
+/// # Example
+/// ```rust
+/// use webweg::raw_types::RawSubjectElement;
+/// use webweg::schema::check_schema;
+///
+/// let json = r#"[{"LONG_DESC": "Mathematics", "SUBJECT_CODE": "MATH", "NEW_KEY": 1}]"#;
+/// let drift = check_schema::<RawSubjectElement>("get_subject_codes", json).unwrap();
+/// assert!(drift.unknown_fields.contains_key("NEW_KEY"));
+/// assert!(drift.missing_fields.is_empty());
+/// ```
+pub fn check_schema<T: RawSchema>(
+    endpoint: &'static str,
+    json: &str,
+) -> types::Result<SchemaDrift> {
+    let value = serde_json::from_str::<Value>(json)?;
+    let records = match &value {
+        Value::Array(arr) => arr.iter().filter_map(Value::as_object).collect::<Vec<_>>(),
+        Value::Object(obj) => vec![obj],
+        _ => vec![],
+    };
+
+    let mut drift = SchemaDrift {
+        endpoint,
+        type_name: T::NAME,
+        records: records.len(),
+        ..Default::default()
+    };
+
+    for record in records {
+        let fields = T::fields(record);
+        for (key, value) in record {
+            if !fields.contains(&key.as_str()) && !T::IGNORED_FIELDS.contains(&key.as_str()) {
+                drift
+                    .unknown_fields
+                    .entry(key.to_owned())
+                    .or_insert_with(|| value.clone());
+            }
+        }
+
+        drift.missing_fields.extend(
+            fields
+                .iter()
+                .filter(|f| !record.contains_key(**f) && !T::OPTIONAL_FIELDS.contains(f))
+                .map(|f| f.to_string()),
+        );
+    }
+
+    Ok(drift)
+}
+
+/// Gets the (renamed) keys that a struct reads when it's deserialized.
+///
+/// # Returns
+/// The keys, or an empty slice if `T` isn't deserialized as a struct.
+pub(crate) fn struct_fields<T: DeserializeOwned>() -> &'static [&'static str] {
+    let fields = Cell::new(None);
+    // This will always fail, since the deserializer never produces any data. We only care
+    // about which fields serde asked for.
+    let _ = T::deserialize(FieldCollector { fields: &fields });
+    fields.get().unwrap_or_default()
+}
+
+/// A deserializer that records the fields that a struct asks for, and then fails.
+struct FieldCollector<'a> {
+    fields: &'a Cell<Option<&'static [&'static str]>>,
+}
+
+impl<'de, 'a> Deserializer<'de> for FieldCollector<'a> {
+    type Error = de::value::Error;
+
+    fn deserialize_any<V: Visitor<'de>>(self, _: V) -> Result<V::Value, Self::Error> {
+        Err(de::Error::custom("not a struct"))
+    }
+
+    fn deserialize_struct<V: Visitor<'de>>(
+        self,
+        _: &'static str,
+        fields: &'static [&'static str],
+        _: V,
+    ) -> Result<V::Value, Self::Error> {
+        self.fields.set(Some(fields));
+        Err(de::Error::custom("fields collected"))
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map enum identifier ignored_any
+    }
+}
@@ -0,0 +1,876 @@
+use std::collections::HashSet;
+use std::path::Path;
+
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSqlOutput, ValueRef};
+use rusqlite::{params, Connection, OptionalExtension, ToSql, Transaction};
+use serde::Serialize;
+
+use crate::export::SnapshotContext;
+use crate::types::{
+    self, CourseSection, Courses, Meeting, MeetingDay, SectionId, TimeType, WrapperError,
+};
+use crate::util;
+
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS sections (
+    id              INTEGER PRIMARY KEY,
+    term            TEXT NOT NULL,
+    section_id      TEXT NOT NULL,
+    subj_course_id  TEXT NOT NULL,
+    section_code    TEXT NOT NULL,
+    UNIQUE (term, section_id)
+);
+
+CREATE TABLE IF NOT EXISTS enrollment_snapshots (
+    id              INTEGER PRIMARY KEY,
+    section_ref     INTEGER NOT NULL REFERENCES sections (id) ON DELETE CASCADE,
+    first_seen      INTEGER NOT NULL,
+    last_seen       INTEGER NOT NULL,
+    enrolled        INTEGER NOT NULL,
+    available       INTEGER NOT NULL,
+    waitlist        INTEGER NOT NULL,
+    total           INTEGER NOT NULL,
+    is_visible      INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS enrollment_snapshots_section
+    ON enrollment_snapshots (section_ref, first_seen);
+
+CREATE TABLE IF NOT EXISTS instructors (
+    id              INTEGER PRIMARY KEY,
+    name            TEXT NOT NULL UNIQUE
+);
+
+CREATE TABLE IF NOT EXISTS instructor_snapshots (
+    id              INTEGER PRIMARY KEY,
+    section_ref     INTEGER NOT NULL REFERENCES sections (id) ON DELETE CASCADE,
+    first_seen      INTEGER NOT NULL,
+    last_seen       INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS instructor_snapshots_section
+    ON instructor_snapshots (section_ref, first_seen);
+
+CREATE TABLE IF NOT EXISTS section_instructors (
+    snapshot_ref    INTEGER NOT NULL REFERENCES instructor_snapshots (id) ON DELETE CASCADE,
+    instructor_ref  INTEGER NOT NULL REFERENCES instructors (id),
+    position        INTEGER NOT NULL,
+    PRIMARY KEY (snapshot_ref, instructor_ref)
+);
+
+CREATE TABLE IF NOT EXISTS meeting_snapshots (
+    id              INTEGER PRIMARY KEY,
+    section_ref     INTEGER NOT NULL REFERENCES sections (id) ON DELETE CASCADE,
+    first_seen      INTEGER NOT NULL,
+    last_seen       INTEGER NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS meeting_snapshots_section
+    ON meeting_snapshots (section_ref, first_seen);
+
+CREATE TABLE IF NOT EXISTS meetings (
+    id              INTEGER PRIMARY KEY,
+    snapshot_ref    INTEGER NOT NULL REFERENCES meeting_snapshots (id) ON DELETE CASCADE,
+    position        INTEGER NOT NULL,
+    meeting_type    TEXT NOT NULL,
+    day_kind        TEXT NOT NULL,
+    days            TEXT NOT NULL,
+    start_hr        INTEGER NOT NULL,
+    start_min       INTEGER NOT NULL,
+    end_hr          INTEGER NOT NULL,
+    end_min         INTEGER NOT NULL,
+    building        TEXT NOT NULL,
+    room            TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS meeting_instructors (
+    meeting_ref     INTEGER NOT NULL REFERENCES meetings (id) ON DELETE CASCADE,
+    instructor_ref  INTEGER NOT NULL REFERENCES instructors (id),
+    position        INTEGER NOT NULL,
+    PRIMARY KEY (meeting_ref, instructor_ref)
+);
+";
+
+// The tables that record when each version of a section's instructors and meetings was seen.
+const INSTRUCTOR_SNAPSHOTS: &str = "instructor_snapshots";
+const MEETING_SNAPSHOTS: &str = "meeting_snapshots";
+
+// How `MeetingDay` is stored in the `day_kind` column.
+const DAY_KIND_REPEATED: &str = "R";
+const DAY_KIND_ONE_TIME: &str = "O";
+const DAY_KIND_NONE: &str = "N";
+
+/// A single point in a section's enrollment time series.
+///
+/// Because unchanged snapshots are deduplicated on ingest, each point covers the period
+/// from `first_seen` to `last_seen` (both inclusive) during which the counts stayed the same.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct EnrollmentPoint {
+    /// When these counts were first observed, in milliseconds since the Unix epoch.
+    pub first_seen: i64,
+    /// When these counts were last observed, in milliseconds since the Unix epoch.
+    pub last_seen: i64,
+    /// The number of students enrolled.
+    pub enrolled: i64,
+    /// The number of available seats.
+    pub available: i64,
+    /// The number of students on the waitlist.
+    pub waitlist: i64,
+    /// The total number of seats.
+    pub total: i64,
+}
+
+/// A summary of what happened when ingesting a snapshot.
+#[derive(Debug, Clone, Default, Eq, PartialEq)]
+pub struct IngestSummary {
+    /// The number of sections whose enrollment counts changed (or were seen for the first
+    /// time), and thus resulted in a new time series point.
+    pub changed: usize,
+    /// The number of sections whose enrollment counts were the same as the latest point,
+    /// and thus only had that point's `last_seen` extended.
+    pub unchanged: usize,
+}
+
+/// Local storage for timestamped `CourseSection` snapshots, backed by SQLite.
+///
+/// Each section is identified by its term and section ID. Every time a snapshot is
+/// ingested, the section's enrollment counts, instructors, and meetings are compared against
+/// the latest stored versions; a new version is only recorded if something changed. This
+/// means that polling frequently will not cause the database to grow unless the section
+/// actually changes, and that older versions are kept (see `section_at`).
+///
+/// # Example
+/// ```rust,no_run
+/// use webweg::export::SnapshotContext;
+/// use webweg::storage::SqliteStorage;
+/// use webweg::wrapper::WebRegWrapper;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let wrapper = WebRegWrapper::builder()
+///     .with_cookies("my cookies")
+///     .try_build_wrapper()
+///     .unwrap();
+/// let mut storage = SqliteStorage::open("enrollment.db").unwrap();
+///
+/// let courses = wrapper
+///     .req("FA23")
+///     .parsed()
+///     .get_course_info(("CSE", "100"))
+///     .await
+///     .unwrap();
+/// storage
+///     .ingest_courses(&SnapshotContext::now("FA23"), &courses)
+///     .unwrap();
+///
+/// let series = storage.section_time_series("FA23", "079911", None).unwrap();
+/// println!("{series:?}");
+/// # }
+/// ```
+pub struct SqliteStorage {
+    conn: Connection,
+}
+
+impl SqliteStorage {
+    /// Opens (or creates) the database at the specified path.
+    ///
+    /// # Parameters
+    /// - `path`: The path to the database file.
+    ///
+    /// # Returns
+    /// The storage, or an error if the database could not be opened or initialized.
+    pub fn open(path: impl AsRef<Path>) -> types::Result<Self> {
+        Self::from_connection(Connection::open(path)?)
+    }
+
+    /// Opens a new database that only lives in memory. This is mainly useful for testing.
+    ///
+    /// # Returns
+    /// The storage, or an error if the database could not be initialized.
+    pub fn open_in_memory() -> types::Result<Self> {
+        Self::from_connection(Connection::open_in_memory()?)
+    }
+
+    /// Initializes the schema on the specified connection.
+    ///
+    /// # Parameters
+    /// - `conn`: The connection.
+    ///
+    /// # Returns
+    /// The storage, or an error if the schema could not be created.
+    fn from_connection(conn: Connection) -> types::Result<Self> {
+        conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+        conn.execute_batch(SCHEMA)?;
+        Ok(Self { conn })
+    }
+
+    /// Ingests a snapshot of the specified sections (e.g., the result of `get_course_info`
+    /// or `get_enrollment_count`).
+    ///
+    /// Sections whose enrollment counts haven't changed since the latest snapshot will not
+    /// produce a new time series point. Likewise, a new version of a section's meetings or
+    /// instructors is only recorded if they differ from the latest version; sections without
+    /// any meetings (e.g., those from `get_enrollment_count`) leave the stored meetings alone.
+    ///
+    /// # Parameters
+    /// - `ctx`: The snapshot context (term and timestamp).
+    /// - `courses`: The sections to ingest.
+    ///
+    /// # Returns
+    /// A summary of how many sections changed, or an error if one occurred. If an error
+    /// occurs, nothing from this snapshot is stored.
+    pub fn ingest_courses(
+        &mut self,
+        ctx: &SnapshotContext,
+        courses: &[CourseSection],
+    ) -> types::Result<IngestSummary> {
+        let timestamp = i64::try_from(ctx.timestamp).map_err(|_| {
+            WrapperError::InputError("ctx.timestamp", "timestamp does not fit in an i64.")
+        })?;
+
+        let tx = self.conn.transaction()?;
+        let mut summary = IngestSummary::default();
+        for section in courses {
+            let section_ref = upsert_section(&tx, &ctx.term, section)?;
+            if record_enrollment(&tx, section_ref, timestamp, section)? {
+                summary.changed += 1;
+            } else {
+                summary.unchanged += 1;
+            }
+
+            // An instructor can only be stored once per section (or meeting), so duplicates
+            // are dropped before comparing; otherwise, the stored instructors would never
+            // match and a new version would be recorded on every ingest.
+            let all_instructors = distinct_names(&section.all_instructors);
+            match snapshot_at(&tx, INSTRUCTOR_SNAPSHOTS, section_ref, i64::MAX)? {
+                Some(snapshot_ref)
+                    if load_section_instructors(&tx, snapshot_ref)? == all_instructors =>
+                {
+                    extend_snapshot(&tx, INSTRUCTOR_SNAPSHOTS, snapshot_ref, timestamp)?;
+                }
+                _ => insert_section_instructors(&tx, section_ref, timestamp, &all_instructors)?,
+            }
+
+            if !section.meetings.is_empty() {
+                let meetings = section
+                    .meetings
+                    .iter()
+                    .map(|m| Meeting {
+                        instructors: distinct_names(&m.instructors),
+                        ..m.clone()
+                    })
+                    .collect::<Vec<_>>();
+                match snapshot_at(&tx, MEETING_SNAPSHOTS, section_ref, i64::MAX)? {
+                    Some(snapshot_ref) if load_meetings(&tx, snapshot_ref)? == meetings => {
+                        extend_snapshot(&tx, MEETING_SNAPSHOTS, snapshot_ref, timestamp)?;
+                    }
+                    _ => insert_meetings(&tx, section_ref, timestamp, &meetings)?,
+                }
+            }
+        }
+
+        tx.commit()?;
+        Ok(summary)
+    }
+
+    /// Gets the enrollment time series for a section, ordered from oldest to newest.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `section_id`: The section ID (e.g., `079911`).
+    /// - `since`: If specified, only points that were last seen at or after this time
+    ///   (milliseconds since the Unix epoch) are returned.
+    ///
+    /// # Returns
+    /// The time series, which is empty if the section has never been ingested.
+    pub fn section_time_series(
+        &self,
+        term: impl AsRef<str>,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+        since: Option<i64>,
+    ) -> types::Result<Vec<EnrollmentPoint>> {
+        let mut stmt = self.conn.prepare(
+            "SELECT e.first_seen, e.last_seen, e.enrolled, e.available, e.waitlist, e.total \
+             FROM enrollment_snapshots e JOIN sections s ON s.id = e.section_ref \
+             WHERE s.term = ?1 AND s.section_id = ?2 AND e.last_seen >= ?3 \
+             ORDER BY e.first_seen",
+        )?;
+
+        let points = stmt
+            .query_map(
+                params![
+                    normalize(term.as_ref()),
+                    util::to_section_id(section_id)?,
+                    since.unwrap_or(i64::MIN)
+                ],
+                |row| {
+                    Ok(EnrollmentPoint {
+                        first_seen: row.get(0)?,
+                        last_seen: row.get(1)?,
+                        enrolled: row.get(2)?,
+                        available: row.get(3)?,
+                        waitlist: row.get(4)?,
+                        total: row.get(5)?,
+                    })
+                },
+            )?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(points)
+    }
+
+    /// Finds the section ID of a section given its course and section code. This is useful
+    /// if you only know something like `CSE 100` `A01`.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `subj_course_id`: The subject, course ID (e.g., `CSE 100`).
+    /// - `section_code`: The section code (e.g., `A01`).
+    ///
+    /// # Returns
+    /// The section ID, if this section has been ingested before.
+    pub fn find_section_id(
+        &self,
+        term: impl AsRef<str>,
+        subj_course_id: impl AsRef<str>,
+        section_code: impl AsRef<str>,
+    ) -> types::Result<Option<SectionId>> {
+        Ok(self
+            .conn
+            .query_row(
+                "SELECT section_id FROM sections \
+                 WHERE term = ?1 AND subj_course_id = ?2 AND section_code = ?3",
+                params![
+                    normalize(term.as_ref()),
+                    normalize(subj_course_id.as_ref()),
+                    normalize(section_code.as_ref())
+                ],
+                |row| row.get(0),
+            )
+            .optional()?)
+    }
+
+    /// Gets the latest snapshot of a single section.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `section_id`: The section ID (e.g., `079911`).
+    ///
+    /// # Returns
+    /// The section as it was last seen, or `None` if it has never been ingested.
+    pub fn latest_section(
+        &self,
+        term: impl AsRef<str>,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+    ) -> types::Result<Option<CourseSection>> {
+        self.section_at(term, section_id, i64::MAX)
+    }
+
+    /// Gets a single section as it was at some point in time. Its enrollment counts,
+    /// meetings, and instructors are the ones from the latest snapshot ingested at or before
+    /// that time.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    /// - `section_id`: The section ID (e.g., `079911`).
+    /// - `timestamp`: The time, in milliseconds since the Unix epoch.
+    ///
+    /// # Returns
+    /// The section as it was at that time, or `None` if it hadn't been ingested by then.
+    pub fn section_at(
+        &self,
+        term: impl AsRef<str>,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+        timestamp: i64,
+    ) -> types::Result<Option<CourseSection>> {
+        let section_ref: Option<i64> = self
+            .conn
+            .query_row(
+                "SELECT id FROM sections WHERE term = ?1 AND section_id = ?2",
+                params![normalize(term.as_ref()), util::to_section_id(section_id)?],
+                |row| row.get(0),
+            )
+            .optional()?;
+
+        match section_ref {
+            Some(r) => load_section(&self.conn, r, timestamp),
+            None => Ok(None),
+        }
+    }
+
+    /// Gets the latest snapshot of every section stored for a term.
+    ///
+    /// # Parameters
+    /// - `term`: The term (e.g., `FA23`).
+    ///
+    /// # Returns
+    /// Every section in the term as it was last seen, ordered by course and section code.
+    pub fn latest_snapshot(&self, term: impl AsRef<str>) -> types::Result<Courses> {
+        let mut stmt = self.conn.prepare(
+            "SELECT id FROM sections WHERE term = ?1 ORDER BY subj_course_id, section_code",
+        )?;
+        let refs = stmt
+            .query_map(params![normalize(term.as_ref())], |row| {
+                row.get::<_, i64>(0)
+            })?
+            .collect::<Result<Vec<_>, _>>()?;
+
+        let mut courses = vec![];
+        for r in refs {
+            if let Some(section) = load_section(&self.conn, r, i64::MAX)? {
+                courses.push(section);
+            }
+        }
+
+        Ok(courses)
+    }
+}
+
+/// Inserts the section if it doesn't exist yet, updating its course and section code
+/// otherwise. The term, course, and section code are stored trimmed and uppercase, which is
+/// how they're looked up.
+///
+/// # Parameters
+/// - `tx`: The transaction.
+/// - `term`: The term.
+/// - `section`: The section.
+///
+/// # Returns
+/// The row ID of the section.
+fn upsert_section(tx: &Transaction, term: &str, section: &CourseSection) -> types::Result<i64> {
+    Ok(tx.query_row(
+        "INSERT INTO sections (term, section_id, subj_course_id, section_code) \
+         VALUES (?1, ?2, ?3, ?4) \
+         ON CONFLICT (term, section_id) DO UPDATE \
+         SET subj_course_id = excluded.subj_course_id, section_code = excluded.section_code \
+         RETURNING id",
+        params![
+            normalize(term),
+            section.section_id,
+            normalize(&section.subj_course_id),
+            normalize(&section.section_code)
+        ],
+        |row| row.get(0),
+    )?)
+}
+
+/// Records the enrollment counts of a section, extending the latest point if the counts
+/// haven't changed.
+///
+/// # Parameters
+/// - `tx`: The transaction.
+/// - `section_ref`: The row ID of the section.
+/// - `timestamp`: The time of this snapshot.
+/// - `section`: The section.
+///
+/// # Returns
+/// `true` if a new point was recorded, and `false` if the latest point was extended.
+fn record_enrollment(
+    tx: &Transaction,
+    section_ref: i64,
+    timestamp: i64,
+    section: &CourseSection,
+) -> types::Result<bool> {
+    let latest: Option<(i64, i64, i64, i64, i64, bool)> = tx
+        .query_row(
+            "SELECT id, enrolled, available, waitlist, total, is_visible \
+             FROM enrollment_snapshots WHERE section_ref = ?1 \
+             ORDER BY first_seen DESC, id DESC LIMIT 1",
+            params![section_ref],
+            |row| {
+                Ok((
+                    row.get(0)?,
+                    row.get(1)?,
+                    row.get(2)?,
+                    row.get(3)?,
+                    row.get(4)?,
+                    row.get(5)?,
+                ))
+            },
+        )
+        .optional()?;
+
+    if let Some((id, enrolled, available, waitlist, total, is_visible)) = latest {
+        if enrolled == section.enrolled_ct
+            && available == section.available_seats
+            && waitlist == section.waitlist_ct
+            && total == section.total_seats
+            && is_visible == section.is_visible
+        {
+            tx.execute(
+                "UPDATE enrollment_snapshots SET last_seen = MAX(last_seen, ?1) WHERE id = ?2",
+                params![timestamp, id],
+            )?;
+            return Ok(false);
+        }
+    }
+
+    tx.execute(
+        "INSERT INTO enrollment_snapshots \
+         (section_ref, first_seen, last_seen, enrolled, available, waitlist, total, is_visible) \
+         VALUES (?1, ?2, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            section_ref,
+            timestamp,
+            section.enrolled_ct,
+            section.available_seats,
+            section.waitlist_ct,
+            section.total_seats,
+            section.is_visible
+        ],
+    )?;
+    Ok(true)
+}
+
+/// Gets the row ID of an instructor, inserting the instructor if needed.
+///
+/// # Parameters
+/// - `tx`: The transaction.
+/// - `name`: The instructor's name.
+///
+/// # Returns
+/// The row ID of the instructor.
+fn instructor_id(tx: &Transaction, name: &str) -> types::Result<i64> {
+    tx.execute(
+        "INSERT OR IGNORE INTO instructors (name) VALUES (?1)",
+        params![name],
+    )?;
+    Ok(tx.query_row(
+        "SELECT id FROM instructors WHERE name = ?1",
+        params![name],
+        |row| row.get(0),
+    )?)
+}
+
+/// Gets the version of a section's instructors or meetings that was current at some point in
+/// time.
+///
+/// # Parameters
+/// - `conn`: The connection.
+/// - `table`: The snapshot table (e.g., `MEETING_SNAPSHOTS`).
+/// - `section_ref`: The row ID of the section.
+/// - `timestamp`: The time.
+///
+/// # Returns
+/// The row ID of the latest snapshot first seen at or before that time, if any.
+fn snapshot_at(
+    conn: &Connection,
+    table: &str,
+    section_ref: i64,
+    timestamp: i64,
+) -> types::Result<Option<i64>> {
+    Ok(conn
+        .prepare_cached(&format!(
+            "SELECT id FROM {table} WHERE section_ref = ?1 AND first_seen <= ?2 \
+             ORDER BY first_seen DESC, id DESC LIMIT 1"
+        ))?
+        .query_row(params![section_ref, timestamp], |row| row.get(0))
+        .optional()?)
+}
+
+/// Records a new version of a section's instructors or meetings. The rows of the version
+/// itself still need to be inserted.
+///
+/// # Parameters
+/// - `tx`: The transaction.
+/// - `table`: The snapshot table (e.g., `MEETING_SNAPSHOTS`).
+/// - `section_ref`: The row ID of the section.
+/// - `timestamp`: The time of this snapshot.
+///
+/// # Returns
+/// The row ID of the new snapshot.
+fn insert_snapshot(
+    tx: &Transaction,
+    table: &str,
+    section_ref: i64,
+    timestamp: i64,
+) -> types::Result<i64> {
+    Ok(tx.query_row(
+        &format!(
+            "INSERT INTO {table} (section_ref, first_seen, last_seen) \
+             VALUES (?1, ?2, ?2) RETURNING id"
+        ),
+        params![section_ref, timestamp],
+        |row| row.get(0),
+    )?)
+}
+
+/// Marks a version of a section's instructors or meetings as seen again.
+///
+/// # Parameters
+/// - `tx`: The transaction.
+/// - `table`: The snapshot table (e.g., `MEETING_SNAPSHOTS`).
+/// - `snapshot_ref`: The row ID of the snapshot.
+/// - `timestamp`: The time of this snapshot.
+///
+/// # Returns
+/// Nothing, or an error if one occurred.
+fn extend_snapshot(
+    tx: &Transaction,
+    table: &str,
+    snapshot_ref: i64,
+    timestamp: i64,
+) -> types::Result<()> {
+    tx.execute(
+        &format!("UPDATE {table} SET last_seen = MAX(last_seen, ?1) WHERE id = ?2"),
+        params![timestamp, snapshot_ref],
+    )?;
+    Ok(())
+}
+
+/// Records a new version of a section's instructors.
+///
+/// # Parameters
+/// - `tx`: The transaction.
+/// - `section_ref`: The row ID of the section.
+/// - `timestamp`: The time of this snapshot.
+/// - `names`: The instructors, without duplicates.
+///
+/// # Returns
+/// Nothing, or an error if one occurred.
+fn insert_section_instructors(
+    tx: &Transaction,
+    section_ref: i64,
+    timestamp: i64,
+    names: &[String],
+) -> types::Result<()> {
+    let snapshot_ref = insert_snapshot(tx, INSTRUCTOR_SNAPSHOTS, section_ref, timestamp)?;
+    for (pos, name) in names.iter().enumerate() {
+        tx.execute(
+            "INSERT INTO section_instructors (snapshot_ref, instructor_ref, position) \
+             VALUES (?1, ?2, ?3)",
+            params![snapshot_ref, instructor_id(tx, name)?, pos as i64],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Records a new version of a section's meetings.
+///
+/// # Parameters
+/// - `tx`: The transaction.
+/// - `section_ref`: The row ID of the section.
+/// - `timestamp`: The time of this snapshot.
+/// - `meetings`: The meetings, each without duplicate instructors.
+///
+/// # Returns
+/// Nothing, or an error if one occurred.
+fn insert_meetings(
+    tx: &Transaction,
+    section_ref: i64,
+    timestamp: i64,
+    meetings: &[Meeting],
+) -> types::Result<()> {
+    let snapshot_ref = insert_snapshot(tx, MEETING_SNAPSHOTS, section_ref, timestamp)?;
+    for (pos, meeting) in meetings.iter().enumerate() {
+        let (day_kind, days) = match &meeting.meeting_days {
+            MeetingDay::Repeated(d) => (DAY_KIND_REPEATED, d.join(",")),
+            MeetingDay::OneTime(d) => (DAY_KIND_ONE_TIME, d.to_string()),
+            MeetingDay::None => (DAY_KIND_NONE, String::new()),
+        };
+
+        let meeting_ref: i64 = tx.query_row(
+            "INSERT INTO meetings (snapshot_ref, position, meeting_type, day_kind, days, \
+             start_hr, start_min, end_hr, end_min, building, room) \
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11) RETURNING id",
+            params![
+                snapshot_ref,
+                pos as i64,
+                meeting.meeting_type,
+                day_kind,
+                days,
+                meeting.start_hr,
+                meeting.start_min,
+                meeting.end_hr,
+                meeting.end_min,
+                meeting.building,
+                meeting.room
+            ],
+            |row| row.get(0),
+        )?;
+
+        for (i_pos, name) in meeting.instructors.iter().enumerate() {
+            tx.execute(
+                "INSERT INTO meeting_instructors (meeting_ref, instructor_ref, position) \
+                 VALUES (?1, ?2, ?3)",
+                params![meeting_ref, instructor_id(tx, name)?, i_pos as i64],
+            )?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Normalizes a term, course, or section code so that it's stored and looked up the same way
+/// no matter how it was given.
+///
+/// # Parameters
+/// - `value`: The value (e.g., ` fa23`).
+///
+/// # Returns
+/// The value, trimmed and uppercase (e.g., `FA23`).
+fn normalize(value: &str) -> String {
+    value.trim().to_uppercase()
+}
+
+/// Removes duplicate instructor names, keeping the first occurrence of each.
+///
+/// # Parameters
+/// - `names`: The instructor names.
+///
+/// # Returns
+/// The distinct names, in the order that they were first given.
+fn distinct_names(names: &[String]) -> Vec<String> {
+    let mut seen = HashSet::new();
+    names
+        .iter()
+        .filter(|name| seen.insert(name.as_str()))
+        .cloned()
+        .collect()
+}
+
+/// Loads one version of a section's instructors, in order.
+///
+/// # Parameters
+/// - `conn`: The connection.
+/// - `snapshot_ref`: The row ID of the instructor snapshot.
+///
+/// # Returns
+/// The instructors.
+fn load_section_instructors(conn: &Connection, snapshot_ref: i64) -> types::Result<Vec<String>> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT i.name FROM section_instructors si JOIN instructors i ON i.id = si.instructor_ref \
+         WHERE si.snapshot_ref = ?1 ORDER BY si.position",
+    )?;
+    let names = stmt
+        .query_map(params![snapshot_ref], |row| row.get(0))?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(names)
+}
+
+/// Loads one version of a section's meetings, in order.
+///
+/// # Parameters
+/// - `conn`: The connection.
+/// - `snapshot_ref`: The row ID of the meeting snapshot.
+///
+/// # Returns
+/// The meetings.
+fn load_meetings(conn: &Connection, snapshot_ref: i64) -> types::Result<Vec<Meeting>> {
+    let mut stmt = conn.prepare_cached(
+        "SELECT id, meeting_type, day_kind, days, start_hr, start_min, end_hr, end_min, \
+         building, room FROM meetings WHERE snapshot_ref = ?1 ORDER BY position",
+    )?;
+    let rows = stmt
+        .query_map(params![snapshot_ref], |row| {
+            let day_kind: String = row.get(2)?;
+            let days: String = row.get(3)?;
+            Ok((
+                row.get::<_, i64>(0)?,
+                Meeting {
+                    meeting_type: row.get(1)?,
+                    meeting_days: match day_kind.as_str() {
+                        DAY_KIND_REPEATED if days.is_empty() => MeetingDay::Repeated(vec![]),
+                        DAY_KIND_REPEATED => {
+                            MeetingDay::Repeated(days.split(',').map(|d| d.to_string()).collect())
+                        }
+                        DAY_KIND_ONE_TIME => MeetingDay::OneTime(days),
+                        _ => MeetingDay::None,
+                    },
+                    start_hr: row.get::<_, TimeType>(4)?,
+                    start_min: row.get::<_, TimeType>(5)?,
+                    end_hr: row.get::<_, TimeType>(6)?,
+                    end_min: row.get::<_, TimeType>(7)?,
+                    building: row.get(8)?,
+                    room: row.get(9)?,
+                    instructors: vec![],
+                },
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let mut inst_stmt = conn.prepare_cached(
+        "SELECT i.name FROM meeting_instructors mi JOIN instructors i ON i.id = mi.instructor_ref \
+         WHERE mi.meeting_ref = ?1 ORDER BY mi.position",
+    )?;
+    let mut meetings = vec![];
+    for (meeting_ref, mut meeting) in rows {
+        meeting.instructors = inst_stmt
+            .query_map(params![meeting_ref], |row| row.get(0))?
+            .collect::<Result<Vec<_>, _>>()?;
+        meetings.push(meeting);
+    }
+
+    Ok(meetings)
+}
+
+/// Loads a section, along with the enrollment counts, meetings, and instructors that were
+/// current at some point in time.
+///
+/// # Parameters
+/// - `conn`: The connection.
+/// - `section_ref`: The row ID of the section.
+/// - `timestamp`: The time.
+///
+/// # Returns
+/// The section, or `None` if it has no enrollment data from at or before that time.
+fn load_section(
+    conn: &Connection,
+    section_ref: i64,
+    timestamp: i64,
+) -> types::Result<Option<CourseSection>> {
+    let section = conn
+        .query_row(
+            "SELECT s.subj_course_id, s.section_id, s.section_code, \
+             e.enrolled, e.available, e.waitlist, e.total, e.is_visible \
+             FROM sections s JOIN enrollment_snapshots e ON e.section_ref = s.id \
+             WHERE s.id = ?1 AND e.first_seen <= ?2 \
+             ORDER BY e.first_seen DESC, e.id DESC LIMIT 1",
+            params![section_ref, timestamp],
+            |row| {
+                Ok(CourseSection {
+                    subj_course_id: row.get(0)?,
+                    section_id: row.get(1)?,
+                    section_code: row.get(2)?,
+                    all_instructors: vec![],
+                    enrolled_ct: row.get(3)?,
+                    available_seats: row.get(4)?,
+                    waitlist_ct: row.get(5)?,
+                    total_seats: row.get(6)?,
+                    meetings: vec![],
+                    is_visible: row.get(7)?,
+                })
+            },
+        )
+        .optional()?;
+
+    match section {
+        Some(mut s) => {
+            if let Some(r) = snapshot_at(conn, INSTRUCTOR_SNAPSHOTS, section_ref, timestamp)? {
+                s.all_instructors = load_section_instructors(conn, r)?;
+            }
+            if let Some(r) = snapshot_at(conn, MEETING_SNAPSHOTS, section_ref, timestamp)? {
+                s.meetings = load_meetings(conn, r)?;
+            }
+            Ok(Some(s))
+        }
+        None => Ok(None),
+    }
+}
+
+// Section IDs are stored in their zero-padded form.
+impl ToSql for SectionId {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.padded()))
+    }
+}
+
+impl FromSql for SectionId {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        value
+            .as_str()?
+            .parse()
+            .map_err(|e| FromSqlError::Other(Box::new(e)))
+    }
+}
@@ -1,8 +1,14 @@
+use std::borrow::Cow;
+use std::convert::Infallible;
 use std::fmt::{Display, Formatter};
+use std::str::FromStr;
 
-use serde::Serialize;
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
 use thiserror::Error;
 
+use crate::util;
+use crate::wrapper::input_types::{DayOfWeek, EventAdd};
+
 /// The generic type is the return value. Otherwise, regardless of request type,
 /// we're just returning the error string if there is an error.
 pub type Result<T, E = WrapperError> = std::result::Result<T, E>;
@@ -43,13 +49,519 @@ impl Display for SearchResultItem {
     }
 }
 
+/// A subject that has at least one course offered in a term.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct Subject {
+    /// The subject code. For example, `CSE`.
+    pub code: String,
+    /// The subject description. For example, `Computer Science & Engineering`.
+    pub description: String,
+}
+
+impl Subject {
+    /// Finds the subject with the specified code, ignoring case.
+    ///
+    /// # Parameters
+    /// - `subjects`: The subjects to search through.
+    /// - `code`: The subject code. For example, `CSE`.
+    ///
+    /// # Returns
+    /// The subject, if found.
+    pub fn find(subjects: &[Subject], code: impl AsRef<str>) -> Option<&Subject> {
+        let code = code.as_ref().trim();
+        subjects.iter().find(|s| s.code.eq_ignore_ascii_case(code))
+    }
+}
+
+impl Display for Subject {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.code, self.description)
+    }
+}
+
+/// A department that is offering courses in a term.
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Serialize)]
+pub struct Department {
+    /// The department code. For example, `CSE`.
+    pub code: String,
+    /// The department description. For example, `Computer Science & Engineering`.
+    pub description: String,
+}
+
+impl Department {
+    /// Finds the department with the specified code, ignoring case.
+    ///
+    /// # Parameters
+    /// - `departments`: The departments to search through.
+    /// - `code`: The department code. For example, `CSE`.
+    ///
+    /// # Returns
+    /// The department, if found.
+    pub fn find(departments: &[Department], code: impl AsRef<str>) -> Option<&Department> {
+        let code = code.as_ref().trim();
+        departments
+            .iter()
+            .find(|d| d.code.eq_ignore_ascii_case(code))
+    }
+}
+
+impl Display for Department {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} - {}", self.code, self.description)
+    }
+}
+
+/// A section ID, like `079911`.
+///
+/// WebReg isn't consistent with how it formats section IDs; some endpoints give you a
+/// zero-padded string (e.g., `079911`), while others give you a number (e.g., `79911`).
+/// This type stores the numeric value, so two section IDs are equal regardless of how they
+/// were formatted. By default, section IDs are displayed and serialized in the zero-padded
+/// form.
+///
+/// # Example
+/// ```rust
+/// use webweg::types::SectionId;
+///
+/// let a: SectionId = "079911".parse().unwrap();
+/// let b = SectionId::from(79911);
+/// assert_eq!(a, b);
+/// assert_eq!("079911", a.to_string());
+/// assert_eq!("79911", a.unpadded());
+/// assert_eq!(a, "79911");
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct SectionId(u32);
+
+impl SectionId {
+    /// Creates a new section ID.
+    ///
+    /// # Parameters
+    /// - `id`: The numeric section ID (e.g., `79911`).
+    ///
+    /// # Returns
+    /// The section ID.
+    pub fn new(id: u32) -> Self {
+        Self(id)
+    }
+
+    /// Gets the numeric value of this section ID.
+    ///
+    /// # Returns
+    /// The numeric value (e.g., `79911`).
+    pub fn value(&self) -> u32 {
+        self.0
+    }
+
+    /// Gets the zero-padded form of this section ID, which is what most of WebReg uses.
+    ///
+    /// # Returns
+    /// The zero-padded form (e.g., `079911`).
+    pub fn padded(&self) -> String {
+        format!("{:06}", self.0)
+    }
+
+    /// Gets the form of this section ID without any leading zeros, which is what the
+    /// schedule endpoint uses.
+    ///
+    /// # Returns
+    /// The form without leading zeros (e.g., `79911`).
+    pub fn unpadded(&self) -> String {
+        self.0.to_string()
+    }
+}
+
+impl Display for SectionId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:06}", self.0)
+    }
+}
+
+impl FromStr for SectionId {
+    type Err = WrapperError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let s = s.trim();
+        if s.is_empty() || !s.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(WrapperError::InputError(
+                "section_id",
+                "section IDs must be non-empty and only contain digits.",
+            ));
+        }
+
+        s.parse::<u32>()
+            .map(Self)
+            .map_err(|_| WrapperError::InputError("section_id", "section ID is too large."))
+    }
+}
+
+impl From<u32> for SectionId {
+    fn from(id: u32) -> Self {
+        Self(id)
+    }
+}
+
+impl TryFrom<i64> for SectionId {
+    type Error = WrapperError;
+
+    fn try_from(id: i64) -> Result<Self> {
+        u32::try_from(id)
+            .map(Self)
+            .map_err(|_| WrapperError::InputError("section_id", "section ID is out of range."))
+    }
+}
+
+impl TryFrom<&str> for SectionId {
+    type Error = WrapperError;
+
+    fn try_from(id: &str) -> Result<Self> {
+        id.parse()
+    }
+}
+
+impl TryFrom<String> for SectionId {
+    type Error = WrapperError;
+
+    fn try_from(id: String) -> Result<Self> {
+        id.parse()
+    }
+}
+
+impl TryFrom<&String> for SectionId {
+    type Error = WrapperError;
+
+    fn try_from(id: &String) -> Result<Self> {
+        id.parse()
+    }
+}
+
+impl PartialEq<str> for SectionId {
+    fn eq(&self, other: &str) -> bool {
+        other.parse::<SectionId>().is_ok_and(|id| id == *self)
+    }
+}
+
+impl PartialEq<&str> for SectionId {
+    fn eq(&self, other: &&str) -> bool {
+        self == *other
+    }
+}
+
+impl PartialEq<SectionId> for str {
+    fn eq(&self, other: &SectionId) -> bool {
+        other == self
+    }
+}
+
+impl PartialEq<SectionId> for &str {
+    fn eq(&self, other: &SectionId) -> bool {
+        other == *self
+    }
+}
+
+impl Serialize for SectionId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for SectionId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Raw {
+            Str(String),
+            Num(u32),
+        }
+
+        match Raw::deserialize(deserializer)? {
+            Raw::Str(s) => s.parse().map_err(serde::de::Error::custom),
+            Raw::Num(n) => Ok(Self(n)),
+        }
+    }
+}
+
+/// A course identifier, like `CSE 100` or `MATH 20C`.
+///
+/// A course ID consists of a subject code (e.g., `MATH`), a course number (e.g., `20`), and
+/// an optional suffix (e.g., `C`). Course IDs are compared naturally, so `CSE 2` comes before
+/// `CSE 10`, which comes before `CSE 100` and then `CSE 100A`.
+///
+/// # Example
+/// ```rust
+/// use webweg::types::CourseId;
+///
+/// let id: CourseId = "math20c".parse().unwrap();
+/// assert_eq!("MATH", id.subject());
+/// assert_eq!(20, id.number());
+/// assert_eq!("C", id.suffix());
+/// assert_eq!("MATH 20C", id.to_string());
+/// assert_eq!(" 20C", id.formatted_course_num());
+/// assert!("CSE 2".parse::<CourseId>().unwrap() < "CSE 10".parse().unwrap());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct CourseId {
+    subject: String,
+    number: u32,
+    suffix: String,
+}
+
+impl CourseId {
+    /// Creates a new course ID.
+    ///
+    /// # Parameters
+    /// - `subject`: The subject code (e.g., `MATH`).
+    /// - `number`: The course number (e.g., `20`).
+    /// - `suffix`: The suffix of the course number (e.g., `C`), or an empty string if there
+    ///   is none.
+    ///
+    /// # Returns
+    /// The course ID, or `None` if the subject code is empty or either the subject code or
+    /// suffix contains anything other than letters.
+    pub fn new(subject: impl AsRef<str>, number: u32, suffix: impl AsRef<str>) -> Option<Self> {
+        let subject = subject.as_ref().trim();
+        let suffix = suffix.as_ref().trim();
+        if subject.is_empty()
+            || !subject.bytes().all(|b| b.is_ascii_alphabetic())
+            || !suffix.bytes().all(|b| b.is_ascii_alphabetic())
+        {
+            return None;
+        }
+
+        Some(Self {
+            subject: subject.to_uppercase(),
+            number,
+            suffix: suffix.to_uppercase(),
+        })
+    }
+
+    /// Gets the subject code of this course.
+    ///
+    /// # Returns
+    /// The subject code (e.g., `MATH`).
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Gets the numeric part of this course's number.
+    ///
+    /// # Returns
+    /// The course number (e.g., `20`).
+    pub fn number(&self) -> u32 {
+        self.number
+    }
+
+    /// Gets the suffix of this course's number.
+    ///
+    /// # Returns
+    /// The suffix (e.g., `C`), or an empty string if there is none.
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Gets the course number along with its suffix.
+    ///
+    /// # Returns
+    /// The course number (e.g., `20C`).
+    pub fn course_num(&self) -> String {
+        format!("{}{}", self.number, self.suffix)
+    }
+
+    /// Gets the course number in the padded form that WebReg expects (e.g., when
+    /// searching for a course).
+    ///
+    /// # Returns
+    /// The padded course number (e.g., ` 20C`).
+    pub fn formatted_course_num(&self) -> String {
+        format!("{:>3}{}", self.number, self.suffix)
+    }
+}
+
+impl Display for CourseId {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}{}", self.subject, self.number, self.suffix)
+    }
+}
+
+impl FromStr for CourseId {
+    type Err = WrapperError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        // Accepts both `CSE 100` and `CSE100`, along with any extra whitespace.
+        let s = s.split_whitespace().collect::<String>();
+        let num_start = s
+            .find(|c: char| c.is_ascii_digit())
+            .ok_or(WrapperError::InputError(
+                "course_id",
+                "course IDs must contain a course number.",
+            ))?;
+        let (subject, rest) = s.split_at(num_start);
+        let num_end = rest
+            .find(|c: char| !c.is_ascii_digit())
+            .unwrap_or(rest.len());
+        let (number, suffix) = rest.split_at(num_end);
+
+        let number = number
+            .parse::<u32>()
+            .map_err(|_| WrapperError::InputError("course_id", "course number is too large."))?;
+        Self::new(subject, number, suffix).ok_or(WrapperError::InputError(
+            "course_id",
+            "course IDs must be of the form `SUBJ NUM`, e.g., `CSE 100` or `MATH 20C`.",
+        ))
+    }
+}
+
+impl TryFrom<&str> for CourseId {
+    type Error = WrapperError;
+
+    fn try_from(id: &str) -> Result<Self> {
+        id.parse()
+    }
+}
+
+impl From<CourseId> for String {
+    fn from(id: CourseId) -> Self {
+        id.to_string()
+    }
+}
+
+impl From<&CourseId> for String {
+    fn from(id: &CourseId) -> Self {
+        id.to_string()
+    }
+}
+
+impl Serialize for CourseId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for CourseId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        String::deserialize(deserializer)?
+            .parse()
+            .map_err(serde::de::Error::custom)
+    }
+}
+
+/// A course to look up, given either as a [`CourseId`] or as a subject code and course
+/// number pair (e.g., `("MATH", "100B")`). Functions that look up a course take anything that
+/// can be converted into this. Either way, the course is trimmed, made uppercase, and padded
+/// before it's sent to WebReg, so `("math", " 20c")` is the same as `MATH 20C`.
+///
+/// # Example
+/// ```rust
+/// use webweg::types::{CourseId, CourseKey};
+///
+/// let from_pair = CourseKey::from(("math", " 20c"));
+/// let id: CourseId = "MATH 20C".parse().unwrap();
+/// let from_id = CourseKey::from(&id);
+///
+/// assert_eq!("MATH 20C", from_pair.to_string());
+/// assert_eq!(from_pair.to_string(), from_id.to_string());
+/// ```
+#[derive(Debug, Clone, Eq, PartialEq)]
+pub struct CourseKey<'a> {
+    subject: Cow<'a, str>,
+    course_num: Cow<'a, str>,
+}
+
+impl<'a> CourseKey<'a> {
+    /// Creates a new course key.
+    ///
+    /// # Parameters
+    /// - `subject`: The subject code (e.g., `MATH`).
+    /// - `course_num`: The course number (e.g., `100B`).
+    ///
+    /// # Returns
+    /// The course key.
+    pub fn new(subject: impl Into<Cow<'a, str>>, course_num: impl Into<Cow<'a, str>>) -> Self {
+        Self {
+            subject: subject.into(),
+            course_num: course_num.into(),
+        }
+    }
+
+    /// Gets the subject code of this course, as given.
+    ///
+    /// # Returns
+    /// The subject code (e.g., `MATH`).
+    pub fn subject(&self) -> &str {
+        &self.subject
+    }
+
+    /// Gets the course number of this course, as given.
+    ///
+    /// # Returns
+    /// The course number (e.g., `100B`).
+    pub fn course_num(&self) -> &str {
+        &self.course_num
+    }
+
+    /// Gets the subject code and course number in the form that WebReg expects, i.e.,
+    /// trimmed, uppercase, and with the course number padded.
+    ///
+    /// # Returns
+    /// The subject code and padded course number (e.g., `("MATH", " 20C")`).
+    pub(crate) fn to_wire(&self) -> (String, String) {
+        match format!("{} {}", self.subject, self.course_num).parse::<CourseId>() {
+            Ok(id) => {
+                let course_num = id.formatted_course_num();
+                (id.subject, course_num)
+            }
+            // Not every course number that WebReg knows about is of the form that `CourseId`
+            // accepts, so anything else is sent mostly as given.
+            Err(_) => (
+                self.subject.trim().to_uppercase(),
+                util::get_formatted_course_num(&self.course_num.trim().to_uppercase()),
+            ),
+        }
+    }
+}
+
+/// Displays the course like `MATH 100B`, trimmed and uppercase.
+impl Display for CourseKey<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{} {}",
+            self.subject.trim().to_uppercase(),
+            self.course_num.trim().to_uppercase()
+        )
+    }
+}
+
+impl<'a, S, C> From<(S, C)> for CourseKey<'a>
+where
+    S: Into<Cow<'a, str>>,
+    C: Into<Cow<'a, str>>,
+{
+    fn from((subject, course_num): (S, C)) -> Self {
+        Self::new(subject, course_num)
+    }
+}
+
+impl<'a> From<&'a CourseId> for CourseKey<'a> {
+    fn from(id: &'a CourseId) -> Self {
+        Self::new(id.subject(), id.course_num())
+    }
+}
+
+impl From<CourseId> for CourseKey<'static> {
+    fn from(id: CourseId) -> Self {
+        let course_num = id.course_num();
+        Self::new(id.subject, course_num)
+    }
+}
+
 /// A section, which consists of a lecture, usually a discussion, and usually a final.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
 pub struct CourseSection {
     /// The subject, course ID. For example, `CSE 100`.
     pub subj_course_id: String,
     /// The section ID. For example, `079912`.
-    pub section_id: String,
+    pub section_id: SectionId,
     /// The section code. For example, `B01`.
     pub section_code: String,
     /// All instructors (i.e., all of the instructors that appear in the `meetings`).
@@ -172,8 +684,8 @@ impl Display for Meeting {
 /// are enrolled in, waitlisted for, or planned.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
 pub struct ScheduledSection {
-    /// The section ID, for example `79903`.
-    pub section_id: String,
+    /// The section ID, for example `079903`.
+    pub section_id: SectionId,
     /// The subject code. For example, if this represents `CSE 100`, then this would be `CSE`.
     pub subject_code: String,
     /// The subject code. For example, if this represents `CSE 100`, then this would be `100`.
@@ -242,6 +754,106 @@ impl Display for ScheduledSection {
     }
 }
 
+/// A response from WebReg, untouched.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RawResponse {
+    /// The HTTP status code.
+    pub status: u16,
+    /// The response headers, in the order that they were received.
+    pub headers: Vec<(String, String)>,
+    /// The response body. For most endpoints, this is JSON.
+    pub body: String,
+}
+
+impl RawResponse {
+    /// Gets the value of a header. Header names are case-insensitive.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the header.
+    ///
+    /// # Returns
+    /// The value of the first header with that name, if any.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Whether the status code indicates success (i.e., is `2xx`). Keep in mind that WebReg
+    /// will often respond with a successful status code even if the request itself failed;
+    /// in that case, the reason is given in the body.
+    ///
+    /// # Returns
+    /// `true` if the status code indicates success, and `false` otherwise.
+    pub fn is_success(&self) -> bool {
+        (200..300).contains(&self.status)
+    }
+
+    /// Parses the body as JSON.
+    ///
+    /// # Returns
+    /// The JSON, or an error if the body isn't valid JSON.
+    pub fn json(&self) -> Result<serde_json::Value> {
+        Ok(serde_json::from_str(&self.body)?)
+    }
+}
+
+/// The result of a single section in a bulk plan operation (e.g., copying a schedule).
+#[derive(Debug)]
+pub struct PlanItemResult {
+    /// The section ID of the section that was operated on.
+    pub section_id: SectionId,
+    /// The result of the operation. If this is an error, it is usually a `WebRegError`
+    /// containing the reason WebReg gave.
+    pub result: Result<bool>,
+}
+
+impl PlanItemResult {
+    /// Whether the operation on this section succeeded.
+    ///
+    /// # Returns
+    /// `true` if the operation succeeded, and `false` otherwise.
+    pub fn is_success(&self) -> bool {
+        matches!(self.result, Ok(true))
+    }
+}
+
+/// A report of a bulk plan operation, with one result per section. Bulk operations do not
+/// stop at the first failure, so some sections may succeed while others fail.
+#[derive(Debug, Default)]
+pub struct PlanReport {
+    /// The results, in the order that the sections were processed.
+    pub items: Vec<PlanItemResult>,
+}
+
+impl PlanReport {
+    /// Whether every operation in this report succeeded.
+    ///
+    /// # Returns
+    /// `true` if every operation succeeded (or if there were no operations), and `false`
+    /// otherwise.
+    pub fn is_success(&self) -> bool {
+        self.items.iter().all(PlanItemResult::is_success)
+    }
+
+    /// Gets the results of all operations that succeeded.
+    ///
+    /// # Returns
+    /// An iterator over the successful results.
+    pub fn succeeded(&self) -> impl Iterator<Item = &PlanItemResult> {
+        self.items.iter().filter(|i| i.is_success())
+    }
+
+    /// Gets the results of all operations that failed.
+    ///
+    /// # Returns
+    /// An iterator over the failed results.
+    pub fn failed(&self) -> impl Iterator<Item = &PlanItemResult> {
+        self.items.iter().filter(|i| !i.is_success())
+    }
+}
+
 /// An enum that represents your enrollment status.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
 #[serde(tag = "enroll_status")]
@@ -273,6 +885,63 @@ pub struct PrerequisiteInfo {
     pub exam_prerequisites: Vec<String>,
 }
 
+impl PrerequisiteInfo {
+    /// Checks whether the specified completed courses and passed exams satisfy these
+    /// prerequisites. Passing any one of the exam prerequisites satisfies all course
+    /// prerequisites; otherwise, every group of course prerequisites must be satisfied by
+    /// at least one completed course.
+    ///
+    /// # Parameters
+    /// - `completed`: The courses that the student has completed.
+    /// - `exams_passed`: The exams that the student has passed (e.g., `AP-Math BC`).
+    ///
+    /// # Returns
+    /// `true` if the prerequisites are satisfied, and `false` otherwise.
+    pub fn is_satisfied_by(
+        &self,
+        completed: &[CompletedCourse],
+        exams_passed: &[impl AsRef<str>],
+    ) -> bool {
+        self.unmet_requirements(completed, exams_passed).is_empty()
+    }
+
+    /// Gets all groups of course prerequisites that are not satisfied by the specified
+    /// completed courses and passed exams. If an exam prerequisite was passed, nothing is
+    /// returned since exam prerequisites satisfy all course prerequisites.
+    ///
+    /// # Parameters
+    /// - `completed`: The courses that the student has completed.
+    /// - `exams_passed`: The exams that the student has passed (e.g., `AP-Math BC`).
+    ///
+    /// # Returns
+    /// The unmet groups, where the student needs to complete one course from each group.
+    pub fn unmet_requirements(
+        &self,
+        completed: &[CompletedCourse],
+        exams_passed: &[impl AsRef<str>],
+    ) -> Vec<Vec<CoursePrerequisite>> {
+        let passed_exam = self.exam_prerequisites.iter().any(|exam| {
+            exams_passed
+                .iter()
+                .any(|p| p.as_ref().trim().eq_ignore_ascii_case(exam.trim()))
+        });
+
+        if passed_exam {
+            return vec![];
+        }
+
+        self.course_prerequisites
+            .iter()
+            .filter(|group| {
+                !group
+                    .iter()
+                    .any(|prereq| completed.iter().any(|c| prereq.is_satisfied_by(c)))
+            })
+            .cloned()
+            .collect()
+    }
+}
+
 /// A course prerequisite.
 #[derive(Debug, Clone, Eq, PartialEq, Serialize)]
 pub struct CoursePrerequisite {
@@ -281,6 +950,11 @@ pub struct CoursePrerequisite {
 
     /// The course title, for example `Advanced Data Structure`.
     pub course_title: String,
+
+    /// The minimum grade needed in this course, as WebReg's grade sequence ID (for example,
+    /// `600`). This is `None` if there is no minimum grade. See
+    /// [`CoursePrerequisite::meets_min_grade`] for how these are compared.
+    pub min_grade_seq_id: Option<u32>,
 }
 
 impl CoursePrerequisite {
@@ -296,8 +970,102 @@ impl CoursePrerequisite {
         Self {
             subj_course_id: subj_course_id.into(),
             course_title: course_title.into(),
+            min_grade_seq_id: None,
+        }
+    }
+
+    /// Sets the minimum grade needed in this course.
+    ///
+    /// # Parameters
+    /// - `grade_seq_id`: The minimum grade, as WebReg's grade sequence ID.
+    ///
+    /// # Returns
+    /// The `CoursePrerequisite` object.
+    pub fn with_min_grade(mut self, grade_seq_id: u32) -> Self {
+        self.min_grade_seq_id = Some(grade_seq_id);
+        self
+    }
+
+    /// Checks whether the specified completed course satisfies this prerequisite.
+    ///
+    /// A completed course satisfies this prerequisite if it's the same course and, if both
+    /// a minimum grade and the earned grade are known, the earned grade meets the minimum
+    /// grade (see [`CoursePrerequisite::meets_min_grade`]).
+    ///
+    /// # Parameters
+    /// - `course`: The completed course.
+    ///
+    /// # Returns
+    /// `true` if the completed course satisfies this prerequisite, and `false` otherwise.
+    pub fn is_satisfied_by(&self, course: &CompletedCourse) -> bool {
+        if !util::is_same_subj_course_id(&self.subj_course_id, &course.subj_course_id) {
+            return false;
+        }
+
+        match (self.min_grade_seq_id, course.grade_seq_id) {
+            (Some(min), Some(earned)) => Self::meets_min_grade(earned, min),
+            _ => true,
+        }
+    }
+
+    /// Checks whether an earned grade meets a minimum grade, where both are grade sequence
+    /// IDs.
+    ///
+    /// WebReg doesn't document its grade sequence IDs. The only source for them is WebReg's
+    /// prerequisite responses (see `tests/json/prereq*.json`), where the IDs are always
+    /// multiples of 50 (usually `450` or `600`). This assumes that they form an ordered
+    /// scale where a _higher_ ID is a _better_ grade, so an earned grade meets the minimum
+    /// if its ID is at least the minimum's. If you track grades on another scale (e.g.,
+    /// letter grades), convert them to WebReg's IDs before comparing.
+    ///
+    /// # Parameters
+    /// - `earned`: The grade earned.
+    /// - `min`: The minimum grade.
+    ///
+    /// # Returns
+    /// `true` if the earned grade is at least as good as the minimum grade.
+    pub fn meets_min_grade(earned: u32, min: u32) -> bool {
+        earned >= min
+    }
+}
+
+/// A course that a student has completed.
+#[derive(Debug, Clone, Eq, PartialEq, Serialize)]
+pub struct CompletedCourse {
+    /// The subject, course ID. For example, `CSE 100`.
+    pub subj_course_id: String,
+
+    /// The grade earned, using the same scale as `CoursePrerequisite::min_grade_seq_id`. If
+    /// this is `None`, the course is assumed to meet any minimum grade.
+    pub grade_seq_id: Option<u32>,
+}
+
+impl CompletedCourse {
+    /// Creates a new `CompletedCourse` object, without a known grade.
+    ///
+    /// # Parameters
+    /// - `subj_course_id`: The subject, course ID (e.g., `CSE 100`)
+    ///
+    /// # Returns
+    /// The new `CompletedCourse` object.
+    pub fn new(subj_course_id: impl Into<String>) -> Self {
+        Self {
+            subj_course_id: subj_course_id.into(),
+            grade_seq_id: None,
         }
     }
+
+    /// Sets the grade earned in this course.
+    ///
+    /// # Parameters
+    /// - `grade_seq_id`: The grade earned, as a grade sequence ID.
+    ///
+    /// # Returns
+    /// The `CompletedCourse` object.
+    pub fn with_grade(mut self, grade_seq_id: u32) -> Self {
+        self.grade_seq_id = Some(grade_seq_id);
+        self
+    }
 }
 
 /// An event on WebReg.
@@ -336,7 +1104,155 @@ impl Display for Event {
     }
 }
 
+impl Event {
+    /// Converts this event into an `EventAdd`, which can be modified and then given to
+    /// `add_or_edit_event` (along with this event's timestamp) to edit this event.
+    ///
+    /// # Returns
+    /// The `EventAdd`, or an error if one of this event's days isn't recognized.
+    ///
+    /// # Example
+    /// ```rust
+    /// use webweg::types::Event;
+    ///
+    /// let event = Event {
+    ///     location: "RIMAC".into(),
+    ///     start_hr: 8,
+    ///     start_min: 0,
+    ///     end_hr: 9,
+    ///     end_min: 0,
+    ///     name: "Gym".into(),
+    ///     days: vec!["M".into(), "W".into()],
+    ///     timestamp: "2022-09-09 21:50:16.846885".into(),
+    /// };
+    ///
+    /// // Move the event 45 minutes later, carrying the minutes into the hour.
+    /// let mut event_add = event.to_event_add().unwrap();
+    /// let start = event_add.start_hr * 60 + event_add.start_min + 45;
+    /// let end = event_add.end_hr * 60 + event_add.end_min + 45;
+    /// (event_add.start_hr, event_add.start_min) = (start / 60, start % 60);
+    /// (event_add.end_hr, event_add.end_min) = (end / 60, end % 60);
+    /// assert_eq!((8, 45, 9, 45), (event_add.start_hr, event_add.start_min, event_add.end_hr, event_add.end_min));
+    /// assert!(event_add.validate().is_ok());
+    /// ```
+    pub fn to_event_add(&self) -> Result<EventAdd<'_>> {
+        Ok(EventAdd {
+            event_name: Cow::Borrowed(self.name.as_str()),
+            location: if self.location.is_empty() {
+                None
+            } else {
+                Some(Cow::Borrowed(self.location.as_str()))
+            },
+            event_days: self
+                .days
+                .iter()
+                .map(|d| d.parse::<DayOfWeek>())
+                .collect::<Result<Vec<_>>>()?,
+            start_hr: self.start_hr,
+            start_min: self.start_min,
+            end_hr: self.end_hr,
+            end_min: self.end_min,
+        })
+    }
+}
+
+/// The result of a single event in a bulk event operation (e.g., importing events).
+#[derive(Debug)]
+pub struct EventItemResult {
+    /// The name of the event that was operated on.
+    pub event_name: String,
+    /// The result of the operation. If this is an error, it is usually a `WebRegError`
+    /// containing the reason WebReg gave.
+    pub result: Result<bool>,
+}
+
+impl EventItemResult {
+    /// Whether the operation on this event succeeded.
+    ///
+    /// # Returns
+    /// `true` if the operation succeeded, and `false` otherwise.
+    pub fn is_success(&self) -> bool {
+        matches!(self.result, Ok(true))
+    }
+}
+
+/// A report of a bulk event operation. Bulk operations do not stop at the first failure,
+/// so some events may succeed while others fail.
+#[derive(Debug, Default)]
+pub struct EventReport {
+    /// The results of removing existing events, in the order they were removed.
+    pub removed: Vec<EventItemResult>,
+    /// The results of adding new events, in the order they were given.
+    pub added: Vec<EventItemResult>,
+}
+
+impl EventReport {
+    /// Whether every operation in this report succeeded.
+    ///
+    /// # Returns
+    /// `true` if every operation succeeded (or if there were no operations), and `false`
+    /// otherwise.
+    pub fn is_success(&self) -> bool {
+        self.removed
+            .iter()
+            .chain(self.added.iter())
+            .all(EventItemResult::is_success)
+    }
+
+    /// Gets the results of all operations that failed.
+    ///
+    /// # Returns
+    /// An iterator over the failed results, with removals first.
+    pub fn failed(&self) -> impl Iterator<Item = &EventItemResult> {
+        self.removed
+            .iter()
+            .chain(self.added.iter())
+            .filter(|i| !i.is_success())
+    }
+}
+
+/// The result of parsing a WebReg response leniently. Any record that couldn't be parsed
+/// is skipped, and the reason is kept as a warning.
+#[derive(Debug)]
+pub struct LenientParse<T> {
+    /// Everything that could be parsed.
+    pub parsed: T,
+    /// Why each skipped record couldn't be parsed.
+    pub warnings: Vec<ParseWarning>,
+}
+
+/// A record that was skipped while parsing a WebReg response leniently.
+#[derive(Debug)]
+pub struct ParseWarning {
+    /// The section that the record belongs to, if known. This is `None` for records that
+    /// aren't sections (e.g., events), or if the section ID itself couldn't be parsed.
+    pub section_id: Option<SectionId>,
+    /// Why the record couldn't be parsed. This is usually a `WrapperParsingError`
+    /// describing the field and record that had a problem.
+    pub error: WrapperError,
+}
+
+impl Display for ParseWarning {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.error.fmt(f)
+    }
+}
+
+impl<T> LenientParse<T> {
+    /// Whether every record was parsed.
+    ///
+    /// # Returns
+    /// `true` if there are no warnings, and `false` otherwise.
+    pub fn is_clean(&self) -> bool {
+        self.warnings.is_empty()
+    }
+}
+
+/// An error that can occur when using this crate. Some variants only exist when certain
+/// features are enabled (e.g., `StorageError` with `storage-sqlite`), so this enum is
+/// non-exhaustive and matches on it need a wildcard arm.
 #[derive(Error, Debug)]
+#[non_exhaustive]
 pub enum WrapperError {
     /// Occurs if there was an error encountered by the reqwest library.
     #[error("Request error occurred: {0}")]
@@ -375,15 +1291,24 @@ pub enum WrapperError {
 
     /// Occurs if a section that you're trying to look for isn't available.
     #[error("Section ID not found: {0} (context: {1}")]
-    SectionIdNotFound(String, SectionIdNotFoundContext),
+    SectionIdNotFound(SectionId, SectionIdNotFoundContext),
 
     /// Occurs if there's an error with the parsing logic.
     #[error("An error occurred when parsing the response from WebReg: {0}")]
     WrapperParsingError(String),
 
+    /// Occurs when a term code (e.g., `FA23`) could not be parsed.
+    #[error("Invalid term code '{0}': {1}")]
+    TermParseError(String, &'static str),
+
     /// Occurs when your cookies may have expired.
     #[error("The current session is not valid. Are your cookies valid?")]
     SessionNotValid,
+
+    /// Occurs if there was an error reading from, or writing to, the local SQLite storage.
+    #[cfg(feature = "storage-sqlite")]
+    #[error("Storage error occurred: {0}")]
+    StorageError(#[from] rusqlite::Error),
 }
 
 /// An enum to be used for giving more context into where the section ID wasn't found.
@@ -406,6 +1331,14 @@ impl Display for SectionIdNotFoundContext {
     }
 }
 
+// Lets conversions that can't fail (e.g., a `SectionId` into itself) be used wherever a
+// conversion that fails with a `WrapperError` is accepted.
+impl From<Infallible> for WrapperError {
+    fn from(e: Infallible) -> Self {
+        match e {}
+    }
+}
+
 /// A term that is available on WebReg.
 #[derive(Debug, Clone, Serialize)]
 pub struct Term {
@@ -413,4 +1346,284 @@ pub struct Term {
     pub seq_id: i64,
     /// The term code (e.g., `SP23`).
     pub term_code: String,
+    /// The human-readable name of the term (e.g., `Spring 2023`).
+    pub term_desc: String,
+}
+
+impl Term {
+    /// Parses the term code of this term.
+    ///
+    /// # Returns
+    /// The term code, or an error if WebReg gave a term code that isn't recognized.
+    pub fn code(&self) -> Result<TermCode> {
+        self.term_code.parse()
+    }
+}
+
+impl From<TermCode> for Term {
+    fn from(code: TermCode) -> Self {
+        Self {
+            seq_id: code.seq_id(),
+            term_code: code.to_string(),
+            term_desc: code.description(),
+        }
+    }
+}
+
+/// A quarter (or summer session) at UCSD, in the order that they occur in a calendar year.
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub enum Quarter {
+    /// Winter quarter (`WI`).
+    Winter,
+    /// Spring quarter (`SP`).
+    Spring,
+    /// Summer Session I (`S1`).
+    SummerSession1,
+    /// Summer Session II (`S2`).
+    SummerSession2,
+    /// Summer Session III (`S3`).
+    SummerSession3,
+    /// Special Summer Session (`SU`).
+    SpecialSummer,
+    /// Fall quarter (`FA`).
+    Fall,
+}
+
+impl Quarter {
+    /// All quarters, in the order that they occur in a calendar year.
+    pub const ALL: [Quarter; 7] = [
+        Quarter::Winter,
+        Quarter::Spring,
+        Quarter::SummerSession1,
+        Quarter::SummerSession2,
+        Quarter::SummerSession3,
+        Quarter::SpecialSummer,
+        Quarter::Fall,
+    ];
+
+    /// Gets the two-character code of this quarter, as used in term codes.
+    ///
+    /// # Returns
+    /// The code (e.g., `FA`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Quarter::Winter => "WI",
+            Quarter::Spring => "SP",
+            Quarter::SummerSession1 => "S1",
+            Quarter::SummerSession2 => "S2",
+            Quarter::SummerSession3 => "S3",
+            Quarter::SpecialSummer => "SU",
+            Quarter::Fall => "FA",
+        }
+    }
+
+    /// Gets the human-readable name of this quarter.
+    ///
+    /// # Returns
+    /// The name (e.g., `Summer Session I`).
+    pub fn name(&self) -> &'static str {
+        match self {
+            Quarter::Winter => "Winter",
+            Quarter::Spring => "Spring",
+            Quarter::SummerSession1 => "Summer Session I",
+            Quarter::SummerSession2 => "Summer Session II",
+            Quarter::SummerSession3 => "Summer Session III",
+            Quarter::SpecialSummer => "Special Summer Session",
+            Quarter::Fall => "Fall",
+        }
+    }
+
+    /// Gets the position of this quarter in `Quarter::ALL`.
+    ///
+    /// # Returns
+    /// The position.
+    fn index(&self) -> usize {
+        *self as usize
+    }
+}
+
+impl FromStr for Quarter {
+    type Err = WrapperError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        Quarter::ALL
+            .into_iter()
+            .find(|q| q.as_str().eq_ignore_ascii_case(s))
+            .ok_or_else(|| WrapperError::TermParseError(s.to_string(), "unknown quarter."))
+    }
+}
+
+impl Display for Quarter {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+/// A term code, like `FA23` or `S123`. Term codes are ordered chronologically, so `SP23`
+/// comes before `S123`, which comes before `FA23`.
+///
+/// Term codes can be parsed from strings (case-insensitive), and are serialized as strings.
+///
+/// # Example
+/// ```rust
+/// use webweg::types::{Quarter, TermCode};
+///
+/// let term: TermCode = "fa23".parse().unwrap();
+/// assert_eq!(Quarter::Fall, term.quarter());
+/// assert_eq!(2023, term.year());
+/// assert_eq!("WI24", term.next().to_string());
+/// assert_eq!(5320, term.seq_id());
+/// ```
+#[derive(Debug, Clone, Copy, Eq, PartialEq, Hash)]
+pub struct TermCode {
+    quarter: Quarter,
+    year: u16,
+}
+
+impl TermCode {
+    /// Creates a new term code.
+    ///
+    /// # Parameters
+    /// - `quarter`: The quarter.
+    /// - `year`: The full year (e.g., `2023`). Term codes only store the last two digits of
+    ///   the year, so this must be between `2000` and `2099`, inclusive.
+    ///
+    /// # Returns
+    /// The term code, or `None` if the year is out of range.
+    pub fn new(quarter: Quarter, year: u16) -> Option<Self> {
+        (2000..=2099)
+            .contains(&year)
+            .then_some(Self { quarter, year })
+    }
+
+    /// Gets the quarter of this term.
+    ///
+    /// # Returns
+    /// The quarter.
+    pub fn quarter(&self) -> Quarter {
+        self.quarter
+    }
+
+    /// Gets the full year of this term (e.g., `2023`).
+    ///
+    /// # Returns
+    /// The year.
+    pub fn year(&self) -> u16 {
+        self.year
+    }
+
+    /// Gets the term that comes directly after this one (e.g., `WI24` for `FA23`).
+    ///
+    /// # Returns
+    /// The next term. If this is `FA99`, then `FA99` is returned.
+    pub fn next(&self) -> Self {
+        match Quarter::ALL.get(self.quarter.index() + 1) {
+            Some(&quarter) => Self { quarter, ..*self },
+            None => Self::new(Quarter::Winter, self.year + 1).unwrap_or(*self),
+        }
+    }
+
+    /// Gets the term that comes directly before this one (e.g., `FA23` for `WI24`).
+    ///
+    /// # Returns
+    /// The previous term. If this is `WI00`, then `WI00` is returned.
+    pub fn prev(&self) -> Self {
+        match self.quarter.index().checked_sub(1) {
+            Some(idx) => Self {
+                quarter: Quarter::ALL[idx],
+                ..*self
+            },
+            None => Self::new(Quarter::Fall, self.year - 1).unwrap_or(*self),
+        }
+    }
+
+    /// Gets the sequence ID (term ID) that WebReg uses for this term.
+    ///
+    /// # Returns
+    /// The sequence ID.
+    pub fn seq_id(&self) -> i64 {
+        // WI22 has a sequence ID of 5190, each quarter is 10 apart, and each year is 70
+        // apart (e.g., the sequence IDs of FA22 and FA23 have a difference of 70).
+        5190 + 70 * (self.year as i64 - 2022) + 10 * self.quarter.index() as i64
+    }
+
+    /// Gets the human-readable name of this term.
+    ///
+    /// # Returns
+    /// The name (e.g., `Fall 2023`).
+    pub fn description(&self) -> String {
+        format!("{} {}", self.quarter.name(), self.year)
+    }
+}
+
+impl Ord for TermCode {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        (self.year, self.quarter).cmp(&(other.year, other.quarter))
+    }
+}
+
+impl PartialOrd for TermCode {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl FromStr for TermCode {
+    type Err = WrapperError;
+
+    fn from_str(s: &str) -> Result<Self> {
+        let term = s.trim();
+        if term.len() != 4 || !term.is_ascii() {
+            return Err(WrapperError::TermParseError(
+                s.to_string(),
+                "term codes must be 4 characters long.",
+            ));
+        }
+
+        let quarter = term[..2]
+            .parse::<Quarter>()
+            .map_err(|_| WrapperError::TermParseError(s.to_string(), "unknown quarter."))?;
+
+        let year = &term[2..];
+        if !year.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(WrapperError::TermParseError(
+                s.to_string(),
+                "the year must be two digits.",
+            ));
+        }
+
+        // The unwraps are safe since we know the year is two digits.
+        Ok(Self::new(quarter, 2000 + year.parse::<u16>().unwrap()).unwrap())
+    }
+}
+
+impl Display for TermCode {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{:02}", self.quarter, self.year % 100)
+    }
+}
+
+impl Serialize for TermCode {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for TermCode {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> std::result::Result<Self, D::Error> {
+        let s = String::deserialize(deserializer)?;
+        s.parse().map_err(serde::de::Error::custom)
+    }
+}
+
+impl From<TermCode> for Cow<'_, str> {
+    fn from(code: TermCode) -> Self {
+        Cow::Owned(code.to_string())
+    }
+}
+
+impl From<&TermCode> for Cow<'_, str> {
+    fn from(code: &TermCode) -> Self {
+        Cow::Owned(code.to_string())
+    }
 }
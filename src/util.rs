@@ -1,7 +1,7 @@
 use std::time::SystemTime;
 
 use crate::raw_types::RawWebRegMeeting;
-use crate::types::MeetingDay;
+use crate::types::{self, MeetingDay, SectionId, TermCode, WrapperError};
 
 /// Gets the meeting type (e.g. Lecture, Final Exam, Discussion, etc.) and the meeting time from
 /// an arbitrary `WebRegMeeting`.
@@ -10,39 +10,51 @@ use crate::types::MeetingDay;
 /// - `w_meeting`: The WebReg meeting to check.
 ///
 /// # Returns
-/// A tuple where:
+/// Either a tuple where:
 /// - the first element is the meeting type
 /// - the second element is/are the day(s) that this meeting occurs
+///
+/// or an error if the meeting has no start date (for one-time meetings) or an invalid
+/// day code (for repeated meetings).
 #[inline]
-pub fn parse_meeting_type_date(w_meeting: &RawWebRegMeeting) -> (&str, MeetingDay) {
+pub fn parse_meeting_type_date(w_meeting: &RawWebRegMeeting) -> types::Result<(&str, MeetingDay)> {
     let special_meeting = w_meeting.special_meeting.trim();
     if !special_meeting.is_empty() && special_meeting != "TBA" {
-        assert!(!w_meeting.section_start_date.is_empty());
-        return (
+        if w_meeting.section_start_date.trim().is_empty() {
+            return Err(WrapperError::WrapperParsingError(format!(
+                "section_start_date: missing for one-time meeting '{special_meeting}'"
+            )));
+        }
+
+        return Ok((
             special_meeting,
             MeetingDay::OneTime(w_meeting.start_date.to_string()),
-        );
+        ));
     }
 
     let regular_meeting = w_meeting.meeting_type.trim();
     let day_code = w_meeting.day_code.trim();
-    assert!(day_code.chars().all(|x| x.is_numeric()));
+    if !day_code.chars().all(|x| x.is_ascii_digit()) {
+        return Err(WrapperError::WrapperParsingError(format!(
+            "day_code: invalid day code '{day_code}'"
+        )));
+    }
 
-    if day_code.is_empty() {
+    Ok(if day_code.is_empty() {
         (regular_meeting, MeetingDay::None)
     } else {
         (
             regular_meeting,
             MeetingDay::Repeated(parse_day_code(day_code)),
         )
-    }
+    })
 }
 
 /// Parses the days of the week from a day code string.
 ///
 /// # Parameters
 /// - `day_code_str`: The day code string. This should only contain integers between 0 and 6, both
-/// inclusive.
+///   inclusive.
 ///
 /// # Returns
 /// A string with the days of the week.
@@ -77,9 +89,9 @@ const DAYS: [&str; 7] = ["M", "Tu", "W", "Th", "F", "Sa", "Su"];
 ///
 /// # Parameters
 /// - `bin_str`: The binary string. Must be length 7. The first bit
-/// represents Monday, the second bit represents Tuesday, and so on.
-/// The `1` bit means that the day is active, and the `0` bit means
-/// the day is inactive.
+///   represents Monday, the second bit represents Tuesday, and so on.
+///   The `1` bit means that the day is active, and the `0` bit means
+///   the day is inactive.
 ///
 /// # Returns
 /// A string with the days of the week.
@@ -87,31 +99,23 @@ const DAYS: [&str; 7] = ["M", "Tu", "W", "Th", "F", "Sa", "Su"];
 /// # Example
 /// An input of `1010101` would return `["M", "W", "F", "Su"]`.
 pub fn parse_binary_days(bin_str: &str) -> Vec<String> {
-    let mut days = vec![];
-    if bin_str.len() == 7 {
-        let day_vec = bin_str.chars().collect::<Vec<_>>();
-        for (idx, day) in DAYS.iter().enumerate() {
-            if day_vec[idx] == '1' {
-                days.push(day.to_string());
-            }
-        }
+    if bin_str.chars().count() != DAYS.len() {
+        return vec![];
     }
 
-    days
+    bin_str
+        .chars()
+        .zip(DAYS)
+        .filter(|(c, _)| *c == '1')
+        .map(|(_, day)| day.to_string())
+        .collect()
 }
 
-const TERM_ARR: [(&str, (i64, i64)); 7] = [
-    ("SP", (5200, 22)), // SP22
-    ("S1", (5210, 22)), // S122
-    ("S2", (5220, 22)), // S222
-    ("S3", (5230, 22)), // S322
-    ("SU", (5240, 22)), // SU22
-    ("FA", (5250, 22)), // FA22
-    ("WI", (5260, 23)), // WI23
-];
-
 /// Gets the term ID based on the term that was passed in.
 ///
+/// Prefer parsing the term into a `TermCode` and using `TermCode::seq_id`, which gives
+/// you an error explaining why the term is invalid.
+///
 /// # Parameters
 /// - `term`: The term
 ///
@@ -119,25 +123,10 @@ const TERM_ARR: [(&str, (i64, i64)); 7] = [
 /// The term ID, if valid. If `0` is returned, then the input
 /// is invalid.
 pub fn get_term_seq_id(term: impl AsRef<str>) -> i64 {
-    let term = term.as_ref();
-    if term.len() != 4 {
-        return 0;
-    }
-
-    let term_init = &term[..2];
-    let (base_seq_id, base_year) = match TERM_ARR.iter().find(|(term, _)| *term == term_init) {
-        Some((_, data)) => *data,
-        None => return 0,
-    };
-
-    let quarter_yr = match term[2..].parse::<i64>() {
-        Ok(o) => o,
-        Err(_) => return 0,
-    };
-
-    // 70 is the difference between each term, apparently
-    // For example, the seqid of FA22 and FA23 has a difference of 70
-    70 * (quarter_yr - base_year) + base_seq_id
+    term.as_ref()
+        .parse::<TermCode>()
+        .map(|t| t.seq_id())
+        .unwrap_or(0)
 }
 
 /// Gets the formatted course code so that it can be recognized by
@@ -145,7 +134,7 @@ pub fn get_term_seq_id(term: impl AsRef<str>) -> i64 {
 ///
 /// # Parameters
 /// - `course_code`: The course code, e.g. if you have the course
-/// `CSE 110`, you would put `110`.
+///   `CSE 110`, you would put `110`.
 ///
 /// # Returns
 /// The formatted course code for WebReg.
@@ -226,8 +215,8 @@ where
 ///
 /// # Parameters
 /// - `query`: The vector of courses to format. Each element can either be a
-/// full course code (e.g., `CSE 100`) or a partial course code (e.g., `CSE`
-/// or `100`).
+///   full course code (e.g., `CSE 100`) or a partial course code (e.g., `CSE`
+///   or `100`).
 ///
 /// # Returns
 /// The formatted string.
@@ -283,3 +272,46 @@ pub fn format_multiple_courses<T: AsRef<str>>(query: &[T]) -> String {
         .join(";")
         .to_uppercase()
 }
+
+/// Checks whether two subject, course IDs refer to the same course, ignoring case and
+/// extra whitespace. For example, `cse  100` and `CSE 100` are the same course.
+///
+/// # Parameters
+/// - `a`: The first subject, course ID.
+/// - `b`: The second subject, course ID.
+///
+/// # Returns
+/// `true` if both refer to the same course, and `false` otherwise.
+pub(crate) fn is_same_subj_course_id(a: &str, b: &str) -> bool {
+    normalize_subj_course_id(a) == normalize_subj_course_id(b)
+}
+
+/// Normalizes a subject, course ID so that, for example, `cse  100` and `CSE 100` are the
+/// same.
+///
+/// # Parameters
+/// - `subj_course_id`: The subject, course ID.
+///
+/// # Returns
+/// The normalized subject, course ID.
+pub(crate) fn normalize_subj_course_id(subj_course_id: &str) -> String {
+    subj_course_id
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+        .to_uppercase()
+}
+
+/// Converts the input into a section ID.
+///
+/// # Parameters
+/// - `section_id`: The section ID, usually as a string (e.g., `079911`).
+///
+/// # Returns
+/// The section ID, or the error from converting it (e.g., an input error describing why it
+/// isn't a valid section ID).
+pub(crate) fn to_section_id(
+    section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+) -> types::Result<SectionId> {
+    section_id.try_into().map_err(Into::into)
+}
@@ -0,0 +1,863 @@
+use std::collections::{BTreeMap, HashMap};
+use std::future::{ready, Future, Ready};
+use std::sync::{Mutex, MutexGuard};
+
+use crate::constants::DEFAULT_SCHEDULE_NAME;
+use crate::types::{
+    self, CourseKey, CourseSection, Courses, EnrollmentStatus, Event, Events, PrerequisiteInfo,
+    Schedule, ScheduledSection, SearchResult, SectionId, SectionIdNotFoundContext, WrapperError,
+};
+use crate::wrapper::input_types::{
+    AddType, EnrollWaitAdd, EventAdd, ExplicitAddType, PlanAdd, SearchType,
+};
+use crate::wrapper::requester_term::WrapperTermRequest;
+use crate::wrapper::term_handle::TermHandle;
+
+/// The parsed WebReg operations for a single term. This is implemented by the parsed
+/// requester ([`WrapperTermRequest`]), the owned [`TermHandle`], and [`InMemoryWebReg`], so
+/// code that only needs these operations can be written generically and then tested without
+/// a live session.
+///
+/// Each operation behaves like the method of the same name on `WrapperTermRequest`.
+///
+/// # Example
+/// ```rust
+/// use webweg::types;
+/// use webweg::wrapper::api::{InMemoryWebReg, WebRegApi};
+///
+/// async fn planned_count(api: &impl WebRegApi) -> types::Result<usize> {
+///     Ok(api.get_schedule(None).await?.len())
+/// }
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let fake = InMemoryWebReg::new();
+/// assert_eq!(0, planned_count(&fake).await.unwrap());
+/// # }
+/// ```
+pub trait WebRegApi {
+    /// Gets a schedule. See [`WrapperTermRequest::get_schedule`].
+    fn get_schedule(
+        &self,
+        schedule_name: Option<&str>,
+    ) -> impl Future<Output = types::Result<Schedule>> + Send;
+
+    /// Gets information about a course. See [`WrapperTermRequest::get_course_info`].
+    fn get_course_info<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> impl Future<Output = types::Result<Courses>> + Send;
+
+    /// Gets the enrollment counts for a course. See
+    /// [`WrapperTermRequest::get_enrollment_count`].
+    fn get_enrollment_count<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> impl Future<Output = types::Result<Courses>> + Send;
+
+    /// Searches for courses. See [`WrapperTermRequest::search_courses`].
+    fn search_courses(
+        &self,
+        filter_by: SearchType,
+    ) -> impl Future<Output = types::Result<SearchResult>> + Send;
+
+    /// Plans a section. See [`WrapperTermRequest::add_to_plan`].
+    fn add_to_plan(
+        &self,
+        plan_options: PlanAdd<'_>,
+        validate: bool,
+    ) -> impl Future<Output = types::Result<bool>> + Send;
+
+    /// Removes a planned section. See [`WrapperTermRequest::remove_from_plan`].
+    fn remove_from_plan(
+        &self,
+        section_id: SectionId,
+        schedule_name: Option<&str>,
+    ) -> impl Future<Output = types::Result<bool>> + Send;
+
+    /// Enrolls in, or waitlists, a section. See [`WrapperTermRequest::add_section`].
+    fn add_section(
+        &self,
+        add_type: AddType,
+        enroll_options: EnrollWaitAdd,
+        validate: bool,
+    ) -> impl Future<Output = types::Result<bool>> + Send;
+
+    /// Drops a section. See [`WrapperTermRequest::drop_section`].
+    fn drop_section(
+        &self,
+        prev_enroll_status: ExplicitAddType,
+        section_id: SectionId,
+    ) -> impl Future<Output = types::Result<bool>> + Send;
+
+    /// Gets all events. See [`WrapperTermRequest::get_events`].
+    fn get_events(&self) -> impl Future<Output = types::Result<Events>> + Send;
+
+    /// Adds or edits an event. See [`WrapperTermRequest::add_or_edit_event`].
+    fn add_or_edit_event(
+        &self,
+        event_info: EventAdd<'_>,
+        event_timestamp: Option<&str>,
+    ) -> impl Future<Output = types::Result<bool>> + Send;
+
+    /// Removes an event. See [`WrapperTermRequest::remove_event`].
+    fn remove_event(
+        &self,
+        event_timestamp: &str,
+    ) -> impl Future<Output = types::Result<bool>> + Send;
+
+    /// Gets the prerequisites for a course. See [`WrapperTermRequest::get_prerequisites`].
+    fn get_prerequisites<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> impl Future<Output = types::Result<PrerequisiteInfo>> + Send;
+}
+
+impl<'a> WebRegApi for WrapperTermRequest<'a> {
+    fn get_schedule(
+        &self,
+        schedule_name: Option<&str>,
+    ) -> impl Future<Output = types::Result<Schedule>> + Send {
+        WrapperTermRequest::get_schedule(self, schedule_name)
+    }
+
+    fn get_course_info<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> impl Future<Output = types::Result<Courses>> + Send {
+        WrapperTermRequest::get_course_info(self, course)
+    }
+
+    fn get_enrollment_count<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> impl Future<Output = types::Result<Courses>> + Send {
+        WrapperTermRequest::get_enrollment_count(self, course)
+    }
+
+    fn search_courses(
+        &self,
+        filter_by: SearchType,
+    ) -> impl Future<Output = types::Result<SearchResult>> + Send {
+        WrapperTermRequest::search_courses(self, filter_by)
+    }
+
+    fn add_to_plan(
+        &self,
+        plan_options: PlanAdd<'_>,
+        validate: bool,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        WrapperTermRequest::add_to_plan(self, plan_options, validate)
+    }
+
+    fn remove_from_plan(
+        &self,
+        section_id: SectionId,
+        schedule_name: Option<&str>,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        WrapperTermRequest::remove_from_plan(self, section_id, schedule_name)
+    }
+
+    fn add_section(
+        &self,
+        add_type: AddType,
+        enroll_options: EnrollWaitAdd,
+        validate: bool,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        WrapperTermRequest::add_section(self, add_type, enroll_options, validate)
+    }
+
+    fn drop_section(
+        &self,
+        prev_enroll_status: ExplicitAddType,
+        section_id: SectionId,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        WrapperTermRequest::drop_section(self, prev_enroll_status, section_id)
+    }
+
+    fn get_events(&self) -> impl Future<Output = types::Result<Events>> + Send {
+        WrapperTermRequest::get_events(self)
+    }
+
+    fn add_or_edit_event(
+        &self,
+        event_info: EventAdd<'_>,
+        event_timestamp: Option<&str>,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        WrapperTermRequest::add_or_edit_event(self, event_info, event_timestamp)
+    }
+
+    fn remove_event(
+        &self,
+        event_timestamp: &str,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        WrapperTermRequest::remove_event(self, event_timestamp)
+    }
+
+    fn get_prerequisites<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> impl Future<Output = types::Result<PrerequisiteInfo>> + Send {
+        WrapperTermRequest::get_prerequisites(self, course)
+    }
+}
+
+impl WebRegApi for TermHandle {
+    async fn get_schedule(&self, schedule_name: Option<&str>) -> types::Result<Schedule> {
+        self.parsed().get_schedule(schedule_name).await
+    }
+
+    async fn get_course_info<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> types::Result<Courses> {
+        self.parsed().get_course_info(course).await
+    }
+
+    async fn get_enrollment_count<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> types::Result<Courses> {
+        self.parsed().get_enrollment_count(course).await
+    }
+
+    async fn search_courses(&self, filter_by: SearchType) -> types::Result<SearchResult> {
+        self.parsed().search_courses(filter_by).await
+    }
+
+    async fn add_to_plan(&self, plan_options: PlanAdd<'_>, validate: bool) -> types::Result<bool> {
+        self.parsed().add_to_plan(plan_options, validate).await
+    }
+
+    async fn remove_from_plan(
+        &self,
+        section_id: SectionId,
+        schedule_name: Option<&str>,
+    ) -> types::Result<bool> {
+        self.parsed()
+            .remove_from_plan(section_id, schedule_name)
+            .await
+    }
+
+    async fn add_section(
+        &self,
+        add_type: AddType,
+        enroll_options: EnrollWaitAdd,
+        validate: bool,
+    ) -> types::Result<bool> {
+        self.parsed()
+            .add_section(add_type, enroll_options, validate)
+            .await
+    }
+
+    async fn drop_section(
+        &self,
+        prev_enroll_status: ExplicitAddType,
+        section_id: SectionId,
+    ) -> types::Result<bool> {
+        self.parsed()
+            .drop_section(prev_enroll_status, section_id)
+            .await
+    }
+
+    async fn get_events(&self) -> types::Result<Events> {
+        self.parsed().get_events().await
+    }
+
+    async fn add_or_edit_event(
+        &self,
+        event_info: EventAdd<'_>,
+        event_timestamp: Option<&str>,
+    ) -> types::Result<bool> {
+        self.parsed()
+            .add_or_edit_event(event_info, event_timestamp)
+            .await
+    }
+
+    async fn remove_event(&self, event_timestamp: &str) -> types::Result<bool> {
+        self.parsed().remove_event(event_timestamp).await
+    }
+
+    async fn get_prerequisites<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> types::Result<PrerequisiteInfo> {
+        self.parsed().get_prerequisites(course).await
+    }
+}
+
+/// The state held by an `InMemoryWebReg`.
+#[derive(Default)]
+struct FakeState {
+    /// The sections for each course, keyed by subject and course number (uppercase).
+    courses: BTreeMap<(String, String), Courses>,
+    schedules: BTreeMap<String, Schedule>,
+    events: Events,
+    prerequisites: HashMap<(String, String), PrerequisiteInfo>,
+    search_results: SearchResult,
+    /// Operations that should fail, along with the reason to fail with.
+    failures: HashMap<&'static str, String>,
+    next_timestamp: usize,
+}
+
+/// An in-memory stand-in for WebReg, meant for testing code that is written against
+/// [`WebRegApi`]. It starts out empty; use the `with_*` methods to give it courses,
+/// schedules, events, and so on.
+///
+/// Operations change the fake's state the way that WebReg would: for example, enrolling in
+/// a section adds it to your schedule (removing it from any plans) and takes up one of its
+/// seats. Only basic checks are done, so an operation that would be rejected by WebReg for a
+/// more subtle reason (e.g., a time conflict) will succeed here.
+///
+/// # Example
+/// ```rust
+/// use webweg::types::EnrollmentStatus;
+/// use webweg::wrapper::api::{InMemoryWebReg, WebRegApi};
+/// use webweg::wrapper::input_types::{AddType, EnrollWaitAdd};
+/// # use webweg::types::{CourseSection, SectionId};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// # let section = CourseSection {
+/// #     subj_course_id: "CSE 100".into(),
+/// #     section_id: SectionId::new(79913),
+/// #     section_code: "A01".into(),
+/// #     all_instructors: vec![],
+/// #     available_seats: 10,
+/// #     enrolled_ct: 20,
+/// #     total_seats: 30,
+/// #     waitlist_ct: 0,
+/// #     meetings: vec![],
+/// #     is_visible: true,
+/// # };
+/// let fake = InMemoryWebReg::new().with_course("CSE", "100", vec![section]);
+///
+/// let enroll = EnrollWaitAdd::builder()
+///     .with_section_id("79913")
+///     .try_build()
+///     .unwrap();
+/// assert!(fake.add_section(AddType::DecideForMe, enroll, true).await.unwrap());
+///
+/// let schedule = fake.get_schedule(None).await.unwrap();
+/// assert_eq!(EnrollmentStatus::Enrolled, schedule[0].enrolled_status);
+/// # }
+/// ```
+#[derive(Default)]
+pub struct InMemoryWebReg {
+    state: Mutex<FakeState>,
+}
+
+impl InMemoryWebReg {
+    /// Creates a new, empty fake.
+    ///
+    /// # Returns
+    /// The fake.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the sections offered for a course, replacing any sections already set for it.
+    ///
+    /// # Parameters
+    /// - `subject_code`: The subject code (e.g., `CSE`).
+    /// - `course_num`: The course number (e.g., `100`).
+    /// - `sections`: The sections.
+    ///
+    /// # Returns
+    /// The fake.
+    pub fn with_course(
+        self,
+        subject_code: impl AsRef<str>,
+        course_num: impl AsRef<str>,
+        sections: Courses,
+    ) -> Self {
+        self.state().courses.insert(
+            course_key(subject_code.as_ref(), course_num.as_ref()),
+            sections,
+        );
+        self
+    }
+
+    /// Sets a schedule, replacing any schedule with the same name.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The name of the schedule.
+    /// - `schedule`: The sections in the schedule.
+    ///
+    /// # Returns
+    /// The fake.
+    pub fn with_schedule(self, schedule_name: impl Into<String>, schedule: Schedule) -> Self {
+        self.state()
+            .schedules
+            .insert(schedule_name.into(), schedule);
+        self
+    }
+
+    /// Sets the events, replacing any existing events.
+    ///
+    /// # Parameters
+    /// - `events`: The events.
+    ///
+    /// # Returns
+    /// The fake.
+    pub fn with_events(self, events: Events) -> Self {
+        self.state().events = events;
+        self
+    }
+
+    /// Sets the prerequisites for a course. Courses without prerequisites set have none.
+    ///
+    /// # Parameters
+    /// - `subject_code`: The subject code (e.g., `CSE`).
+    /// - `course_num`: The course number (e.g., `100`).
+    /// - `prerequisites`: The prerequisites.
+    ///
+    /// # Returns
+    /// The fake.
+    pub fn with_prerequisites(
+        self,
+        subject_code: impl AsRef<str>,
+        course_num: impl AsRef<str>,
+        prerequisites: PrerequisiteInfo,
+    ) -> Self {
+        self.state().prerequisites.insert(
+            course_key(subject_code.as_ref(), course_num.as_ref()),
+            prerequisites,
+        );
+        self
+    }
+
+    /// Sets the results given by `search_courses`, regardless of the search filter.
+    ///
+    /// # Parameters
+    /// - `results`: The search results.
+    ///
+    /// # Returns
+    /// The fake.
+    pub fn with_search_results(self, results: SearchResult) -> Self {
+        self.state().search_results = results;
+        self
+    }
+
+    /// Makes an operation fail with a `WebRegError`, as if WebReg had rejected it.
+    ///
+    /// # Parameters
+    /// - `operation`: The name of the `WebRegApi` method (e.g., `add_section`).
+    /// - `reason`: The reason given in the error.
+    ///
+    /// # Returns
+    /// The fake.
+    pub fn with_failure(self, operation: &'static str, reason: impl Into<String>) -> Self {
+        self.state().failures.insert(operation, reason.into());
+        self
+    }
+
+    /// Locks the state of this fake, even if a previous operation panicked.
+    ///
+    /// # Returns
+    /// The state.
+    fn state(&self) -> MutexGuard<'_, FakeState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Runs an operation against the state, unless the operation was set to fail.
+    ///
+    /// # Parameters
+    /// - `operation`: The name of the operation.
+    /// - `f`: The operation.
+    ///
+    /// # Returns
+    /// A future that resolves to the result of the operation.
+    fn run<T>(
+        &self,
+        operation: &'static str,
+        f: impl FnOnce(&mut FakeState) -> types::Result<T>,
+    ) -> Ready<types::Result<T>> {
+        let mut state = self.state();
+        let res = match state.failures.get(operation) {
+            Some(reason) => Err(WrapperError::WebRegError(reason.clone())),
+            None => f(&mut state),
+        };
+
+        ready(res)
+    }
+}
+
+impl FakeState {
+    /// Finds a section in the catalog.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// The subject code, course number, and the section, or an error if no course offers
+    /// the section.
+    fn find_section(
+        &mut self,
+        section_id: SectionId,
+    ) -> types::Result<(&str, &str, &mut CourseSection)> {
+        self.courses
+            .iter_mut()
+            .find_map(|((subj, crsc), sections)| {
+                sections
+                    .iter_mut()
+                    .find(|s| s.section_id == section_id)
+                    .map(|s| (subj.as_str(), crsc.as_str(), s))
+            })
+            .ok_or(WrapperError::SectionIdNotFound(
+                section_id,
+                SectionIdNotFoundContext::Catalog,
+            ))
+    }
+
+    /// Gets a schedule, creating it if it doesn't exist.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The name of the schedule, or `None` for the default schedule.
+    ///
+    /// # Returns
+    /// The schedule.
+    fn schedule_mut(&mut self, schedule_name: Option<&str>) -> &mut Schedule {
+        self.schedules
+            .entry(schedule_name.unwrap_or(DEFAULT_SCHEDULE_NAME).to_owned())
+            .or_default()
+    }
+}
+
+impl WebRegApi for InMemoryWebReg {
+    fn get_schedule(
+        &self,
+        schedule_name: Option<&str>,
+    ) -> impl Future<Output = types::Result<Schedule>> + Send {
+        self.run("get_schedule", |state| {
+            Ok(state
+                .schedules
+                .get(schedule_name.unwrap_or(DEFAULT_SCHEDULE_NAME))
+                .cloned()
+                .unwrap_or_default())
+        })
+    }
+
+    fn get_course_info<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> impl Future<Output = types::Result<Courses>> + Send {
+        let course = course.into();
+        self.run("get_course_info", |state| {
+            Ok(state
+                .courses
+                .get(&course_key(course.subject(), course.course_num()))
+                .cloned()
+                .unwrap_or_default())
+        })
+    }
+
+    fn get_enrollment_count<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> impl Future<Output = types::Result<Courses>> + Send {
+        let course = course.into();
+        self.run("get_enrollment_count", |state| {
+            Ok(state
+                .courses
+                .get(&course_key(course.subject(), course.course_num()))
+                .cloned()
+                .unwrap_or_default())
+        })
+    }
+
+    fn search_courses(
+        &self,
+        _filter_by: SearchType,
+    ) -> impl Future<Output = types::Result<SearchResult>> + Send {
+        self.run("search_courses", |state| Ok(state.search_results.clone()))
+    }
+
+    fn add_to_plan(
+        &self,
+        plan_options: PlanAdd<'_>,
+        _validate: bool,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        self.run("add_to_plan", |state| {
+            let (_, _, section) = state.find_section(plan_options.section_id)?;
+            let mut planned = to_scheduled_section(
+                &plan_options.subject_code,
+                &plan_options.course_code,
+                section,
+                EnrollmentStatus::Planned,
+            );
+            planned.units = plan_options.unit_count.into();
+            if let Some(grade) = plan_options.grading_option {
+                planned.grade_option = grade.as_str().to_owned();
+            }
+
+            let schedule = state.schedule_mut(plan_options.schedule_name.as_deref());
+            schedule.retain(|s| s.section_id != plan_options.section_id);
+            schedule.push(planned);
+            Ok(true)
+        })
+    }
+
+    fn remove_from_plan(
+        &self,
+        section_id: SectionId,
+        schedule_name: Option<&str>,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        self.run("remove_from_plan", |state| {
+            let schedule = state.schedule_mut(schedule_name);
+            let len = schedule.len();
+            schedule.retain(|s| {
+                !(s.section_id == section_id && s.enrolled_status == EnrollmentStatus::Planned)
+            });
+
+            if schedule.len() == len {
+                Err(WrapperError::SectionIdNotFound(
+                    section_id,
+                    SectionIdNotFoundContext::Schedule,
+                ))
+            } else {
+                Ok(true)
+            }
+        })
+    }
+
+    fn add_section(
+        &self,
+        add_type: AddType,
+        enroll_options: EnrollWaitAdd,
+        _validate: bool,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        self.run("add_section", |state| {
+            let section_id = enroll_options.section_id;
+            if state.schedules.get(DEFAULT_SCHEDULE_NAME).is_some_and(|s| {
+                s.iter().any(|s| {
+                    s.section_id == section_id && s.enrolled_status != EnrollmentStatus::Planned
+                })
+            }) {
+                return Err(WrapperError::WebRegError(
+                    "You are already enrolled or waitlisted in this section.".into(),
+                ));
+            }
+
+            let (subj, crsc, section) = state.find_section(section_id)?;
+            let status = match add_type {
+                AddType::Enroll => ExplicitAddType::Enroll,
+                AddType::Waitlist => ExplicitAddType::Waitlist,
+                AddType::DecideForMe if section.has_seats() => ExplicitAddType::Enroll,
+                AddType::DecideForMe => ExplicitAddType::Waitlist,
+            };
+
+            let status = match status {
+                ExplicitAddType::Enroll => {
+                    if !section.has_seats() {
+                        return Err(WrapperError::WebRegError(
+                            "There are no seats available in this section.".into(),
+                        ));
+                    }
+
+                    section.available_seats -= 1;
+                    section.enrolled_ct += 1;
+                    EnrollmentStatus::Enrolled
+                }
+                ExplicitAddType::Waitlist => {
+                    section.waitlist_ct += 1;
+                    EnrollmentStatus::Waitlist {
+                        waitlist_pos: section.waitlist_ct,
+                    }
+                }
+            };
+
+            let mut added = to_scheduled_section(subj, crsc, section, status);
+            if let Some(units) = enroll_options.unit_count {
+                added.units = units.into();
+            }
+
+            if let Some(grade) = enroll_options.grading_option {
+                added.grade_option = grade.as_str().to_owned();
+            }
+
+            // Like WebReg, enrolling in or waitlisting a section removes it from every plan.
+            for schedule in state.schedules.values_mut() {
+                schedule.retain(|s| s.section_id != section_id);
+            }
+
+            state.schedule_mut(None).push(added);
+            Ok(true)
+        })
+    }
+
+    fn drop_section(
+        &self,
+        prev_enroll_status: ExplicitAddType,
+        section_id: SectionId,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        self.run("drop_section", |state| {
+            let schedule = state.schedule_mut(None);
+            let idx = schedule
+                .iter()
+                .position(|s| {
+                    s.section_id == section_id
+                        && match prev_enroll_status {
+                            ExplicitAddType::Enroll => {
+                                s.enrolled_status == EnrollmentStatus::Enrolled
+                            }
+                            ExplicitAddType::Waitlist => {
+                                matches!(s.enrolled_status, EnrollmentStatus::Waitlist { .. })
+                            }
+                        }
+                })
+                .ok_or(WrapperError::SectionIdNotFound(
+                    section_id,
+                    SectionIdNotFoundContext::Schedule,
+                ))?;
+            schedule.remove(idx);
+
+            if let Ok((_, _, section)) = state.find_section(section_id) {
+                match prev_enroll_status {
+                    ExplicitAddType::Enroll => {
+                        section.available_seats += 1;
+                        section.enrolled_ct -= 1;
+                    }
+                    ExplicitAddType::Waitlist => section.waitlist_ct -= 1,
+                }
+            }
+
+            Ok(true)
+        })
+    }
+
+    fn get_events(&self) -> impl Future<Output = types::Result<Events>> + Send {
+        self.run("get_events", |state| Ok(state.events.clone()))
+    }
+
+    fn add_or_edit_event(
+        &self,
+        event_info: EventAdd<'_>,
+        event_timestamp: Option<&str>,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        self.run("add_or_edit_event", |state| {
+            event_info.validate()?;
+            let timestamp = match event_timestamp {
+                Some(timestamp) => {
+                    let idx = state
+                        .events
+                        .iter()
+                        .position(|e| e.timestamp == timestamp)
+                        .ok_or_else(|| {
+                            WrapperError::WebRegError(format!(
+                                "No event with timestamp {timestamp}."
+                            ))
+                        })?;
+                    state.events.remove(idx).timestamp
+                }
+                None => {
+                    state.next_timestamp += 1;
+                    format!("in-memory-event-{}", state.next_timestamp)
+                }
+            };
+
+            state.events.push(Event {
+                location: event_info
+                    .location
+                    .as_deref()
+                    .unwrap_or_default()
+                    .to_owned(),
+                start_hr: event_info.start_hr,
+                start_min: event_info.start_min,
+                end_hr: event_info.end_hr,
+                end_min: event_info.end_min,
+                name: event_info.event_name.to_string(),
+                days: event_info
+                    .event_days
+                    .iter()
+                    .map(|d| d.as_str().to_owned())
+                    .collect(),
+                timestamp,
+            });
+            Ok(true)
+        })
+    }
+
+    fn remove_event(
+        &self,
+        event_timestamp: &str,
+    ) -> impl Future<Output = types::Result<bool>> + Send {
+        self.run("remove_event", |state| {
+            let len = state.events.len();
+            state.events.retain(|e| e.timestamp != event_timestamp);
+            if state.events.len() == len {
+                Err(WrapperError::WebRegError(format!(
+                    "No event with timestamp {event_timestamp}."
+                )))
+            } else {
+                Ok(true)
+            }
+        })
+    }
+
+    fn get_prerequisites<'k>(
+        &self,
+        course: impl Into<CourseKey<'k>> + Send,
+    ) -> impl Future<Output = types::Result<PrerequisiteInfo>> + Send {
+        let course = course.into();
+        self.run("get_prerequisites", |state| {
+            Ok(state
+                .prerequisites
+                .get(&course_key(course.subject(), course.course_num()))
+                .cloned()
+                .unwrap_or(PrerequisiteInfo {
+                    course_prerequisites: vec![],
+                    exam_prerequisites: vec![],
+                }))
+        })
+    }
+}
+
+/// Normalizes a course into a key for the fake's maps.
+///
+/// # Parameters
+/// - `subject_code`: The subject code.
+/// - `course_num`: The course number.
+///
+/// # Returns
+/// The key.
+fn course_key(subject_code: &str, course_num: &str) -> (String, String) {
+    (
+        subject_code.trim().to_uppercase(),
+        course_num.trim().to_uppercase(),
+    )
+}
+
+/// Creates the schedule entry for a section.
+///
+/// # Parameters
+/// - `subject_code`: The subject code.
+/// - `course_code`: The course number.
+/// - `section`: The section.
+/// - `status`: The enrollment status.
+///
+/// # Returns
+/// The schedule entry, with the default grading option and units.
+fn to_scheduled_section(
+    subject_code: &str,
+    course_code: &str,
+    section: &CourseSection,
+    status: EnrollmentStatus,
+) -> ScheduledSection {
+    ScheduledSection {
+        section_id: section.section_id,
+        subject_code: subject_code.to_owned(),
+        course_code: course_code.to_owned(),
+        course_title: String::new(),
+        section_code: section.section_code.clone(),
+        section_capacity: section.total_seats,
+        enrolled_count: section.enrolled_ct,
+        available_seats: section.available_seats,
+        grade_option: "L".into(),
+        all_instructors: section.all_instructors.clone(),
+        units: 4,
+        enrolled_status: status,
+        waitlist_ct: section.waitlist_ct,
+        meetings: section.meetings.clone(),
+    }
+}
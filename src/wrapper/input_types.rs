@@ -1,11 +1,13 @@
-use crate::types::TimeType;
+use crate::types::{CourseId, ScheduledSection, SectionId, TimeType, WrapperError};
+use crate::{types, util};
 use std::borrow::Cow;
+use std::str::FromStr;
 
 /// Use this struct to add more information regarding the section that you want to enroll/waitlist
 /// in.
-pub struct EnrollWaitAdd<'a> {
+pub struct EnrollWaitAdd {
     /// The section ID. For example, `0123123`.
-    pub section_id: Cow<'a, str>,
+    pub section_id: SectionId,
     /// The grading option. Can either be L, P, or S.
     /// If None is specified, this uses the default option.
     pub grading_option: Option<GradeOption>,
@@ -14,12 +16,12 @@ pub struct EnrollWaitAdd<'a> {
     pub unit_count: Option<u8>,
 }
 
-impl<'a> EnrollWaitAdd<'a> {
+impl EnrollWaitAdd {
     /// Creates a new builder for the `EnrollWaitAdd` structure.
     ///
     /// # Returns
     /// The builder.
-    pub fn builder() -> EnrollWaitAddBuilder<'a> {
+    pub fn builder() -> EnrollWaitAddBuilder {
         EnrollWaitAddBuilder {
             section_id: None,
             grading_option: None,
@@ -28,13 +30,13 @@ impl<'a> EnrollWaitAdd<'a> {
     }
 }
 
-pub struct EnrollWaitAddBuilder<'a> {
-    section_id: Option<Cow<'a, str>>,
+pub struct EnrollWaitAddBuilder {
+    section_id: Option<SectionId>,
     grading_option: Option<GradeOption>,
     unit_count: Option<u8>,
 }
 
-impl<'a> EnrollWaitAddBuilder<'a> {
+impl EnrollWaitAddBuilder {
     pub fn new() -> Self {
         EnrollWaitAddBuilder {
             section_id: None,
@@ -45,15 +47,32 @@ impl<'a> EnrollWaitAddBuilder<'a> {
     /// Sets the section ID for this builder. For example, `0123123` is a possible section ID.
     ///
     /// # Parameters
-    /// - `section_id`: The section ID.
+    /// - `section_id`: The section ID. If this isn't a valid section ID, then the section ID
+    ///   will be unset, so `try_build` won't build the object. To
+    ///   get the reason that the section ID is invalid, use `try_with_section_id` instead.
     ///
     /// # Returns
     /// The builder.
-    pub fn with_section_id(mut self, section_id: impl Into<Cow<'a, str>>) -> Self {
-        self.section_id = Some(section_id.into());
+    pub fn with_section_id(mut self, section_id: impl TryInto<SectionId>) -> Self {
+        self.section_id = section_id.try_into().ok();
         self
     }
 
+    /// Sets the section ID for this builder, failing if it isn't a valid section ID.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// The builder, or the error from converting the section ID.
+    pub fn try_with_section_id(
+        mut self,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+    ) -> types::Result<Self> {
+        self.section_id = Some(section_id.try_into().map_err(Into::into)?);
+        Ok(self)
+    }
+
     /// Sets the grading option (L, P/NP, S/U) for this builder.
     ///
     /// # Parameters
@@ -83,28 +102,24 @@ impl<'a> EnrollWaitAddBuilder<'a> {
     /// # Returns
     /// The result of constructing this `EnrollWaitAdd` object. It is guaranteed that this will
     /// result the `EnrollWaitAdd` object if the section ID has been provided.
-    pub fn try_build(self) -> Option<EnrollWaitAdd<'a>> {
-        if let Some(section_id) = self.section_id {
-            Some(EnrollWaitAdd {
-                section_id,
-                grading_option: self.grading_option,
-                unit_count: self.unit_count,
-            })
-        } else {
-            None
-        }
+    pub fn try_build(self) -> Option<EnrollWaitAdd> {
+        self.section_id.map(|section_id| EnrollWaitAdd {
+            section_id,
+            grading_option: self.grading_option,
+            unit_count: self.unit_count,
+        })
     }
 }
 
-impl<'a> Default for EnrollWaitAddBuilder<'a> {
+impl Default for EnrollWaitAddBuilder {
     fn default() -> Self {
         EnrollWaitAddBuilder::new()
     }
 }
 
 // This trait implementation may be helpful later.
-impl<'a> AsRef<EnrollWaitAdd<'a>> for EnrollWaitAdd<'a> {
-    fn as_ref(&self) -> &EnrollWaitAdd<'a> {
+impl AsRef<EnrollWaitAdd> for EnrollWaitAdd {
+    fn as_ref(&self) -> &EnrollWaitAdd {
         self
     }
 }
@@ -118,7 +133,7 @@ pub struct PlanAdd<'a> {
     /// The course code. For example, `12`.
     pub course_code: Cow<'a, str>,
     /// The section ID. For example, `0123123`.
-    pub section_id: Cow<'a, str>,
+    pub section_id: SectionId,
     /// The section code. For example `A00`.
     pub section_code: Cow<'a, str>,
     /// The grading option.
@@ -138,12 +153,40 @@ impl<'a> PlanAdd<'a> {
     pub fn builder() -> PlanAddBuilder<'a> {
         PlanAddBuilder::new()
     }
+
+    /// Creates a `PlanAdd` object that would plan the given section, with the same grading
+    /// option and number of units, into the specified schedule.
+    ///
+    /// # Parameters
+    /// - `section`: The section to plan, usually from `get_schedule`.
+    /// - `schedule_name`: The schedule to plan the section into.
+    ///
+    /// # Returns
+    /// The `PlanAdd` object, or an error if the section's grading option or number of units
+    /// isn't valid.
+    pub fn from_scheduled_section(
+        section: &'a ScheduledSection,
+        schedule_name: impl Into<Cow<'a, str>>,
+    ) -> types::Result<Self> {
+        let unit_count = u8::try_from(section.units)
+            .map_err(|_| WrapperError::InputError("units", "unit count is out of range."))?;
+
+        Ok(Self {
+            subject_code: Cow::Borrowed(section.subject_code.as_str()),
+            course_code: Cow::Borrowed(section.course_code.as_str()),
+            section_id: section.section_id,
+            section_code: Cow::Borrowed(section.section_code.as_str()),
+            grading_option: Some(section.grade_option.parse()?),
+            schedule_name: Some(schedule_name.into()),
+            unit_count,
+        })
+    }
 }
 
 pub struct PlanAddBuilder<'a> {
     subject_code: Option<Cow<'a, str>>,
     course_code: Option<Cow<'a, str>>,
-    section_id: Option<Cow<'a, str>>,
+    section_id: Option<SectionId>,
     section_code: Option<Cow<'a, str>>,
     grading_option: Option<GradeOption>,
     schedule_name: Option<Cow<'a, str>>,
@@ -196,15 +239,32 @@ impl<'a> PlanAddBuilder<'a> {
     /// Sets the section ID for this builder. For example, `0123123` is a possible section ID.
     ///
     /// # Parameters
-    /// - `section_id`: The section ID.
+    /// - `section_id`: The section ID. If this isn't a valid section ID, then the section ID
+    ///   will be unset, so `try_build` won't build the object. To
+    ///   get the reason that the section ID is invalid, use `try_with_section_id` instead.
     ///
     /// # Returns
     /// The builder.
-    pub fn with_section_id(mut self, section_id: impl Into<Cow<'a, str>>) -> Self {
-        self.section_id = Some(section_id.into());
+    pub fn with_section_id(mut self, section_id: impl TryInto<SectionId>) -> Self {
+        self.section_id = section_id.try_into().ok();
         self
     }
 
+    /// Sets the section ID for this builder, failing if it isn't a valid section ID.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// The builder, or the error from converting the section ID.
+    pub fn try_with_section_id(
+        mut self,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+    ) -> types::Result<Self> {
+        self.section_id = Some(section_id.try_into().map_err(Into::into)?);
+        Ok(self)
+    }
+
     /// Sets the section code for this builder. For example, `A01` is a possible section code.
     ///
     /// # Parameters
@@ -325,6 +385,62 @@ impl<'a> EventAdd<'a> {
     pub fn builder() -> EventAddBuilder<'a> {
         EventAddBuilder::new()
     }
+
+    /// Checks whether WebReg will accept this event. In particular, an event must
+    /// - have minutes between 0 and 59 and hours between 0 and 23,
+    /// - start before it ends,
+    /// - start between 7am and 10pm (inclusive), and
+    /// - be held on at least one day.
+    ///
+    /// # Returns
+    /// Nothing if the event is valid, or an `InputError` describing the first problem found.
+    pub fn validate(&self) -> types::Result<()> {
+        if self.start_min >= 60 || self.end_min >= 60 {
+            return Err(WrapperError::InputError(
+                "event_info.min",
+                "Minutes must be between 0 and 59.",
+            ));
+        }
+
+        if self.end_hr >= 24 {
+            return Err(WrapperError::InputError(
+                "event_info.end_hr",
+                "End hour must be between 0 and 23.",
+            ));
+        }
+
+        let start_time_full = self.start_hr * 100 + self.start_min;
+        let end_time_full = self.end_hr * 100 + self.end_min;
+        if start_time_full >= end_time_full {
+            return Err(WrapperError::InputError(
+                "time",
+                "Start time must be less than end time.",
+            ));
+        }
+
+        if self.start_hr < 7 || self.start_hr > 12 + 10 {
+            return Err(WrapperError::InputError(
+                "event_info.start_hr",
+                "Start hour must be between 7 and 22 (7am and 10pm)",
+            ));
+        }
+
+        if self.start_hr == 12 + 10 && self.start_min != 0 {
+            return Err(WrapperError::InputError(
+                "event_info.start",
+                "You cannot exceed 10pm.",
+            ));
+        }
+
+        if self.event_days.is_empty() {
+            return Err(WrapperError::InputError(
+                "event_info.event_days",
+                "Must specify one day.",
+            ));
+        }
+
+        Ok(())
+    }
 }
 
 pub struct EventAddBuilder<'a> {
@@ -464,7 +580,7 @@ impl<'a> Default for EventAddBuilder<'a> {
 }
 
 /// The possible grading options.
-#[derive(PartialOrd, PartialEq, Debug)]
+#[derive(PartialOrd, PartialEq, Debug, Clone, Copy)]
 pub enum GradeOption {
     /// S/U grading (Satisfactory/Unsatisfactory) option.
     S,
@@ -490,6 +606,22 @@ impl GradeOption {
     }
 }
 
+impl FromStr for GradeOption {
+    type Err = WrapperError;
+
+    fn from_str(s: &str) -> types::Result<Self> {
+        match s.trim() {
+            "L" | "l" => Ok(GradeOption::L),
+            "S" | "s" => Ok(GradeOption::S),
+            "P" | "p" => Ok(GradeOption::P),
+            _ => Err(WrapperError::InputError(
+                "grade_option",
+                "grading option must be one of L, P, or S.",
+            )),
+        }
+    }
+}
+
 /// An enum that represents how a course should be added to the person's schedule when
 /// calling the corresponding `add_section` method (and associated methods).
 pub enum AddType {
@@ -503,6 +635,7 @@ pub enum AddType {
 
 /// An enum that's similar to `AddType`, but explicitly only allows `Enroll` or `Waitlist`
 /// actions.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum ExplicitAddType {
     /// Indicates that the user wants to enroll into the section.
     Enroll,
@@ -510,6 +643,15 @@ pub enum ExplicitAddType {
     Waitlist,
 }
 
+/// The HTTP method to use when calling an arbitrary WebReg endpoint.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum HttpMethod {
+    /// A `GET` request. Parameters are sent as the query string.
+    Get,
+    /// A `POST` request. Parameters are sent as a form.
+    Post,
+}
+
 /// Used to construct search requests for the `search_courses` function.
 ///
 /// When building your request, you can either use one of the helper methods
@@ -518,7 +660,7 @@ pub enum ExplicitAddType {
 #[derive(Clone)]
 pub struct SearchRequestBuilder {
     pub subjects: Vec<String>,
-    pub courses: Vec<String>,
+    pub courses: Vec<SearchCourse>,
     pub departments: Vec<String>,
     pub instructor: Option<String>,
     pub title: Option<String>,
@@ -572,11 +714,12 @@ impl SearchRequestBuilder {
     /// examples include `20E`, `math 20d`, `101`, `CSE`.
     ///
     /// # Parameters
-    /// - `course`: The course.
+    /// - `course`: The course. This can also be a `CourseId`, which is sent exactly as is
+    ///   instead of being split into its parts.
     ///
     /// # Returns
     /// The `SearchRequestBuilder`
-    pub fn add_course(mut self, course: impl Into<String>) -> Self {
+    pub fn add_course(mut self, course: impl Into<SearchCourse>) -> Self {
         self.courses.push(course.into());
         self
     }
@@ -722,9 +865,62 @@ impl Default for SearchRequestBuilder {
     }
 }
 
+/// A course to search for. See `SearchRequestBuilder::add_course`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SearchCourse {
+    /// A subject code, course code, or both (e.g., `math 20d`), which is split into its parts
+    /// using `util::format_multiple_courses`.
+    Query(String),
+    /// A course ID.
+    Id(CourseId),
+}
+
+impl SearchCourse {
+    /// Formats this course so that it can be recognized by WebReg's search queries.
+    ///
+    /// # Returns
+    /// The formatted course (e.g., `MATH: 20D`).
+    pub(crate) fn to_wire(&self) -> String {
+        match self {
+            SearchCourse::Query(query) => util::format_multiple_courses(&[query]),
+            SearchCourse::Id(id) => format!("{}:{}", id.subject(), id.formatted_course_num()),
+        }
+    }
+}
+
+impl From<&str> for SearchCourse {
+    fn from(query: &str) -> Self {
+        SearchCourse::Query(query.to_owned())
+    }
+}
+
+impl From<String> for SearchCourse {
+    fn from(query: String) -> Self {
+        SearchCourse::Query(query)
+    }
+}
+
+impl From<&String> for SearchCourse {
+    fn from(query: &String) -> Self {
+        SearchCourse::Query(query.clone())
+    }
+}
+
+impl From<CourseId> for SearchCourse {
+    fn from(id: CourseId) -> Self {
+        SearchCourse::Id(id)
+    }
+}
+
+impl From<&CourseId> for SearchCourse {
+    fn from(id: &CourseId) -> Self {
+        SearchCourse::Id(id.clone())
+    }
+}
+
 /// The day of week enum, which designates what days you want
 /// to filter specific sections by.
-#[derive(PartialOrd, PartialEq, Debug)]
+#[derive(PartialOrd, PartialEq, Debug, Clone, Copy)]
 pub enum DayOfWeek {
     Monday,
     Tuesday,
@@ -735,6 +931,44 @@ pub enum DayOfWeek {
     Sunday,
 }
 
+impl DayOfWeek {
+    /// Gets the abbreviation that WebReg uses for this day.
+    ///
+    /// # Returns
+    /// The abbreviation (one of `M`, `Tu`, `W`, `Th`, `F`, `Sa`, or `Su`).
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            DayOfWeek::Monday => "M",
+            DayOfWeek::Tuesday => "Tu",
+            DayOfWeek::Wednesday => "W",
+            DayOfWeek::Thursday => "Th",
+            DayOfWeek::Friday => "F",
+            DayOfWeek::Saturday => "Sa",
+            DayOfWeek::Sunday => "Su",
+        }
+    }
+}
+
+impl FromStr for DayOfWeek {
+    type Err = WrapperError;
+
+    fn from_str(s: &str) -> types::Result<Self> {
+        match s.trim() {
+            "M" => Ok(DayOfWeek::Monday),
+            "Tu" => Ok(DayOfWeek::Tuesday),
+            "W" => Ok(DayOfWeek::Wednesday),
+            "Th" => Ok(DayOfWeek::Thursday),
+            "F" => Ok(DayOfWeek::Friday),
+            "Sa" => Ok(DayOfWeek::Saturday),
+            "Su" => Ok(DayOfWeek::Sunday),
+            _ => Err(WrapperError::InputError(
+                "day",
+                "day must be one of M, Tu, W, Th, F, Sa, or Su.",
+            )),
+        }
+    }
+}
+
 /// The course level filter enum, which can be used to filter
 /// specific sections by.
 pub enum CourseLevelFilter {
@@ -768,10 +1002,10 @@ pub enum CourseLevelFilter {
 /// you use one of the associated functions to create this enum instance.
 pub enum SearchType {
     /// Searches for a course by section ID.
-    BySection(String),
+    BySection(SectionId),
 
     /// Searches for a course by more than one section ID.
-    ByMultipleSections(Vec<String>),
+    ByMultipleSections(Vec<SectionId>),
 
     /// Searches for a (set of) course(s) by multiple specifications.
     Advanced(SearchRequestBuilder),
@@ -0,0 +1,98 @@
+use std::sync::Arc;
+
+use crate::types::{self, RawResponse};
+use crate::wrapper::transport::HttpRequest;
+
+/// Information about a request being made to WebReg.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RequestMeta<'a> {
+    /// The URL being requested, without the query string (e.g.,
+    /// `https://act.ucsd.edu/webreg2/svc/wradapter/secure/get-class`).
+    pub endpoint: &'a str,
+    /// The term that the request is for, if any. Requests that aren't tied to a term (e.g.,
+    /// getting the term list or pinging the server) won't have one.
+    pub term: Option<&'a str>,
+    /// The name of the wrapper function making the request (e.g., `get_schedule`).
+    pub operation: &'static str,
+}
+
+/// Hooks that are run around every request made to WebReg. Middleware can be registered
+/// using
+/// [`with_middleware`](crate::wrapper::wrapper_builder::WebRegWrapperBuilder::with_middleware),
+/// and is run in the order that it was registered.
+///
+/// Every hook has a default implementation that does nothing, so you only need to implement
+/// the hooks that you care about.
+///
+/// # Example
+/// ```rust
+/// use std::collections::HashMap;
+/// use std::sync::Mutex;
+///
+/// use webweg::types::RawResponse;
+/// use webweg::wrapper::middleware::{Middleware, RequestMeta};
+///
+/// #[derive(Default)]
+/// struct CallCounter {
+///     calls: Mutex<HashMap<&'static str, usize>>,
+/// }
+///
+/// impl Middleware for CallCounter {
+///     fn after_response(&self, _: &RawResponse, meta: &RequestMeta<'_>) {
+///         *self.calls.lock().unwrap().entry(meta.operation).or_default() += 1;
+///     }
+/// }
+/// ```
+pub trait Middleware: Send + Sync {
+    /// Called before a request is sent. This can be used to change the request (e.g., to add
+    /// headers), or to stop the request from being sent at all.
+    ///
+    /// # Parameters
+    /// - `req`: The request that is about to be sent.
+    /// - `meta`: Information about the request.
+    ///
+    /// # Returns
+    /// Nothing if the request should be sent, or an error if it shouldn't. Any error is
+    /// returned to the caller as-is.
+    fn before_request(&self, _req: &mut HttpRequest, _meta: &RequestMeta<'_>) -> types::Result<()> {
+        Ok(())
+    }
+
+    /// Called after a response is received, before it is processed.
+    ///
+    /// # Parameters
+    /// - `res`: The response.
+    /// - `meta`: Information about the request.
+    fn after_response(&self, _res: &RawResponse, _meta: &RequestMeta<'_>) {}
+}
+
+/// The middleware to run for a single request, along with the information about that request.
+pub(crate) struct Hooks<'a> {
+    pub middleware: &'a [Arc<dyn Middleware>],
+    pub meta: RequestMeta<'a>,
+}
+
+impl<'a> Hooks<'a> {
+    /// Runs `before_request` for each middleware.
+    ///
+    /// # Parameters
+    /// - `req`: The request that is about to be sent.
+    ///
+    /// # Returns
+    /// Nothing if the request should be sent, or the first error returned by a middleware.
+    pub fn before_request(&self, req: &mut HttpRequest) -> types::Result<()> {
+        self.middleware
+            .iter()
+            .try_for_each(|m| m.before_request(req, &self.meta))
+    }
+
+    /// Runs `after_response` for each middleware.
+    ///
+    /// # Parameters
+    /// - `res`: The response.
+    pub fn after_response(&self, res: &RawResponse) {
+        for m in self.middleware {
+            m.after_response(res, &self.meta);
+        }
+    }
+}
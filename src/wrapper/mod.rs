@@ -1,31 +1,45 @@
-#[cfg(feature = "multi")]
-use parking_lot::Mutex;
+use std::borrow::Cow;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
 use reqwest::Client;
-use serde_json::{json, Value};
+use serde_json::Value;
 use url::Url;
 
 use crate::constants::*;
 use crate::raw_types::RawTermListItem;
+use crate::schema::SchemaReport;
 use crate::types::{Term, WrapperError};
 use crate::wrapper::request_builder::WrapperTermRequestBuilder;
 use crate::wrapper::request_data::{ReqType, ReqwestWebRegClientData, WebRegWrapperData};
+use crate::wrapper::term_handle::TermHandle;
+use crate::wrapper::transport::{ReqwestTransport, TransportCache};
 use crate::wrapper::wrapper_builder::WebRegWrapperBuilder;
-use crate::wrapper::ww_helper::{associate_term_helper, process_get_result};
+use crate::wrapper::ww_helper::{
+    associate_term_helper, extract_text, process_get_text_observed, send,
+};
 use crate::{types, util};
 
+pub mod api;
 pub mod input_types;
+pub mod middleware;
 pub mod request_builder;
 mod request_data;
 pub mod requester_term;
+pub mod session_pool;
+pub mod term_handle;
+pub mod transport;
 pub mod wrapper_builder;
 mod ww_helper;
 
 /// A wrapper for [UCSD's WebReg](https://act.ucsd.edu/webreg2/start). For more information,
 /// please see the README.
+///
+/// The wrapper can be shared between threads or tasks. Cloning it is cheap, and the clone
+/// shares everything (including the cookies) with the original.
+#[derive(Clone)]
 pub struct WebRegWrapper {
-    data: WebRegWrapperData,
+    data: Arc<WebRegWrapperData>,
 }
 
 impl<'a> WebRegWrapper {
@@ -48,7 +62,7 @@ impl<'a> WebRegWrapper {
     /// # Parameters
     /// - `client`: The `reqwest` client. You are able to override this on a per-request basis.
     /// - `cookies`: The cookies from your session of WebReg. You are able to override this on
-    ///              a per-request basis.
+    ///   a per-request basis.
     ///
     /// # Returns
     /// The new instance of the `WebRegWrapper`.
@@ -63,16 +77,16 @@ impl<'a> WebRegWrapper {
     /// ```
     pub fn new(client: Client, cookies: impl Into<String>) -> Self {
         Self {
-            data: WebRegWrapperData {
-                #[cfg(feature = "multi")]
-                cookies: Mutex::new(cookies.into()),
-                #[cfg(not(feature = "multi"))]
-                cookies: cookies.into(),
-                client,
+            data: Arc::new(WebRegWrapperData {
+                cookies: RwLock::new(cookies.into().into()),
+                transport: Arc::new(ReqwestTransport::new(client)),
                 timeout: Duration::from_secs(30),
                 user_agent: MY_USER_AGENT.to_owned(),
                 close_after_request: false,
-            },
+                schema_observer: None,
+                middleware: vec![],
+                transport_cache: TransportCache::new(OVERRIDE_TRANSPORT_CACHE_SIZE),
+            }),
         }
     }
 
@@ -88,28 +102,13 @@ impl<'a> WebRegWrapper {
     /// Sets the cookies to the new, specified cookies.
     ///
     /// This might be useful if you want to use the existing wrapper but need to change the
-    /// cookies.
+    /// cookies. The change is seen by every clone of this wrapper. Requests that are already
+    /// in progress keep using the old cookies.
     ///
     /// # Parameters
     /// - `new_cookies`: The new cookies.
-    #[cfg(not(feature = "multi"))]
-    pub fn set_cookies(&mut self, new_cookies: impl Into<String>) {
-        self.data.cookies = new_cookies.into();
-    }
-
-    /// Sets the cookies to the new, specified cookies.
-    ///
-    /// This might be useful if you want to use the existing wrapper but need to change the
-    /// cookies.
-    ///
-    /// Note that a mutex is internally used to store the cookies.
-    ///
-    /// # Parameters
-    /// - `new_cookies`: The new cookies.
-    #[cfg(feature = "multi")]
     pub fn set_cookies(&self, new_cookies: impl Into<String>) {
-        let mut cookies = self.data.cookies.lock();
-        *cookies = new_cookies.into();
+        self.data.set_cookies(new_cookies.into().into());
     }
 
     /// Checks if the current WebReg instance is valid. Specifically, this will check if you
@@ -154,13 +153,9 @@ impl<'a> WebRegWrapper {
             return Err(WrapperError::SessionNotValid);
         }
 
-        Ok(self
-            .data
-            .req(ReqType::Get(ACC_NAME))
-            .send()
-            .await?
-            .text()
-            .await?)
+        let hooks = self.data.hooks(ACC_NAME, None, "get_account_name");
+        let req = self.data.req(ReqType::Get(ACC_NAME), &hooks)?;
+        Ok(send(self.data.get_transport(), req, &hooks).await?.body)
     }
 
     /// Registers all terms to your current session so that you can freely
@@ -213,23 +208,78 @@ impl<'a> WebRegWrapper {
     /// # }
     /// ```
     pub async fn get_all_terms(&self) -> types::Result<Vec<Term>> {
+        process_get_text_observed::<RawTermListItem>(
+            self.data.schema_observer.as_ref(),
+            "get_all_terms",
+            self.get_term_list().await?,
+        )
+        .map(|raw_term_list| {
+            raw_term_list
+                .into_iter()
+                .map(
+                    |RawTermListItem {
+                         seq_id,
+                         term_code,
+                         term_desc,
+                     }| Term {
+                        seq_id,
+                        term_code: term_code.trim().to_string(),
+                        term_desc: term_desc.trim().to_string(),
+                    },
+                )
+                .collect()
+        })
+    }
+
+    /// Gets all terms available on WebReg, as returned by WebReg.
+    ///
+    /// # Returns
+    /// The term list.
+    async fn get_term_list(&self) -> types::Result<String> {
         let url = Url::parse_with_params(
             TERM_LIST,
             &[("_", util::get_epoch_time().to_string().as_str())],
         )?;
 
-        process_get_result::<Vec<RawTermListItem>>(self.data.req(ReqType::Get(url)).send().await)
-            .await
-            .map(|raw_term_list| {
-                raw_term_list
-                    .into_iter()
-                    .map(
-                        |RawTermListItem {
-                             seq_id, term_code, ..
-                         }| Term { seq_id, term_code },
-                    )
-                    .collect()
-            })
+        let hooks = self.data.hooks(TERM_LIST, None, "get_all_terms");
+        let req = self.data.req(ReqType::Get(url), &hooks)?;
+        extract_text(send(self.data.get_transport(), req, &hooks).await)
+    }
+
+    /// Checks whether WebReg's API has changed by calling the term list endpoint and each
+    /// GET endpoint for the given term, and comparing the responses against the keys that
+    /// this crate expects. See
+    /// [`diagnose_schema`](crate::wrapper::requester_term::WrapperTermRequest::diagnose_schema)
+    /// for which endpoints are checked.
+    ///
+    /// # Parameters
+    /// - `term`: The term to check the endpoints with. Your cookies must already be
+    ///   associated with this term.
+    ///
+    /// # Returns
+    /// A summary of the drift found for each endpoint, along with any endpoints that
+    /// couldn't be checked.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies".to_string());
+    /// let report = wrapper.diagnose_schema("FA23").await;
+    /// println!("{report}");
+    /// # }
+    /// ```
+    pub async fn diagnose_schema(&'a self, term: impl Into<Cow<'a, str>>) -> SchemaReport {
+        let mut report = SchemaReport::default();
+        report.check::<RawTermListItem>("get_all_terms", self.get_term_list().await);
+
+        let term_report = self.req(term).parsed().diagnose_schema().await;
+        report.drifts.extend(term_report.drifts);
+        report.errors.extend(term_report.errors);
+        report
     }
 
     /// Associates a particular term to this current instance of the wrapper.
@@ -262,8 +312,8 @@ impl<'a> WebRegWrapper {
     /// // We should now be able to use those three terms.
     /// # }
     /// ```
-    pub async fn associate_term(&self, term: impl AsRef<str>) -> types::Result<()> {
-        associate_term_helper(&self.data, term).await
+    pub async fn associate_term(&self, term: impl Into<Cow<'_, str>>) -> types::Result<()> {
+        associate_term_helper(self.data.as_ref(), term.into()).await
     }
 
     /// Pings the WebReg server. Presumably, this is the endpoint that is used to ensure that
@@ -273,42 +323,45 @@ impl<'a> WebRegWrapper {
     /// # Returns
     /// `true` if the ping was successful and `false` otherwise.
     pub async fn ping_server(&self) -> bool {
-        let res = self
-            .data
-            .req(ReqType::Get(format!(
-                "{}?_={}",
-                PING_SERVER,
-                util::get_epoch_time()
-            )))
-            .send()
-            .await;
-
-        if let Ok(r) = res {
-            let text = r.text().await.unwrap_or_else(|_| {
-                json!({
-                    "SESSION_OK": false
-                })
-                .to_string()
-            });
+        let hooks = self.data.hooks(PING_SERVER, None, "ping_server");
+        let url = format!("{}?_={}", PING_SERVER, util::get_epoch_time());
+        let res = match self.data.req(ReqType::Get(url), &hooks) {
+            Ok(req) => send(self.data.get_transport(), req, &hooks).await,
+            Err(e) => Err(e),
+        };
 
-            let json: Value = serde_json::from_str(&text).unwrap_or_default();
-            // Use of unwrap here is safe since we know that there is a boolean value beforehand
-            json["SESSION_OK"].is_boolean() && json["SESSION_OK"].as_bool().unwrap()
-        } else {
-            false
-        }
+        let json: Value = res
+            .ok()
+            .and_then(|r| serde_json::from_str(&r.body).ok())
+            .unwrap_or_default();
+        json["SESSION_OK"].as_bool().unwrap_or(false)
     }
 
     /// Returns a request builder that can be used to customize any settings for a specific
     /// request only.
     ///
     /// # Parameters
-    /// - `term`: The term to use for this request.
+    /// - `term`: The term to use for this request. This can either be a string (e.g., `FA23`)
+    ///   or a `TermCode`.
     ///
     /// # Returns
     /// A builder allowing you to customize any settings for your request, like the cookies,
     /// client, term, user agent, and timeout.
-    pub fn req(&'a self, term: &'a str) -> WrapperTermRequestBuilder {
+    pub fn req(&'a self, term: impl Into<Cow<'a, str>>) -> WrapperTermRequestBuilder<'a> {
         WrapperTermRequestBuilder::new_request(&self.data, term)
     }
+
+    /// Returns an owned handle for making requests for a term. Unlike the requesters
+    /// returned by [`req`](WebRegWrapper::req), the handle doesn't borrow the wrapper, so it
+    /// can be stored or moved into a spawned task.
+    ///
+    /// # Parameters
+    /// - `term`: The term to make requests for. This can either be a string (e.g., `FA23`)
+    ///   or a `TermCode`.
+    ///
+    /// # Returns
+    /// The handle, which shares everything (including the cookies) with this wrapper.
+    pub fn term_handle<'t>(&self, term: impl Into<Cow<'t, str>>) -> TermHandle {
+        TermHandle::new(self.clone(), term.into().as_ref().into())
+    }
 }
@@ -1,9 +1,12 @@
+use std::borrow::Cow;
+use std::sync::Arc;
 use std::time::Duration;
 
-use crate::wrapper::request_data::WebRegWrapperDataRef;
+use crate::wrapper::request_data::{ReqwestWebRegClientData, WebRegWrapperDataRef};
 use reqwest::Client;
 
 use crate::wrapper::requester_term::{WrapperTermRawRequest, WrapperTermRequest};
+use crate::wrapper::transport::{ReqwestTransport, Transport, TransportCache};
 use crate::wrapper::WebRegWrapperData;
 
 /// A structure that represents a request to be "built." This allows you to
@@ -17,7 +20,11 @@ use crate::wrapper::WebRegWrapperData;
 ///   some API responses on your own.
 pub struct WrapperTermRequestBuilder<'a> {
     pub(crate) data: WebRegWrapperDataRef<'a>,
-    pub(crate) term: &'a str,
+    pub(crate) term: Cow<'a, str>,
+    /// The wrapper's transports for requests that override the cookies.
+    transport_cache: &'a TransportCache,
+    cookies_overridden: bool,
+    transport_overridden: bool,
 }
 
 impl<'a> WrapperTermRequestBuilder<'a> {
@@ -25,62 +32,48 @@ impl<'a> WrapperTermRequestBuilder<'a> {
     ///
     /// # Parameters
     /// - `wrapper`: The wrapper.
+    /// - `term`: The term to use for this request. This can either be a string (e.g., `FA23`)
+    ///   or a `TermCode`.
     ///
     /// # Returns
     /// The builder.
-    pub fn new_request(wrapper_data: &'a WebRegWrapperData, term: &'a str) -> Self {
+    pub fn new_request(wrapper_data: &'a WebRegWrapperData, term: impl Into<Cow<'a, str>>) -> Self {
         Self {
             data: WebRegWrapperDataRef {
-                #[cfg(feature = "multi")]
-                cookies: wrapper_data.cookies.lock().to_owned(),
-                #[cfg(not(feature = "multi"))]
-                cookies: wrapper_data.cookies.as_ref(),
-                client: &wrapper_data.client,
+                cookies: wrapper_data.get_cookies(),
+                transport: wrapper_data.transport.clone(),
                 user_agent: wrapper_data.user_agent.as_str(),
                 timeout: wrapper_data.timeout,
                 close_after_request: wrapper_data.close_after_request,
+                schema_observer: wrapper_data.schema_observer.as_ref(),
+                middleware: &wrapper_data.middleware,
             },
-            term,
+            term: term.into(),
+            transport_cache: &wrapper_data.transport_cache,
+            cookies_overridden: false,
+            transport_overridden: false,
         }
     }
 
     /// Overrides the cookies for any requests made under this soon-to-be requester.
     ///
-    /// If you plan on overriding cookies for this particular request, you should ensure
-    /// requests are being closed on completion (call `should_close_after_request` for the
-    /// builder).
+    /// Unless the client or transport is also overridden, requests with overridden cookies
+    /// are made with a separate client that is only used for those cookies, so that
+    /// connections are never shared between sessions. The wrapper keeps these clients around
+    /// so that later requests with the same cookies can reuse their connections. If the
+    /// wrapper's transport can't be isolated, or if the client or transport is overridden
+    /// with one that isn't [shareable](Transport::is_shareable), the connection is instead
+    /// closed after each request.
     ///
     /// # Parameters
     /// - `cookies`: The cookies to use. This will _not_ override the cookies for the
-    ///              wrapper, just this request.
+    ///   wrapper, just this request.
     ///
     /// # Returns
     /// The builder.
-    ///
-    /// # Panic
-    /// This function will only panic if you configured the wrapper instance so that it does _not_
-    /// close the connection after a request is done.
-    ///
-    /// For some context, when creating the wrapper instance, you have the option of setting
-    /// whether the connection is closed after a request is done (via the builder's
-    /// [`should_close_after_request`](crate::wrapper::wrapper_builder::WebRegWrapperBuilder@should_close_after_request)
-    /// function). If you never called this function, or you used the default constructor to create
-    /// the wrapper, or you explicitly set this value to `false`, then the wrapper instance will
-    /// not close the connection after a request is done and thus will panic when _this_ function
-    /// is called.
     pub fn override_cookies(mut self, cookies: &'a str) -> Self {
-        if !self.data.close_after_request {
-            panic!("Your wrapper must be configured to close the connection after a request is done in order to override the cookies.");
-        }
-
-        #[cfg(feature = "multi")]
-        {
-            self.data.cookies = cookies.to_owned();
-        }
-        #[cfg(not(feature = "multi"))]
-        {
-            self.data.cookies = cookies;
-        }
+        self.data.cookies = cookies.into();
+        self.cookies_overridden = true;
         self
     }
 
@@ -88,12 +81,27 @@ impl<'a> WrapperTermRequestBuilder<'a> {
     ///
     /// # Parameters
     /// - `client`: The client to use. This will _not_ override the client for the
-    ///             wrapper, just this request.
+    ///   wrapper, just this request.
     ///
     /// # Returns
     /// The builder.
     pub fn override_client(mut self, client: &'a Client) -> Self {
-        self.data.client = client;
+        self.data.transport = Arc::new(ReqwestTransport::new(client.clone()));
+        self.transport_overridden = true;
+        self
+    }
+
+    /// Overrides the transport for any requests made under this soon-to-be requester.
+    ///
+    /// # Parameters
+    /// - `transport`: The transport to use. This will _not_ override the transport for the
+    ///   wrapper, just this request.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn override_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.data.transport = Arc::new(transport);
+        self.transport_overridden = true;
         self
     }
 
@@ -101,7 +109,7 @@ impl<'a> WrapperTermRequestBuilder<'a> {
     ///
     /// # Parameters
     /// - `user_agent`: The user agent to use. This will _not_ override the user agent
-    ///                 for the wrapper, just this request.
+    ///   for the wrapper, just this request.
     ///
     /// # Returns
     /// The builder.
@@ -114,7 +122,7 @@ impl<'a> WrapperTermRequestBuilder<'a> {
     ///
     /// # Parameters
     /// - `duration`: The timeout to use. This will _not_ override the timeout
-    ///               for the wrapper, just this request.
+    ///   for the wrapper, just this request.
     ///
     /// # Returns
     /// The builder.
@@ -127,9 +135,30 @@ impl<'a> WrapperTermRequestBuilder<'a> {
     /// internally by one of the two public build functions.
     ///
     /// # Returns
-    /// A structure containing the actual request information.
-    fn build(self) -> WebRegWrapperDataRef<'a> {
-        self.data
+    /// A structure containing the actual request information, along with the term.
+    fn build(mut self) -> (WebRegWrapperDataRef<'a>, Cow<'a, str>) {
+        if self.cookies_overridden && !self.data.close_after_request {
+            // An overridden transport (e.g., the wrapper's own client) may still share its
+            // connections with other sessions, so it's only used as-is if it's shareable.
+            let transport = if self.transport_overridden {
+                self.data
+                    .transport
+                    .is_shareable()
+                    .then(|| self.data.transport.clone())
+            } else {
+                self.transport_cache
+                    .get(&self.data.cookies, &self.data.transport)
+            };
+
+            match transport {
+                Some(transport) => self.data.transport = transport,
+                // Without a transport of its own, the request must not leave a connection
+                // open for another session to reuse.
+                None => self.data.close_after_request = true,
+            }
+        }
+
+        (self.data, self.term)
     }
 
     /// Builds the requester that can be used to generally obtain raw responses from WebReg.
@@ -144,10 +173,8 @@ impl<'a> WrapperTermRequestBuilder<'a> {
     /// # Returns
     /// The raw requester.
     pub fn raw(self) -> WrapperTermRawRequest<'a> {
-        WrapperTermRawRequest {
-            term: self.term,
-            info: self.build(),
-        }
+        let (info, term) = self.build();
+        WrapperTermRawRequest { info, term }
     }
 
     /// Builds the requester that can be used to make many different calls (GET, POST) to
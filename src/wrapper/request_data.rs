@@ -1,50 +1,61 @@
-#[cfg(feature = "multi")]
-use parking_lot::Mutex;
-use reqwest::header::{CONNECTION, COOKIE, USER_AGENT};
-use reqwest::{Client, IntoUrl, RequestBuilder};
+use reqwest::header::{CONNECTION, CONTENT_TYPE, COOKIE, USER_AGENT};
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
+use url::Url;
 
-pub(crate) enum ReqType<U: IntoUrl> {
-    Post(U),
+use crate::schema::SchemaObserver;
+use crate::types;
+use crate::wrapper::input_types::HttpMethod;
+use crate::wrapper::middleware::{Hooks, Middleware, RequestMeta};
+use crate::wrapper::transport::{HttpRequest, Transport, TransportCache};
+
+pub(crate) enum ReqType<'f, U: AsRef<str>> {
+    /// A `POST` request, along with the form to send.
+    Post(U, &'f [(&'f str, &'f str)]),
     Get(U),
 }
 
 /// A structure that represents data held by the wrapper or a request.
 pub struct WebRegWrapperData {
-    /// The cookies.
-    #[cfg(feature = "multi")]
-    pub(crate) cookies: Mutex<String>,
-    #[cfg(not(feature = "multi"))]
-    pub(crate) cookies: String,
-    /// The client used to make the request.
-    pub(crate) client: Client,
+    /// The cookies. These are swapped out as a whole when they're changed, so a request
+    /// only needs to clone the `Arc` to use them.
+    pub(crate) cookies: RwLock<Arc<str>>,
+    /// The transport used to make the request.
+    pub(crate) transport: Arc<dyn Transport>,
     /// The user agent.
     pub(crate) user_agent: String,
     /// The timeout for this request.
     pub(crate) timeout: Duration,
     /// Whether to close the connection after the request has been completed.
-    ///
-    /// Setting this field to `true` means that your requests can be made with different cookies
-    /// with the same wrapper. However, this also means that you'll incur a performance hit when
-    /// making a request.
-    ///
-    /// It is recommended that this field's value is set to `false` if you do not need to switch
-    /// cookies for this wrapper.
     pub(crate) close_after_request: bool,
+    /// The callback given any schema drift found in WebReg's responses, if any.
+    pub(crate) schema_observer: Option<SchemaObserver>,
+    /// The middleware run around every request, in order.
+    pub(crate) middleware: Vec<Arc<dyn Middleware>>,
+    /// The transports used for requests that override the cookies.
+    pub(crate) transport_cache: TransportCache,
 }
 
-impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperData {
-    #[cfg(feature = "multi")]
-    fn get_cookies(&'a self) -> String {
-        self.cookies.lock().to_owned()
+impl WebRegWrapperData {
+    /// Replaces the cookies.
+    ///
+    /// # Parameters
+    /// - `cookies`: The new cookies.
+    pub(crate) fn set_cookies(&self, cookies: Arc<str>) {
+        *self.cookies.write().unwrap_or_else(|e| e.into_inner()) = cookies;
     }
-    #[cfg(not(feature = "multi"))]
-    fn get_cookies(&'a self) -> &'a str {
-        self.cookies.as_str()
+}
+
+impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperData {
+    fn get_cookies(&'a self) -> Arc<str> {
+        self.cookies
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
     }
 
-    fn get_client(&'a self) -> &'a Client {
-        &self.client
+    fn get_transport(&'a self) -> &'a dyn Transport {
+        self.transport.as_ref()
     }
 
     fn get_user_agent(&'a self) -> &'a str {
@@ -58,41 +69,37 @@ impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperData {
     fn close_after_request(&'a self) -> bool {
         self.close_after_request
     }
+
+    fn get_middleware(&'a self) -> &'a [Arc<dyn Middleware>] {
+        &self.middleware
+    }
 }
 
 /// A structure that represents data held by the wrapper or a request.
 pub(crate) struct WebRegWrapperDataRef<'a> {
-    #[cfg(feature = "multi")]
-    pub cookies: String,
     /// The cookies.
-    #[cfg(not(feature = "multi"))]
-    pub cookies: &'a str,
-    /// The client used to make the request.
-    pub client: &'a Client,
+    pub cookies: Arc<str>,
+    /// The transport used to make the request.
+    pub transport: Arc<dyn Transport>,
     /// The user agent.
     pub user_agent: &'a str,
     /// The timeout for this request.
     pub timeout: Duration,
     /// Whether to close the connection after the request has been completed.
-    ///
-    /// Setting this field to `true` means that your requests can be made with different cookies
-    /// with the same wrapper. However, this also means that you'll incur a performance hit when
-    /// making a request.
     pub close_after_request: bool,
+    /// The callback given any schema drift found in WebReg's responses, if any.
+    pub schema_observer: Option<&'a SchemaObserver>,
+    /// The middleware run around every request, in order.
+    pub middleware: &'a [Arc<dyn Middleware>],
 }
 
 impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperDataRef<'a> {
-    #[cfg(feature = "multi")]
-    fn get_cookies(&'a self) -> String {
-        self.cookies.to_owned()
-    }
-    #[cfg(not(feature = "multi"))]
-    fn get_cookies(&'a self) -> &'a str {
-        self.cookies
+    fn get_cookies(&'a self) -> Arc<str> {
+        self.cookies.clone()
     }
 
-    fn get_client(&'a self) -> &'a Client {
-        self.client
+    fn get_transport(&'a self) -> &'a dyn Transport {
+        self.transport.as_ref()
     }
 
     fn get_user_agent(&'a self) -> &'a str {
@@ -106,6 +113,10 @@ impl<'a> ReqwestWebRegClientData<'a> for WebRegWrapperDataRef<'a> {
     fn close_after_request(&'a self) -> bool {
         self.close_after_request
     }
+
+    fn get_middleware(&'a self) -> &'a [Arc<dyn Middleware>] {
+        self.middleware
+    }
 }
 
 pub(crate) trait ReqwestWebRegClientData<'a> {
@@ -113,16 +124,13 @@ pub(crate) trait ReqwestWebRegClientData<'a> {
     ///
     /// # Returns
     /// The cookies.
-    #[cfg(not(feature = "multi"))]
-    fn get_cookies(&'a self) -> &'a str;
-    #[cfg(feature = "multi")]
-    fn get_cookies(&'a self) -> String;
+    fn get_cookies(&'a self) -> Arc<str>;
 
-    /// The client to be used for this request.
+    /// The transport to be used for this request.
     ///
     /// # Returns
-    /// The client.
-    fn get_client(&'a self) -> &'a Client;
+    /// The transport.
+    fn get_transport(&'a self) -> &'a dyn Transport;
 
     /// The user agent to be used for this request.
     ///
@@ -142,30 +150,87 @@ pub(crate) trait ReqwestWebRegClientData<'a> {
     /// Whether the connection should be closed after the request is completed.
     fn close_after_request(&'a self) -> bool;
 
-    /// Makes a request with the desired request type using the headers provided by the user.
+    /// The middleware to run around this request.
+    ///
+    /// # Returns
+    /// The middleware, in the order that it should be run.
+    fn get_middleware(&'a self) -> &'a [Arc<dyn Middleware>];
+
+    /// Collects the middleware to run for a single request.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The URL being requested. Any query string is dropped.
+    /// - `term`: The term that the request is for, if any.
+    /// - `operation`: The name of the wrapper function making the request.
+    ///
+    /// # Returns
+    /// The hooks for the request.
+    fn hooks<'h>(
+        &'a self,
+        endpoint: &'h str,
+        term: Option<&'h str>,
+        operation: &'static str,
+    ) -> Hooks<'h>
+    where
+        'a: 'h,
+    {
+        Hooks {
+            middleware: self.get_middleware(),
+            meta: RequestMeta {
+                endpoint: endpoint.split('?').next().unwrap_or(endpoint),
+                term,
+                operation,
+            },
+        }
+    }
+
+    /// Makes a request with the desired request type using the headers provided by the user,
+    /// and then runs it through the middleware.
     ///
     /// # Parameters
     /// - `req_type`: The request type.
+    /// - `hooks`: The middleware to run for this request.
     ///
     /// # Returns
-    /// A request builder that can further be built on top of, if needed.
-    fn req<U>(&'a self, req_type: ReqType<U>) -> RequestBuilder
+    /// The request, ready to be given to the transport, or the error returned by a
+    /// middleware.
+    fn req<U>(&'a self, req_type: ReqType<'_, U>, hooks: &Hooks<'_>) -> types::Result<HttpRequest>
     where
-        U: IntoUrl,
+        U: AsRef<str>,
     {
-        let client = self.get_client();
-        let mut req = match req_type {
-            ReqType::Post(u) => client.post(u),
-            ReqType::Get(u) => client.get(u),
+        let (method, url, body) = match req_type {
+            ReqType::Post(u, form) => (
+                HttpMethod::Post,
+                u,
+                Some(
+                    url::form_urlencoded::Serializer::new(String::new())
+                        .extend_pairs(form)
+                        .finish(),
+                ),
+            ),
+            ReqType::Get(u) => (HttpMethod::Get, u, None),
+        };
+
+        let mut req = HttpRequest {
+            method,
+            url: Url::parse(url.as_ref())?,
+            headers: vec![
+                (COOKIE.to_string(), self.get_cookies().to_string()),
+                (USER_AGENT.to_string(), self.get_user_agent().to_owned()),
+            ],
+            body,
+            timeout: self.get_timeout(),
+        };
+
+        if req.body.is_some() {
+            req.set_header(CONTENT_TYPE.as_str(), "application/x-www-form-urlencoded");
         }
-        .header(COOKIE, self.get_cookies())
-        .header(USER_AGENT, self.get_user_agent())
-        .timeout(self.get_timeout());
 
         if self.close_after_request() {
-            req = req.header(CONNECTION, "close");
+            req.set_header(CONNECTION.as_str(), "close");
         }
 
-        req
+        hooks.before_request(&mut req)?;
+        Ok(req)
     }
 }
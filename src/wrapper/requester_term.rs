@@ -1,79 +1,92 @@
-use std::collections::{HashMap, HashSet};
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap, HashSet};
 
 use url::Url;
 
+use crate::compare::{compare_schedules, ScheduleComparison};
 use crate::constants::{
-    ALL_SCHEDULE, CHANGE_ENROLL, COURSE_DATA, COURSE_TEXT, CURR_SCHEDULE, DEFAULT_SCHEDULE_NAME,
-    DEPT_LIST, ENROLL_ADD, ENROLL_DROP, ENROLL_EDIT, EVENT_ADD, EVENT_EDIT, EVENT_GET,
-    EVENT_REMOVE, PLAN_ADD, PLAN_EDIT, PLAN_REMOVE, PLAN_REMOVE_ALL, PREREQS_INFO, REMOVE_SCHEDULE,
-    RENAME_SCHEDULE, SECTION_TEXT, SEND_EMAIL, SUBJ_LIST, WAITLIST_ADD, WAITLIST_DROP,
-    WAITLIST_EDIT,
+    ALL_SCHEDULE, BATCH_CONCURRENCY, CHANGE_ENROLL, COURSE_DATA, COURSE_TEXT, CURR_SCHEDULE,
+    DEFAULT_SCHEDULE_NAME, DEPT_LIST, ENROLL_ADD, ENROLL_DROP, ENROLL_EDIT, EVENT_ADD, EVENT_EDIT,
+    EVENT_GET, EVENT_REMOVE, PLAN_ADD, PLAN_EDIT, PLAN_REMOVE, PLAN_REMOVE_ALL, PREREQS_INFO,
+    REMOVE_SCHEDULE, RENAME_SCHEDULE, SECTION_TEXT, SEND_EMAIL, SUBJ_LIST, WAITLIST_ADD,
+    WAITLIST_DROP, WAITLIST_EDIT, WRADAPTER_BASE,
 };
+use crate::prereq::PrereqGraph;
 use crate::raw_types::{
     RawCourseTextItem, RawDepartmentElement, RawEvent, RawPrerequisite, RawScheduledMeeting,
     RawSectionTextItem, RawSubjectElement, RawWebRegMeeting, RawWebRegSearchResultItem,
 };
+use crate::schema::SchemaReport;
 use crate::types::{
-    Courses, Events, PrerequisiteInfo, Schedule, SearchResult, SearchResultItem,
-    SectionIdNotFoundContext, WrapperError,
+    CourseKey, CourseSection, Courses, Department, EnrollmentStatus, EventItemResult, EventReport,
+    Events, LenientParse, PlanItemResult, PlanReport, PrerequisiteInfo, RawResponse, Schedule,
+    SearchResult, SearchResultItem, SectionId, SectionIdNotFoundContext, Subject, WrapperError,
 };
+use crate::util::to_section_id;
 use crate::wrapper::input_types::{
-    AddType, DayOfWeek, EnrollWaitAdd, EventAdd, ExplicitAddType, GradeOption, PlanAdd, SearchType,
+    AddType, DayOfWeek, EnrollWaitAdd, EventAdd, ExplicitAddType, GradeOption, HttpMethod, PlanAdd,
+    SearchRequestBuilder, SearchType,
 };
 use crate::wrapper::request_data::{ReqType, ReqwestWebRegClientData, WebRegWrapperDataRef};
 use crate::wrapper::ww_helper::{
-    associate_term_helper, extract_text, process_get_text, process_post_response,
+    associate_term_helper, extract_text, join_limited, process_get_text, process_get_text_observed,
+    process_raw_post_response, send,
 };
 use crate::ww_parser::{
-    build_search_course_url, parse_course_info, parse_enrollment_count, parse_get_events,
-    parse_prerequisites, parse_schedule,
+    build_search_course_url, parse_course_info, parse_course_info_lenient, parse_departments,
+    parse_enrollment_count, parse_get_events, parse_prerequisites, parse_schedule,
+    parse_schedule_lenient, parse_subjects,
 };
 use crate::{types, util};
 
 /// A structure that can be used to get raw data from WebReg, with minimal error handling.
 ///
-/// Keep in mind that this structure only gives you some API access, as these APIs give you
-/// interesting data. For full access, consider using `WrapperTermRequest`.
+/// GET requests give you the response text. POST requests (e.g., adding, dropping, or
+/// planning sections) give you the untouched response body and status code, since WebReg
+/// may say a request failed in the body even if the status code indicates success. For any
+/// endpoint that isn't covered here, use `call`.
+///
+/// Keep in mind that this structure does very little for you; for example, it won't validate
+/// your input, or check whether you should enroll in or waitlist a section. For that,
+/// consider using `WrapperTermRequest`.
 pub struct WrapperTermRawRequest<'a> {
     pub(crate) info: WebRegWrapperDataRef<'a>,
-    pub(crate) term: &'a str,
+    pub(crate) term: Cow<'a, str>,
 }
 
 impl<'a> WrapperTermRawRequest<'a> {
     /// Gets all prerequisites for a specified course for the term set by the wrapper.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_code`: The course code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `course`: The course, either as a `CourseId` or as a subject code and course number
+    ///   pair. For example, if you wanted to check `MATH 100B`, you could put
+    ///   `("MATH", "100B")`.
     ///
     /// # Returns
     /// Prerequisite data as returned by WebReg.
     pub async fn get_prerequisites(
         &self,
-        subject_code: impl AsRef<str>,
-        course_code: impl AsRef<str>,
+        course: impl Into<CourseKey<'_>>,
     ) -> types::Result<String> {
-        let crsc_code = util::get_formatted_course_num(course_code.as_ref());
+        let (subj_code, crsc_code) = course.into().to_wire();
         let url = Url::parse_with_params(
             PREREQS_INFO,
             &[
-                ("subjcode", subject_code.as_ref()),
+                ("subjcode", subj_code.as_str()),
                 ("crsecode", crsc_code.as_str()),
-                ("termcode", self.term),
+                ("termcode", self.term.as_ref()),
                 ("_", util::get_epoch_time().to_string().as_ref()),
             ],
         )?;
 
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        self.get_text(url, "get_prerequisites").await
     }
 
     /// Gets your current schedule.
     ///
     /// # Parameters
     /// - `schedule_name`: The schedule that you want to get. If `None` is given, this will default
-    /// to your main schedule.
+    ///   to your main schedule.
     ///
     /// # Returns
     /// Schedule data as returned by WebReg.
@@ -84,12 +97,12 @@ impl<'a> WrapperTermRawRequest<'a> {
                 ("schedname", schedule_name.unwrap_or(DEFAULT_SCHEDULE_NAME)),
                 ("final", ""),
                 ("sectnum", ""),
-                ("termcode", self.term),
+                ("termcode", self.term.as_ref()),
                 ("_", util::get_epoch_time().to_string().as_str()),
             ],
         )?;
 
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        self.get_text(url, "get_schedule").await
     }
 
     /// Gets course information for a particular course.
@@ -102,30 +115,25 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// Additionally, this implementation will not retrieve canceled sections.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_num`: The course number. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `course`: The course, either as a `CourseId` or as a subject code and course number
+    ///   pair. For example, if you wanted to check `MATH 100B`, you could put
+    ///   `("MATH", "100B")`.
     ///
     /// # Returns
     /// Course information, as returned by WebReg.
-    pub async fn get_course_info(
-        &self,
-        subject_code: impl AsRef<str>,
-        course_num: impl AsRef<str>,
-    ) -> types::Result<String> {
-        let crsc_code = util::get_formatted_course_num(course_num.as_ref());
+    pub async fn get_course_info(&self, course: impl Into<CourseKey<'_>>) -> types::Result<String> {
+        let (subj_code, crsc_code) = course.into().to_wire();
         let url = Url::parse_with_params(
             COURSE_DATA,
             &[
-                ("subjcode", subject_code.as_ref()),
+                ("subjcode", subj_code.as_str()),
                 ("crsecode", crsc_code.as_str()),
-                ("termcode", self.term),
+                ("termcode", self.term.as_ref()),
                 ("_", util::get_epoch_time().to_string().as_ref()),
             ],
         )?;
 
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        self.get_text(url, "get_course_info").await
     }
 
     /// Gets a list of all departments that are offering courses for the given term.
@@ -133,17 +141,15 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// # Returns
     /// Department codes, as returned by WebReg.
     pub async fn get_department_codes(&self) -> types::Result<String> {
-        extract_text(
-            self.info
-                .req(ReqType::Get(Url::parse_with_params(
-                    DEPT_LIST,
-                    &[
-                        ("termcode", self.term),
-                        ("_", util::get_epoch_time().to_string().as_str()),
-                    ],
-                )?))
-                .send()
-                .await,
+        self.get_text(
+            Url::parse_with_params(
+                DEPT_LIST,
+                &[
+                    ("termcode", self.term.as_ref()),
+                    ("_", util::get_epoch_time().to_string().as_str()),
+                ],
+            )?,
+            "get_department_codes",
         )
         .await
     }
@@ -153,17 +159,15 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// # Returns
     /// Subject codes, as returned by WebReg.
     pub async fn get_subject_codes(&self) -> types::Result<String> {
-        extract_text(
-            self.info
-                .req(ReqType::Get(Url::parse_with_params(
-                    SUBJ_LIST,
-                    &[
-                        ("termcode", self.term),
-                        ("_", util::get_epoch_time().to_string().as_str()),
-                    ],
-                )?))
-                .send()
-                .await,
+        self.get_text(
+            Url::parse_with_params(
+                SUBJ_LIST,
+                &[
+                    ("termcode", self.term.as_ref()),
+                    ("_", util::get_epoch_time().to_string().as_str()),
+                ],
+            )?,
+            "get_subject_codes",
         )
         .await
     }
@@ -177,11 +181,9 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// # Returns
     /// Search results, as returned by WebReg.
     pub async fn search_courses(&self, filter_by: SearchType) -> types::Result<String> {
-        extract_text(
-            self.info
-                .req(ReqType::Get(build_search_course_url(filter_by, self.term)?))
-                .send()
-                .await,
+        self.get_text(
+            build_search_course_url(filter_by, self.term.as_ref())?,
+            "search_courses",
         )
         .await
     }
@@ -191,8 +193,8 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// # Returns
     /// Information about any events you added, as returned by WebReg.
     pub async fn get_events(&self) -> types::Result<String> {
-        let url = Url::parse_with_params(EVENT_GET, &[("termcode", self.term)]).unwrap();
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        let url = Url::parse_with_params(EVENT_GET, &[("termcode", self.term.as_ref())]).unwrap();
+        self.get_text(url, "get_events").await
     }
 
     /// Gets all of your schedules.
@@ -200,8 +202,8 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// # Returns
     /// Your schedule list, as returned by WebReg.
     pub async fn get_schedule_list(&self) -> types::Result<String> {
-        let url = Url::parse_with_params(ALL_SCHEDULE, &[("termcode", self.term)])?;
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        let url = Url::parse_with_params(ALL_SCHEDULE, &[("termcode", self.term.as_ref())])?;
+        self.get_text(url, "get_schedule_list").await
     }
 
     /// Gets a list of all course notes for one or more subjects.
@@ -219,9 +221,12 @@ impl<'a> WrapperTermRawRequest<'a> {
             .join(":");
         let url = Url::parse_with_params(
             COURSE_TEXT,
-            &[("subjlist", subj_list.as_str()), ("termcode", self.term)],
+            &[
+                ("subjlist", subj_list.as_str()),
+                ("termcode", self.term.as_ref()),
+            ],
         )?;
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        self.get_text(url, "get_course_notes").await
     }
 
     /// Gets a list of all section notes for one or more sections.
@@ -239,9 +244,12 @@ impl<'a> WrapperTermRawRequest<'a> {
             .join(":");
         let url = Url::parse_with_params(
             SECTION_TEXT,
-            &[("sectnumlist", sec_list.as_str()), ("termcode", self.term)],
+            &[
+                ("sectnumlist", sec_list.as_str()),
+                ("termcode", self.term.as_ref()),
+            ],
         )?;
-        extract_text(self.info.req(ReqType::Get(url)).send().await).await
+        self.get_text(url, "get_section_notes").await
     }
 
     /// Associates the term bound by this request to the cookies that are provided
@@ -251,7 +259,556 @@ impl<'a> WrapperTermRawRequest<'a> {
     /// A result, where nothing is returned if everything went well and an error is returned
     /// if something went wrong.
     pub async fn associate_term(&self) -> types::Result<()> {
-        associate_term_helper(&self.info, self.term).await
+        associate_term_helper(&self.info, self.term.as_ref()).await
+    }
+
+    /// Makes a request to any WebReg (`wradapter`) endpoint, including ones that this crate
+    /// doesn't otherwise support.
+    ///
+    /// The term bound to this requester is sent as the `termcode` parameter, unless you
+    /// give your own `termcode` parameter.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The endpoint, relative to `https://act.ucsd.edu/webreg2/svc/wradapter/`.
+    ///   For example, `secure/get-class`.
+    /// - `method`: The HTTP method. Parameters are sent as the query string for `GET`
+    ///   requests, and as a form for `POST` requests.
+    /// - `params`: The parameters.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made or if the
+    /// endpoint isn't under `wradapter` (e.g., an absolute URL or a path with `..`).
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::HttpMethod;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let res = wrapper
+    ///     .req("FA23")
+    ///     .raw()
+    ///     .call(
+    ///         "secure/get-class",
+    ///         HttpMethod::Get,
+    ///         &[("schedname", "My Schedule"), ("final", ""), ("sectnum", "")],
+    ///     )
+    ///     .await
+    ///     .unwrap();
+    /// println!("{}: {}", res.status, res.body);
+    /// # }
+    /// ```
+    pub async fn call(
+        &self,
+        endpoint: &str,
+        method: HttpMethod,
+        params: &[(&str, &str)],
+    ) -> types::Result<RawResponse> {
+        let url = Url::parse(WRADAPTER_BASE)?.join(endpoint.trim_start_matches('/'))?;
+        // Absolute URLs and `..` segments would let the request (and the cookies) go
+        // somewhere other than WebReg.
+        if !url.as_str().starts_with(WRADAPTER_BASE) {
+            return Err(WrapperError::InputError(
+                "endpoint",
+                "must be a path relative to the wradapter endpoint",
+            ));
+        }
+
+        let mut params = params.to_vec();
+        if !params.iter().any(|(k, _)| *k == "termcode") {
+            params.push(("termcode", self.term.as_ref()));
+        }
+
+        let hooks = self.info.hooks(url.as_str(), Some(&self.term), "call");
+        let req = match method {
+            HttpMethod::Get => self.info.req(
+                ReqType::Get(Url::parse_with_params(url.as_str(), &params)?),
+                &hooks,
+            )?,
+            HttpMethod::Post => self
+                .info
+                .req(ReqType::Post(url.as_str(), &params), &hooks)?,
+        };
+
+        send(self.info.get_transport(), req, &hooks).await
+    }
+
+    /// Sends an email to yourself using the same email that is used to confirm that you
+    /// have enrolled or waitlisted in a particular class.
+    ///
+    /// # Parameters
+    /// - `email_content`: The content of the email.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn send_email_to_self(&self, email_content: &str) -> types::Result<RawResponse> {
+        self.post(
+            SEND_EMAIL,
+            &[
+                ("actionevent", email_content),
+                ("termcode", self.term.as_ref()),
+            ],
+            "send_email_to_self",
+        )
+        .await
+    }
+
+    /// Changes the grading option for a class that you are enrolled or waitlisted in.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID of the class.
+    /// - `unit_count`: The number of units that you are taking the class for.
+    /// - `new_grade_opt`: The new grading option.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn change_grading_option(
+        &self,
+        section_id: SectionId,
+        unit_count: i64,
+        new_grade_opt: GradeOption,
+    ) -> types::Result<RawResponse> {
+        // The schedule endpoint gives us section IDs as integers (e.g., `79911` instead
+        // of `079911`), so we send the section ID back in that same form.
+        let sec_id = section_id.unpadded();
+        let units = unit_count.to_string();
+        self.post(
+            CHANGE_ENROLL,
+            &[
+                ("section", sec_id.as_str()),
+                ("subjCode", ""),
+                ("crseCode", ""),
+                ("unit", units.as_str()),
+                ("grade", new_grade_opt.as_str()),
+                // You don't actually need these
+                ("oldGrade", ""),
+                ("oldUnit", ""),
+                ("termcode", self.term.as_ref()),
+            ],
+            "change_grading_option",
+        )
+        .await
+    }
+
+    /// Validates that adding a course to your plan will cause no issue.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the course that you want to plan.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn validate_add_to_plan(
+        &self,
+        plan_options: &PlanAdd<'_>,
+    ) -> types::Result<RawResponse> {
+        let (subj_code, crsc_code) = CourseKey::new(
+            plan_options.subject_code.as_ref(),
+            plan_options.course_code.as_ref(),
+        )
+        .to_wire();
+        let section_id = plan_options.section_id.padded();
+        self.post(
+            PLAN_EDIT,
+            &[
+                ("section", section_id.as_str()),
+                ("subjcode", subj_code.as_str()),
+                ("crsecode", crsc_code.as_str()),
+                ("termcode", self.term.as_ref()),
+            ],
+            "validate_add_to_plan",
+        )
+        .await
+    }
+
+    /// Plans a course. Unlike the parsed requester, this will _not_ validate your planning
+    /// of the course beforehand; call `validate_add_to_plan` first if you need to.
+    ///
+    /// # Parameters
+    /// - `plan_options`: Information for the course that you want to plan.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn add_to_plan(&self, plan_options: &PlanAdd<'_>) -> types::Result<RawResponse> {
+        let u = plan_options.unit_count.to_string();
+        let (subj_code, crsc_code) = CourseKey::new(
+            plan_options.subject_code.as_ref(),
+            plan_options.course_code.as_ref(),
+        )
+        .to_wire();
+        let section_id = plan_options.section_id.padded();
+        self.post(
+            PLAN_ADD,
+            &[
+                ("subjcode", subj_code.as_str()),
+                ("crsecode", crsc_code.as_str()),
+                ("sectnum", section_id.as_str()),
+                ("sectcode", plan_options.section_code.as_ref()),
+                ("unit", u.as_str()),
+                (
+                    "grade",
+                    plan_options
+                        .grading_option
+                        .unwrap_or(GradeOption::L)
+                        .as_str(),
+                ),
+                ("termcode", self.term.as_ref()),
+                (
+                    "schedname",
+                    match plan_options.schedule_name {
+                        Some(ref r) => r.as_ref(),
+                        None => DEFAULT_SCHEDULE_NAME,
+                    },
+                ),
+            ],
+            "add_to_plan",
+        )
+        .await
+    }
+
+    /// Unplans a course.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    /// - `schedule_name`: The schedule name where the course should be unplanned from. If
+    ///   `None` is given, this will default to your main schedule.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the section ID is invalid or the request
+    /// couldn't be made.
+    pub async fn remove_from_plan(
+        &self,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+        schedule_name: Option<&str>,
+    ) -> types::Result<RawResponse> {
+        let section_id = to_section_id(section_id)?.padded();
+        self.post(
+            PLAN_REMOVE,
+            &[
+                ("sectnum", section_id.as_str()),
+                ("termcode", self.term.as_ref()),
+                ("schedname", schedule_name.unwrap_or(DEFAULT_SCHEDULE_NAME)),
+            ],
+            "remove_from_plan",
+        )
+        .await
+    }
+
+    /// Unplans a course from all of your schedules. This is done after enrolling in or
+    /// waitlisting a course.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the section ID is invalid or the request
+    /// couldn't be made.
+    pub async fn remove_from_all_plans(
+        &self,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+    ) -> types::Result<RawResponse> {
+        let section_id = to_section_id(section_id)?.padded();
+        self.post(
+            PLAN_REMOVE_ALL,
+            &[
+                ("sectnum", section_id.as_str()),
+                ("termcode", self.term.as_ref()),
+            ],
+            "remove_from_all_plans",
+        )
+        .await
+    }
+
+    /// Validates that the section that you are trying to enroll in or waitlist is valid.
+    ///
+    /// # Parameters
+    /// - `add_type`: Whether to validate enrolling in or waitlisting the section.
+    /// - `enroll_options`: The enrollment options. Only the section ID is used.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn validate_add_section(
+        &self,
+        add_type: ExplicitAddType,
+        enroll_options: &EnrollWaitAdd,
+    ) -> types::Result<RawResponse> {
+        let section_id = enroll_options.section_id.padded();
+        self.post(
+            match add_type {
+                ExplicitAddType::Enroll => ENROLL_EDIT,
+                ExplicitAddType::Waitlist => WAITLIST_EDIT,
+            },
+            &[
+                // These are required
+                ("section", section_id.as_str()),
+                ("termcode", self.term.as_ref()),
+                // These are optional.
+                ("subjcode", ""),
+                ("crsecode", ""),
+            ],
+            "validate_add_section",
+        )
+        .await
+    }
+
+    /// Enrolls in, or waitlists, a section. Unlike the parsed requester, this will _not_
+    /// validate the section beforehand (which WebReg requires), nor remove the section
+    /// from your plans afterwards; call `validate_add_section` and `remove_from_all_plans`
+    /// for that.
+    ///
+    /// # Parameters
+    /// - `add_type`: Whether to enroll in or waitlist the section.
+    /// - `enroll_options`: Information for the section that you want to enroll in.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn add_section(
+        &self,
+        add_type: ExplicitAddType,
+        enroll_options: &EnrollWaitAdd,
+    ) -> types::Result<RawResponse> {
+        let section_id = enroll_options.section_id.padded();
+        let u = match enroll_options.unit_count {
+            Some(r) => r.to_string(),
+            None => "".to_string(),
+        };
+
+        self.post(
+            match add_type {
+                ExplicitAddType::Enroll => ENROLL_ADD,
+                ExplicitAddType::Waitlist => WAITLIST_ADD,
+            },
+            &[
+                // These are required
+                ("section", section_id.as_str()),
+                ("termcode", self.term.as_ref()),
+                // These are optional.
+                ("unit", u.as_str()),
+                (
+                    "grade",
+                    enroll_options
+                        .grading_option
+                        .unwrap_or(GradeOption::L)
+                        .as_str(),
+                ),
+                ("crsecode", ""),
+                ("subjcode", ""),
+            ],
+            "add_section",
+        )
+        .await
+    }
+
+    /// Drops a section.
+    ///
+    /// # Parameters
+    /// - `prev_enroll_status`: Your enrollment status (either `Enroll` or `Waitlist` if you
+    ///   are enrolled or waitlisted in the section, respectively).
+    /// - `section_id`: The section ID corresponding to the section that you want to drop.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the section ID is invalid or the request
+    /// couldn't be made.
+    pub async fn drop_section(
+        &self,
+        prev_enroll_status: ExplicitAddType,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+    ) -> types::Result<RawResponse> {
+        let section_id = to_section_id(section_id)?.padded();
+        self.post(
+            match prev_enroll_status {
+                ExplicitAddType::Enroll => ENROLL_DROP,
+                ExplicitAddType::Waitlist => WAITLIST_DROP,
+            },
+            &[
+                // These parameters are optional
+                ("subjcode", ""),
+                ("crsecode", ""),
+                // But these are required
+                ("section", section_id.as_str()),
+                ("termcode", self.term.as_ref()),
+            ],
+            "drop_section",
+        )
+        .await
+    }
+
+    /// Renames a schedule. Unlike the parsed requester, this doesn't stop you from trying
+    /// to rename the default `My Schedule` schedule.
+    ///
+    /// # Parameters
+    /// - `old_name`: The name of the old schedule.
+    /// - `new_name`: The name that you want to change the old name to.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn rename_schedule(
+        &self,
+        old_name: impl AsRef<str>,
+        new_name: impl AsRef<str>,
+    ) -> types::Result<RawResponse> {
+        self.post(
+            RENAME_SCHEDULE,
+            &[
+                ("termcode", self.term.as_ref()),
+                ("oldschedname", old_name.as_ref()),
+                ("newschedname", new_name.as_ref()),
+            ],
+            "rename_schedule",
+        )
+        .await
+    }
+
+    /// Removes a schedule. Unlike the parsed requester, this doesn't stop you from trying
+    /// to remove the default `My Schedule` schedule.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The name of the schedule to delete.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn remove_schedule(
+        &self,
+        schedule_name: impl AsRef<str>,
+    ) -> types::Result<RawResponse> {
+        self.post(
+            REMOVE_SCHEDULE,
+            &[
+                ("termcode", self.term.as_ref()),
+                ("schedname", schedule_name.as_ref()),
+            ],
+            "remove_schedule",
+        )
+        .await
+    }
+
+    /// Adds an event to your WebReg calendar, or edits an existing event. Unlike the parsed
+    /// requester, this will _not_ validate the event beforehand.
+    ///
+    /// # Parameters
+    /// - `event_info`: The details of the event.
+    /// - `event_timestamp`: The timestamp corresponding to the event that you want to
+    ///   edit. If this is `None`, then this function will add the event. If this is `Some`,
+    ///   then this function will edit an existing event.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn add_or_edit_event(
+        &self,
+        event_info: &EventAdd<'_>,
+        event_timestamp: Option<&str>,
+    ) -> types::Result<RawResponse> {
+        let start_time_full = format!("{:02}{:02}", event_info.start_hr, event_info.start_min);
+        let end_time_full = format!("{:02}{:02}", event_info.end_hr, event_info.end_min);
+
+        let mut days: [bool; 7] = [false; 7];
+        for d in &event_info.event_days {
+            let idx = match d {
+                DayOfWeek::Monday => 0,
+                DayOfWeek::Tuesday => 1,
+                DayOfWeek::Wednesday => 2,
+                DayOfWeek::Thursday => 3,
+                DayOfWeek::Friday => 4,
+                DayOfWeek::Saturday => 5,
+                DayOfWeek::Sunday => 6,
+            };
+
+            days[idx] = true;
+        }
+
+        let day_str = days
+            .iter()
+            .map(|d| if *d { '1' } else { '0' })
+            .collect::<String>();
+
+        let mut form_data = vec![
+            ("termcode", self.term.as_ref()),
+            ("aename", event_info.event_name.as_ref()),
+            ("aestarttime", start_time_full.as_str()),
+            ("aeendtime", end_time_full.as_str()),
+            (
+                "aelocation",
+                match event_info.location {
+                    None => "",
+                    Some(ref s) => s.as_ref(),
+                },
+            ),
+            ("aedays", day_str.as_str()),
+        ];
+
+        if let Some(timestamp) = event_timestamp {
+            form_data.push(("aetimestamp", timestamp));
+        }
+
+        self.post(
+            match event_timestamp {
+                Some(_) => EVENT_EDIT,
+                None => EVENT_ADD,
+            },
+            &form_data,
+            "add_or_edit_event",
+        )
+        .await
+    }
+
+    /// Removes an event from your WebReg calendar.
+    ///
+    /// # Parameters
+    /// - `event_timestamp`: The timestamp corresponding to the event that you want to
+    ///   remove.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    pub async fn remove_event(
+        &self,
+        event_timestamp: impl AsRef<str>,
+    ) -> types::Result<RawResponse> {
+        self.post(
+            EVENT_REMOVE,
+            &[
+                ("aetimestamp", event_timestamp.as_ref()),
+                ("termcode", self.term.as_ref()),
+            ],
+            "remove_event",
+        )
+        .await
+    }
+
+    /// Makes a GET request to a WebReg endpoint.
+    ///
+    /// # Parameters
+    /// - `url`: The endpoint, along with the query string.
+    /// - `operation`: The name of the function making the request, for the middleware.
+    ///
+    /// # Returns
+    /// The response text, or an error if the request failed.
+    async fn get_text(&self, url: Url, operation: &'static str) -> types::Result<String> {
+        let hooks = self.info.hooks(url.as_str(), Some(&self.term), operation);
+        let req = self.info.req(ReqType::Get(url.as_str()), &hooks)?;
+        extract_text(send(self.info.get_transport(), req, &hooks).await)
+    }
+
+    /// Sends a form to a WebReg endpoint.
+    ///
+    /// # Parameters
+    /// - `url`: The endpoint.
+    /// - `form`: The form.
+    /// - `operation`: The name of the function making the request, for the middleware.
+    ///
+    /// # Returns
+    /// The untouched response, or an error if the request couldn't be made.
+    async fn post(
+        &self,
+        url: &str,
+        form: &[(&str, &str)],
+        operation: &'static str,
+    ) -> types::Result<RawResponse> {
+        let hooks = self.info.hooks(url, Some(&self.term), operation);
+        let req = self.info.req(ReqType::Post(url, form), &hooks)?;
+        send(self.info.get_transport(), req, &hooks).await
     }
 }
 
@@ -265,10 +822,9 @@ impl<'a> WrapperTermRawRequest<'a> {
 /// Prefer `WrapperTermRawRequest` if you want more control over how WebReg data should be
 /// parsed into a structure.
 ///
-/// Keep in mind that this structure gives you the most access to various WebReg APIs that
-/// involve `GET`ting data or `POST`ing data. The raw variant only gives you limited access
-/// to some GET requests (the ones that return interesting data) and none of the POST requests
-/// (since they don't give you anything useful).
+/// Keep in mind that this structure does a lot of work for you. For example, when enrolling in
+/// a section, it will validate the section beforehand and remove the section from your plans
+/// afterwards; the raw variant leaves these steps to you.
 ///
 /// In fact, `WrapperTermRequest` makes direct use of `WrapperTermRawRequest`.
 pub struct WrapperTermRequest<'a> {
@@ -279,10 +835,9 @@ impl<'a> WrapperTermRequest<'a> {
     /// Gets all prerequisites for a specified course for the term set by the wrapper.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_code`: The course code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `course`: The course, either as a `CourseId` or as a subject code and course number
+    ///   pair. For example, if you wanted to check `MATH 100B`, you could put
+    ///   `("MATH", "100B")`.
     ///
     /// # Returns
     /// All prerequisites for the specified course. This is a structure that has two fields: one
@@ -320,7 +875,7 @@ impl<'a> WrapperTermRequest<'a> {
     /// let prereqs = wrapper
     ///     .req("FA23")
     ///     .parsed()
-    ///     .get_prerequisites("COGS", "108")
+    ///     .get_prerequisites(("COGS", "108"))
     ///     .await;
     ///
     /// if let Ok(prereq_info) = prereqs {
@@ -331,21 +886,70 @@ impl<'a> WrapperTermRequest<'a> {
     /// ```
     pub async fn get_prerequisites(
         &self,
-        subject_code: impl AsRef<str>,
-        course_code: impl AsRef<str>,
+        course: impl Into<CourseKey<'_>>,
     ) -> types::Result<PrerequisiteInfo> {
-        parse_prerequisites(process_get_text::<Vec<RawPrerequisite>>(
-            self.raw
-                .get_prerequisites(subject_code, course_code)
-                .await?,
+        parse_prerequisites(process_get_text_observed::<RawPrerequisite>(
+            self.raw.info.schema_observer,
+            "get_prerequisites",
+            self.raw.get_prerequisites(course).await?,
         )?)
     }
 
+    /// Recursively gets all prerequisites for a specified course for the term set by the
+    /// wrapper, building a graph of the full prerequisite chain (e.g., `MATH 20C` requires
+    /// `MATH 20B`, which requires `MATH 20A`, and so on). Each course is only fetched once,
+    /// even if it's a prerequisite of several courses.
+    ///
+    /// # Parameters
+    /// - `course`: The course, either as a `CourseId` or as a subject code and course number
+    ///   pair. For example, if you wanted to check `MATH 20C`, you could put `("MATH", "20C")`.
+    /// - `depth`: The number of levels of prerequisites to fetch. A depth of `1` gives you the
+    ///   same information as `get_prerequisites`.
+    ///
+    /// # Returns
+    /// The prerequisite graph, which can be exported to DOT or JSON, or an error if any of
+    /// the requests failed.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let graph = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .prereq_graph(("MATH", "20C"), 5)
+    ///     .await;
+    ///
+    /// if let Ok(graph) = graph {
+    ///     println!("{}", graph.to_dot());
+    /// }
+    /// # }
+    /// ```
+    pub async fn prereq_graph(
+        &self,
+        course: impl Into<CourseKey<'_>>,
+        depth: usize,
+    ) -> types::Result<PrereqGraph> {
+        let course = course.into();
+        PrereqGraph::build(
+            course.subject(),
+            course.course_num(),
+            depth,
+            |subj, crsc| async move { self.get_prerequisites((subj, crsc)).await },
+        )
+        .await
+    }
+
     /// Gets your current schedule.
     ///
     /// # Parameters
     /// - `schedule_name`: The schedule that you want to get. If `None` is given, this will default
-    /// to your main schedule.
+    ///   to your main schedule.
     ///
     /// # Returns
     /// Either a vector of sections that appear in your schedule, or an error message if something
@@ -391,11 +995,87 @@ impl<'a> WrapperTermRequest<'a> {
     /// # }
     /// ```
     pub async fn get_schedule(&self, schedule_name: Option<&str>) -> types::Result<Schedule> {
-        parse_schedule(process_get_text::<Vec<RawScheduledMeeting>>(
+        parse_schedule(process_get_text_observed::<RawScheduledMeeting>(
+            self.raw.info.schema_observer,
+            "get_schedule",
             self.raw.get_schedule(schedule_name).await?,
         )?)
     }
 
+    /// Gets your current schedule, skipping any section that couldn't be parsed instead of
+    /// failing entirely.
+    ///
+    /// # Parameters
+    /// - `schedule_name`: The schedule that you want to get. If `None` is given, this will default
+    ///   to your main schedule.
+    ///
+    /// # Returns
+    /// Either the sections that could be parsed, along with why each skipped section couldn't
+    /// be parsed, or an error if the schedule couldn't be retrieved.
+    pub async fn get_schedule_lenient(
+        &self,
+        schedule_name: Option<&str>,
+    ) -> types::Result<LenientParse<Schedule>> {
+        Ok(parse_schedule_lenient(process_get_text_observed::<
+            RawScheduledMeeting,
+        >(
+            self.raw.info.schema_observer,
+            "get_schedule_lenient",
+            self.raw.get_schedule(schedule_name).await?,
+        )?))
+    }
+
+    /// Compares two of your schedules, reporting the sections that are only in one of the
+    /// schedules, the sections in both schedules with a different grading option or number
+    /// of units, and statistics (e.g., total units and days on campus) for each schedule.
+    ///
+    /// # Parameters
+    /// - `schedule_a`: The first schedule. If `None` is given, this will default to your main
+    ///   schedule.
+    /// - `schedule_b`: The second schedule. If `None` is given, this will default to your main
+    ///   schedule.
+    ///
+    /// # Returns
+    /// The comparison, which can be rendered as text or JSON, or an error if either schedule
+    /// couldn't be retrieved.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::compare::render_text;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let comparison = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .compare_schedules(None, Some("What If"))
+    ///     .await;
+    ///
+    /// match comparison {
+    ///     Ok(c) => println!("{}", render_text(&c)),
+    ///     Err(e) => eprintln!("An error occurred! {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn compare_schedules(
+        &self,
+        schedule_a: Option<&str>,
+        schedule_b: Option<&str>,
+    ) -> types::Result<ScheduleComparison> {
+        let a = self.get_schedule(schedule_a).await?;
+        let b = self.get_schedule(schedule_b).await?;
+        Ok(compare_schedules(
+            schedule_a.unwrap_or(DEFAULT_SCHEDULE_NAME),
+            &a,
+            schedule_b.unwrap_or(DEFAULT_SCHEDULE_NAME),
+            &b,
+        ))
+    }
+
     /// Gets enrollment count for a particular course.
     ///
     /// Unlike the `get_course_info` function, this function only returns a vector of sections
@@ -404,123 +1084,419 @@ impl<'a> WrapperTermRequest<'a> {
     ///
     /// Additionally, this function only returns one of some number of possible instructors.
     ///
-    /// Also keep in mind that this implementation will not retrieve canceled sections.
+    /// Also keep in mind that this implementation will not retrieve canceled sections.
+    ///
+    /// If you want full course information, use `get_course_info`. If you only care about the
+    /// number of people enrolled in a section, this function is for you.
+    ///
+    /// # Parameters
+    /// - `course`: The course, either as a `CourseId` or as a subject code and course number
+    ///   pair. For example, if you wanted to check `MATH 100B`, you could put
+    ///   `("MATH", "100B")`.
+    ///
+    /// # Returns
+    /// Either a vector with all sections that match the given subject code & course code, or an
+    /// error if one occurred.
+    ///
+    /// # Example
+    /// Suppose we wanted to find all sections of COGS 108 for the sole purpose of seeing how
+    /// many people are enrolled.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let sec_count = wrapper
+    ///     .req("FA23").parsed()
+    ///     .get_enrollment_count(("COGS", "108"))
+    ///     .await;
+    ///
+    /// match sec_count {
+    ///     Ok(o) => o.iter().for_each(|sec| println!("{sec}")),
+    ///     Err(e) => eprintln!("An error occurred! {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_enrollment_count(
+        &self,
+        course: impl Into<CourseKey<'_>>,
+    ) -> types::Result<Courses> {
+        let course = course.into();
+        let course_dept_id = course.to_string();
+
+        parse_enrollment_count(
+            process_get_text_observed::<RawWebRegMeeting>(
+                self.raw.info.schema_observer,
+                "get_enrollment_count",
+                self.raw.get_course_info(course).await?,
+            )?,
+            course_dept_id,
+        )
+    }
+
+    /// Gets course information for a particular course.
+    ///
+    /// Note that WebReg provides this information in a way that makes it hard to use; in
+    /// particular, WebReg separates each lecture, discussion, final exam, etc. from each other.
+    /// This function attempts to figure out which lecture/discussion/final exam/etc. correspond
+    /// to which section.
+    ///
+    /// Additonally, this implementation will not retrieve canceled sections.
+    ///
+    /// # Parameters
+    /// - `course`: The course, either as a `CourseId` or as a subject code and course number
+    ///   pair. For example, if you wanted to check `MATH 100B`, you could put
+    ///   `("MATH", "100B")`.
+    ///
+    /// # Returns
+    /// A result containing either:
+    /// - A vector with all possible sections that match the given subject code & course code.
+    /// - Or the error that occurred.
+    ///
+    /// # Example
+    /// Let's suppose we wanted to find all sections of CSE 105. This is how we would do this.
+    /// Note that this will contain a lot of information.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let course_info = wrapper
+    ///     .req("FA23").parsed()
+    ///     .get_course_info(("CSE", "105"))
+    ///     .await;
+    ///
+    /// match course_info {
+    ///     Ok(o) => o.iter().for_each(|sec| println!("{sec}")),
+    ///     Err(e) => eprintln!("An error occurred! {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_course_info(
+        &self,
+        course: impl Into<CourseKey<'_>>,
+    ) -> types::Result<Courses> {
+        let course = course.into();
+        let course_dept_id = course.to_string();
+
+        parse_course_info(
+            process_get_text_observed::<RawWebRegMeeting>(
+                self.raw.info.schema_observer,
+                "get_course_info",
+                self.raw.get_course_info(course).await?,
+            )?,
+            course_dept_id,
+        )
+    }
+
+    /// Gets course information for a particular course, skipping any section that couldn't be
+    /// parsed instead of failing entirely.
+    ///
+    /// # Parameters
+    /// - `course`: The course, either as a `CourseId` or as a subject code and course number
+    ///   pair. For example, if you wanted to check `MATH 100B`, you could put
+    ///   `("MATH", "100B")`.
+    ///
+    /// # Returns
+    /// Either the sections that could be parsed, along with why each skipped section couldn't
+    /// be parsed, or an error if the course information couldn't be retrieved.
+    pub async fn get_course_info_lenient(
+        &self,
+        course: impl Into<CourseKey<'_>>,
+    ) -> types::Result<LenientParse<Courses>> {
+        let course = course.into();
+        let course_dept_id = course.to_string();
+
+        Ok(parse_course_info_lenient(
+            process_get_text_observed::<RawWebRegMeeting>(
+                self.raw.info.schema_observer,
+                "get_course_info_lenient",
+                self.raw.get_course_info(course).await?,
+            )?,
+            course_dept_id,
+        ))
+    }
+
+    /// Gets course information for many courses at once, making up to `concurrency` requests
+    /// at a time. A failure for one course doesn't affect the others.
+    ///
+    /// # Parameters
+    /// - `courses`: The courses, either as `CourseId`s or as subject code and course number
+    ///   pairs (e.g., `("CSE", "100")`).
+    /// - `concurrency`: The maximum number of requests to make at once. If this is `0`, the
+    ///   requests are made one at a time.
+    ///
+    /// # Returns
+    /// A map from each course (e.g., `CSE 100`) to its sections, or to the error that
+    /// occurred when getting them.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let courses = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .get_course_info_many([("CSE", "100"), ("MATH", "20C")], 4)
+    ///     .await;
+    ///
+    /// for (course, sections) in courses {
+    ///     match sections {
+    ///         Ok(o) => println!("{course}: {} sections", o.len()),
+    ///         Err(e) => eprintln!("{course}: {e}"),
+    ///     }
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_course_info_many<'b>(
+        &self,
+        courses: impl IntoIterator<Item = impl Into<CourseKey<'b>>>,
+        concurrency: usize,
+    ) -> BTreeMap<String, types::Result<Courses>> {
+        let courses = normalize_courses(courses);
+        let results = join_limited(
+            courses
+                .iter()
+                .map(|(subj, crsc)| self.get_course_info((subj.as_str(), crsc.as_str()))),
+            concurrency,
+        )
+        .await;
+
+        courses
+            .into_iter()
+            .map(|(subj, crsc)| format!("{subj} {crsc}"))
+            .zip(results)
+            .collect()
+    }
+
+    /// Gets the enrollment counts for many courses at once, making up to `concurrency`
+    /// requests at a time. A failure for one course doesn't affect the others.
+    ///
+    /// # Parameters
+    /// - `courses`: The courses, either as `CourseId`s or as subject code and course number
+    ///   pairs (e.g., `("CSE", "100")`).
+    /// - `concurrency`: The maximum number of requests to make at once. If this is `0`, the
+    ///   requests are made one at a time.
     ///
-    /// If you want full course information, use `get_course_info`. If you only care about the
-    /// number of people enrolled in a section, this function is for you.
+    /// # Returns
+    /// A map from each course (e.g., `CSE 100`) to the enrollment counts of its sections, or
+    /// to the error that occurred when getting them.
+    pub async fn get_enrollment_count_many<'b>(
+        &self,
+        courses: impl IntoIterator<Item = impl Into<CourseKey<'b>>>,
+        concurrency: usize,
+    ) -> BTreeMap<String, types::Result<Courses>> {
+        let courses = normalize_courses(courses);
+        let results = join_limited(
+            courses
+                .iter()
+                .map(|(subj, crsc)| self.get_enrollment_count((subj.as_str(), crsc.as_str()))),
+            concurrency,
+        )
+        .await;
+
+        courses
+            .into_iter()
+            .map(|(subj, crsc)| format!("{subj} {crsc}"))
+            .zip(results)
+            .collect()
+    }
+
+    /// Gets the sections with the specified section IDs. WebReg is first searched for the
+    /// courses that these sections belong to, all at once, and then the information for
+    /// each of these courses is retrieved (a few at a time). Like `get_section`, other
+    /// sections of these courses that couldn't be parsed are ignored.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_num`: The course number. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `section_ids`: The section IDs.
     ///
     /// # Returns
-    /// Either a vector with all sections that match the given subject code & course code, or an
-    /// error if one occurred.
+    /// A map from each section ID to its section, or to the error that occurred when getting
+    /// it (e.g., `SectionIdNotFound` if the section isn't offered this term). A failure for
+    /// one section doesn't affect the others. An error is only returned if the search fails.
     ///
     /// # Example
-    /// Suppose we wanted to find all sections of COGS 108 for the sole purpose of seeing how
-    /// many people are enrolled.
     /// ```rust,no_run
     /// use reqwest::Client;
+    /// use webweg::types::SectionId;
     /// use webweg::wrapper::WebRegWrapper;
     ///
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
     ///
-    /// let sec_count = wrapper
-    ///     .req("FA23").parsed()
-    ///     .get_enrollment_count("COGS", "108")
-    ///     .await;
+    /// let sections = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .get_sections_by_id(&[SectionId::new(79913), SectionId::new(185826)])
+    ///     .await
+    ///     .unwrap();
     ///
-    /// match sec_count {
-    ///     Ok(o) => o.iter().for_each(|sec| println!("{sec}")),
-    ///     Err(e) => eprintln!("An error occurred! {e}"),
+    /// for (section_id, section) in sections {
+    ///     match section {
+    ///         Ok(s) => println!("{} ({section_id}): {} seats", s.subj_course_id, s.available_seats),
+    ///         Err(e) => eprintln!("{section_id}: {e}"),
+    ///     }
     /// }
     /// # }
     /// ```
-    pub async fn get_enrollment_count(
+    pub async fn get_sections_by_id(
         &self,
-        subject_code: impl AsRef<str>,
-        course_num: impl AsRef<str>,
-    ) -> types::Result<Courses> {
-        let course_dept_id = format!(
-            "{} {}",
-            subject_code.as_ref().trim(),
-            course_num.as_ref().trim()
+        section_ids: &[SectionId],
+    ) -> types::Result<BTreeMap<SectionId, types::Result<CourseSection>>> {
+        if section_ids.is_empty() {
+            return Ok(BTreeMap::new());
+        }
+
+        let courses = normalize_courses(
+            self.search_courses(SearchType::ByMultipleSections(section_ids.to_vec()))
+                .await?
+                .into_iter()
+                .map(|item| (item.subj_code, item.course_code)),
+        );
+        let course_infos = join_limited(
+            courses
+                .iter()
+                .map(|(subj, crsc)| self.get_course_info_lenient((subj.as_str(), crsc.as_str()))),
+            BATCH_CONCURRENCY,
         )
-        .to_uppercase();
+        .await;
+
+        let mut sections = HashMap::new();
+        let mut errors = HashMap::new();
+        // Whether some sections might be missing because a course couldn't be retrieved, or
+        // because of a warning that doesn't say which section it's about.
+        let mut incomplete = false;
+        for course_info in course_infos {
+            let Ok(course_info) = course_info else {
+                incomplete = true;
+                continue;
+            };
 
-        parse_enrollment_count(
-            process_get_text::<Vec<RawWebRegMeeting>>(
-                self.raw.get_course_info(subject_code, course_num).await?,
-            )?,
-            course_dept_id,
+            sections.extend(course_info.parsed.into_iter().map(|s| (s.section_id, s)));
+            for warning in course_info.warnings {
+                match warning.section_id {
+                    Some(id) => {
+                        errors.entry(id).or_insert(warning.error);
+                    }
+                    None => incomplete = true,
+                }
+            }
+        }
+
+        let mut results = BTreeMap::new();
+        let mut unresolved = vec![];
+        for &id in section_ids {
+            if results.contains_key(&id) || unresolved.contains(&id) {
+                continue;
+            }
+
+            if let Some(section) = sections.remove(&id) {
+                results.insert(id, Ok(section));
+            } else if let Some(e) = errors.remove(&id) {
+                results.insert(id, Err(e));
+            } else if incomplete {
+                unresolved.push(id);
+            } else {
+                results.insert(
+                    id,
+                    Err(WrapperError::SectionIdNotFound(
+                        id,
+                        SectionIdNotFoundContext::Catalog,
+                    )),
+                );
+            }
+        }
+
+        // These sections might belong to a course that couldn't be retrieved, so each of them
+        // is looked up on its own to find out why it's missing.
+        let retried = join_limited(
+            unresolved.iter().map(|&id| self.get_section(id)),
+            BATCH_CONCURRENCY,
         )
+        .await;
+        results.extend(unresolved.into_iter().zip(retried));
+        Ok(results)
     }
 
-    /// Gets course information for a particular course.
-    ///
-    /// Note that WebReg provides this information in a way that makes it hard to use; in
-    /// particular, WebReg separates each lecture, discussion, final exam, etc. from each other.
-    /// This function attempts to figure out which lecture/discussion/final exam/etc. correspond
-    /// to which section.
-    ///
-    /// Additonally, this implementation will not retrieve canceled sections.
+    /// Gets a section by its section ID. Other sections of the same course that couldn't be
+    /// parsed are ignored.
     ///
     /// # Parameters
-    /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
-    /// - `course_num`: The course number. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    /// - `section_id`: The section ID (e.g., `079913`).
     ///
     /// # Returns
-    /// A result containing either:
-    /// - A vector with all possible sections that match the given subject code & course code.
-    /// - Or the error that occurred.
+    /// The section, `SectionIdNotFound` if the section isn't offered this term, or the parsing
+    /// error if the section (or, since WebReg doesn't say which section a bad meeting belongs
+    /// to, some section of the course) couldn't be parsed.
     ///
     /// # Example
-    /// Let's suppose we wanted to find all sections of CSE 105. This is how we would do this.
-    /// Note that this will contain a lot of information.
     /// ```rust,no_run
     /// use reqwest::Client;
+    /// use webweg::types::{SectionIdNotFoundContext, WrapperError};
     /// use webweg::wrapper::WebRegWrapper;
     ///
     /// # #[tokio::main(flavor = "current_thread")]
     /// # async fn main() {
     /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
     ///
-    /// let course_info = wrapper
-    ///     .req("FA23").parsed()
-    ///     .get_course_info("CSE", "105")
-    ///     .await;
-    ///
-    /// match course_info {
-    ///     Ok(o) => o.iter().for_each(|sec| println!("{sec}")),
+    /// match wrapper.req("FA23").parsed().get_section("079913").await {
+    ///     Ok(section) => println!("{section}"),
+    ///     Err(WrapperError::SectionIdNotFound(id, SectionIdNotFoundContext::Catalog)) => {
+    ///         eprintln!("{id} isn't offered this term.")
+    ///     }
     ///     Err(e) => eprintln!("An error occurred! {e}"),
     /// }
     /// # }
     /// ```
-    pub async fn get_course_info(
+    pub async fn get_section(
         &self,
-        subject_code: impl AsRef<str>,
-        course_num: impl AsRef<str>,
-    ) -> types::Result<Courses> {
-        let course_dept_id = format!(
-            "{} {}",
-            subject_code.as_ref().trim(),
-            course_num.as_ref().trim()
-        )
-        .to_uppercase();
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+    ) -> types::Result<CourseSection> {
+        let section_id = to_section_id(section_id)?;
+        let not_found =
+            || WrapperError::SectionIdNotFound(section_id, SectionIdNotFoundContext::Catalog);
+        let search_res = self
+            .search_courses(SearchType::BySection(section_id))
+            .await?;
+        let course = search_res.first().ok_or_else(not_found)?;
 
-        parse_course_info(
-            process_get_text::<Vec<RawWebRegMeeting>>(
-                self.raw.get_course_info(subject_code, course_num).await?,
-            )?,
-            course_dept_id,
-        )
+        // Other sections of the course that couldn't be parsed shouldn't stop us from getting
+        // this section.
+        let course_info = self
+            .get_course_info_lenient((&course.subj_code, &course.course_code))
+            .await?;
+        match course_info
+            .parsed
+            .into_iter()
+            .find(|sec| sec.section_id == section_id)
+        {
+            Some(section) => Ok(section),
+            // Prefer the warning about this section's own meetings, if there is one.
+            None => {
+                let mut warnings = course_info.warnings;
+                let idx = warnings
+                    .iter()
+                    .position(|w| w.section_id == Some(section_id))
+                    .unwrap_or(0);
+                if idx < warnings.len() {
+                    Err(warnings.swap_remove(idx).error)
+                } else {
+                    Err(not_found())
+                }
+            }
+        }
     }
 
     /// Gets a list of all departments that are offering courses for the given term.
@@ -528,12 +1504,14 @@ impl<'a> WrapperTermRequest<'a> {
     /// # Returns
     /// A vector of department codes.
     pub async fn get_department_codes(&self) -> types::Result<Vec<String>> {
-        Ok(
-            process_get_text::<Vec<RawDepartmentElement>>(self.raw.get_department_codes().await?)?
-                .into_iter()
-                .map(|x| x.dep_code.trim().to_string())
-                .collect::<Vec<_>>(),
-        )
+        Ok(process_get_text_observed::<RawDepartmentElement>(
+            self.raw.info.schema_observer,
+            "get_department_codes",
+            self.raw.get_department_codes().await?,
+        )?
+        .into_iter()
+        .map(|x| x.dep_code.trim().to_string())
+        .collect::<Vec<_>>())
     }
 
     /// Gets a list of all subjects that have at least one course offered for the given term.
@@ -541,12 +1519,110 @@ impl<'a> WrapperTermRequest<'a> {
     /// # Returns
     /// A vector of subject codes.
     pub async fn get_subject_codes(&self) -> types::Result<Vec<String>> {
-        Ok(
-            process_get_text::<Vec<RawSubjectElement>>(self.raw.get_subject_codes().await?)?
-                .into_iter()
-                .map(|x| x.subject_code.trim().to_string())
-                .collect::<Vec<_>>(),
-        )
+        Ok(process_get_text_observed::<RawSubjectElement>(
+            self.raw.info.schema_observer,
+            "get_subject_codes",
+            self.raw.get_subject_codes().await?,
+        )?
+        .into_iter()
+        .map(|x| x.subject_code.trim().to_string())
+        .collect::<Vec<_>>())
+    }
+
+    /// Gets a list of all departments that are offering courses for the given term, along
+    /// with their descriptions.
+    ///
+    /// # Returns
+    /// A vector of departments.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::types::Department;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let departments = wrapper.req("FA23").parsed().get_departments().await.unwrap();
+    /// if let Some(cse) = Department::find(&departments, "CSE") {
+    ///     println!("{}", cse.description);
+    /// }
+    /// # }
+    /// ```
+    pub async fn get_departments(&self) -> types::Result<Vec<Department>> {
+        Ok(parse_departments(process_get_text_observed::<
+            RawDepartmentElement,
+        >(
+            self.raw.info.schema_observer,
+            "get_departments",
+            self.raw.get_department_codes().await?,
+        )?))
+    }
+
+    /// Gets a list of all subjects that have at least one course offered for the given term,
+    /// along with their descriptions.
+    ///
+    /// # Returns
+    /// A vector of subjects.
+    pub async fn get_subjects(&self) -> types::Result<Vec<Subject>> {
+        Ok(parse_subjects(process_get_text_observed::<
+            RawSubjectElement,
+        >(
+            self.raw.info.schema_observer,
+            "get_subjects",
+            self.raw.get_subject_codes().await?,
+        )?))
+    }
+
+    /// Gets all subject codes of courses offered by the specified department for the given
+    /// term. WebReg doesn't directly say which department a subject belongs to, so this
+    /// searches for all courses in that department.
+    ///
+    /// # Parameters
+    /// - `department_code`: The department code. For example, `CSE`.
+    ///
+    /// # Returns
+    /// The subject codes, sorted and without duplicates.
+    pub async fn get_department_subjects(
+        &self,
+        department_code: impl Into<String>,
+    ) -> types::Result<Vec<String>> {
+        let mut subjects = self
+            .search_courses(SearchType::Advanced(
+                SearchRequestBuilder::new().add_department(department_code),
+            ))
+            .await?
+            .into_iter()
+            .map(|item| item.subj_code)
+            .collect::<Vec<_>>();
+
+        subjects.sort();
+        subjects.dedup();
+        Ok(subjects)
+    }
+
+    /// Gets a mapping from each subject code to the department code(s) that offer courses in
+    /// that subject for the given term. Note that this makes one request per department, so
+    /// you may want to cache the result.
+    ///
+    /// # Returns
+    /// A map where the key is the subject code and the value is the department codes.
+    pub async fn get_subject_department_map(&self) -> types::Result<BTreeMap<String, Vec<String>>> {
+        let mut map: BTreeMap<String, Vec<String>> = BTreeMap::new();
+        for department in self.get_departments().await? {
+            for subject in self
+                .get_department_subjects(department.code.as_str())
+                .await?
+            {
+                map.entry(subject)
+                    .or_default()
+                    .push(department.code.clone());
+            }
+        }
+
+        Ok(map)
     }
 
     /// Gets all courses that are available. All this does is searches for all courses via WebReg's
@@ -559,7 +1635,9 @@ impl<'a> WrapperTermRequest<'a> {
     /// A vector consisting of all courses that are available. Note that the data that is returned
     /// is directly from WebReg's API, so care will need to be taken to clean the resulting data.
     pub async fn search_courses(&self, filter_by: SearchType) -> types::Result<SearchResult> {
-        Ok(process_get_text::<Vec<RawWebRegSearchResultItem>>(
+        Ok(process_get_text_observed::<RawWebRegSearchResultItem>(
+            self.raw.info.schema_observer,
+            "search_courses",
             self.raw.search_courses(filter_by).await?,
         )?
         .into_iter()
@@ -584,8 +1662,11 @@ impl<'a> WrapperTermRequest<'a> {
         &self,
         subj: &[T],
     ) -> types::Result<HashMap<String, String>> {
-        let res =
-            process_get_text::<Vec<RawCourseTextItem>>(self.raw.get_course_notes(subj).await?)?;
+        let res = process_get_text_observed::<RawCourseTextItem>(
+            self.raw.info.schema_observer,
+            "get_course_notes",
+            self.raw.get_course_notes(subj).await?,
+        )?;
 
         let mut map = HashMap::new();
         // Keep in mind that, for whatever reason, some courses may have multiple entries of the
@@ -614,9 +1695,9 @@ impl<'a> WrapperTermRequest<'a> {
     ///
     /// # Parameters
     /// - `subject_code`: The subject code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `MATH`.
+    ///   would put `MATH`.
     /// - `course_code`: The course code. For example, if you wanted to check `MATH 100B`, you
-    /// would put `100B`.
+    ///   would put `100B`.
     ///
     /// # Returns
     /// A map, where the key is the section family (e.g., section `A`, which encompasses all sections
@@ -638,7 +1719,10 @@ impl<'a> WrapperTermRequest<'a> {
 
         // Begin by getting a list of all valid (section ID, section code) pairs.
         let section_id_code = serde_json::from_str::<Vec<RawWebRegMeeting>>(
-            &self.raw.get_course_info(subject_code, course_num).await?,
+            &self
+                .raw
+                .get_course_info((subject_code.as_ref(), course_num.as_ref()))
+                .await?,
         )?
         .into_iter()
         .filter(|d| d.display_type != "CA" && !d.section_id.is_empty() && !d.sect_code.is_empty())
@@ -659,9 +1743,7 @@ impl<'a> WrapperTermRequest<'a> {
                 &section_code[..1]
             };
 
-            let entry = parent_id_section_map
-                .entry(section_family)
-                .or_insert(HashSet::new());
+            let entry = parent_id_section_map.entry(section_family).or_default();
             entry.insert(section_id);
 
             // Because each section ID should be unique, we know that a section ID won't be
@@ -685,7 +1767,9 @@ impl<'a> WrapperTermRequest<'a> {
                 acc
             });
 
-        let res = process_get_text::<Vec<RawSectionTextItem>>(
+        let res = process_get_text_observed::<RawSectionTextItem>(
+            self.raw.info.schema_observer,
+            "get_section_notes_by_course",
             self.raw.get_section_notes(&all_ids).await?,
         )?;
 
@@ -743,7 +1827,9 @@ impl<'a> WrapperTermRequest<'a> {
     /// # }
     /// ```
     pub async fn get_events(&self) -> types::Result<Events> {
-        parse_get_events(process_get_text::<Vec<RawEvent>>(
+        parse_get_events(process_get_text_observed::<RawEvent>(
+            self.raw.info.schema_observer,
+            "get_events",
             self.raw.get_events().await?,
         )?)
     }
@@ -757,6 +1843,123 @@ impl<'a> WrapperTermRequest<'a> {
         process_get_text::<Vec<String>>(self.raw.get_schedule_list().await?)
     }
 
+    /// Calls each GET endpoint for this term and compares the responses against the keys
+    /// that this crate expects, so you can find out whether WebReg's API has changed.
+    ///
+    /// The course-specific endpoints (course information, prerequisites, and course and
+    /// section notes) are checked using the first course in your schedule or, if your
+    /// schedule is empty, the first course found under the first subject.
+    ///
+    /// # Returns
+    /// A summary of the drift found for each endpoint, along with any endpoints that
+    /// couldn't be checked.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    /// let report = wrapper.req("FA23").parsed().diagnose_schema().await;
+    /// if report.has_drift() {
+    ///     println!("{report}");
+    /// }
+    /// # }
+    /// ```
+    pub async fn diagnose_schema(&self) -> SchemaReport {
+        let mut report = SchemaReport::default();
+        let subjects = report
+            .check::<RawSubjectElement>("get_subject_codes", self.raw.get_subject_codes().await)
+            .unwrap_or_default();
+        report.check::<RawDepartmentElement>(
+            "get_department_codes",
+            self.raw.get_department_codes().await,
+        );
+        let schedule = report
+            .check::<RawScheduledMeeting>("get_schedule", self.raw.get_schedule(None).await)
+            .unwrap_or_default();
+        report.check::<RawEvent>("get_events", self.raw.get_events().await);
+
+        let subject = schedule
+            .first()
+            .map(|m| m.subj_code.trim().to_string())
+            .or_else(|| subjects.first().map(|s| s.subject_code.trim().to_string()));
+        let Some(subject) = subject else {
+            for endpoint in [
+                "search_courses",
+                "get_course_info",
+                "get_prerequisites",
+                "get_course_notes",
+                "get_section_notes",
+            ] {
+                report.errors.push((
+                    endpoint,
+                    WrapperError::InputError("subject", "no subject was found to check with"),
+                ));
+            }
+
+            return report;
+        };
+
+        let search_results = report
+            .check::<RawWebRegSearchResultItem>(
+                "search_courses",
+                self.raw
+                    .search_courses(SearchType::Advanced(
+                        SearchRequestBuilder::new().add_subject(subject.as_str()),
+                    ))
+                    .await,
+            )
+            .unwrap_or_default();
+        report.check::<RawCourseTextItem>(
+            "get_course_notes",
+            self.raw.get_course_notes(&[subject.as_str()]).await,
+        );
+
+        let course = match schedule.first() {
+            Some(m) => Some((m.subj_code.trim(), m.course_code.trim())),
+            None => search_results
+                .first()
+                .map(|r| (r.subj_code.trim(), r.course_code.trim())),
+        };
+        let Some((subj_code, course_code)) = course else {
+            for endpoint in ["get_course_info", "get_prerequisites", "get_section_notes"] {
+                report.errors.push((
+                    endpoint,
+                    WrapperError::InputError("course", "no course was found to check with"),
+                ));
+            }
+
+            return report;
+        };
+
+        let meetings = report
+            .check::<RawWebRegMeeting>(
+                "get_course_info",
+                self.raw.get_course_info((subj_code, course_code)).await,
+            )
+            .unwrap_or_default();
+        report.check::<RawPrerequisite>(
+            "get_prerequisites",
+            self.raw.get_prerequisites((subj_code, course_code)).await,
+        );
+
+        let mut section_ids = meetings
+            .iter()
+            .map(|m| m.section_id.trim())
+            .collect::<Vec<_>>();
+        section_ids.sort_unstable();
+        section_ids.dedup();
+        report.check::<RawSectionTextItem>(
+            "get_section_notes",
+            self.raw.get_section_notes(&section_ids).await,
+        );
+
+        report
+    }
+
     /// Sends an email to yourself using the same email that is used to confirm that you have
     /// enrolled or waitlisted in a particular class. In other words, this will send an email
     /// to you through the email `NoReplyRegistrar@ucsd.edu`.
@@ -792,25 +1995,15 @@ impl<'a> WrapperTermRequest<'a> {
     /// # }
     /// ```
     pub async fn send_email_to_self(&self, email_content: &str) -> types::Result<()> {
-        let r = self
-            .raw
-            .info
-            .req(ReqType::Post(SEND_EMAIL))
-            .form(&[("actionevent", email_content), ("termcode", self.raw.term)])
-            .send()
-            .await?;
-
-        let status = r.status();
-        if !status.is_success() {
-            let text = r.text().await.ok();
-            return Err(WrapperError::BadStatusCode(status.as_u16(), text));
+        let r = self.raw.send_email_to_self(email_content).await?;
+        if !r.is_success() {
+            return Err(WrapperError::BadStatusCode(r.status, Some(r.body)));
         }
 
-        let t = r.text().await?;
-        if t.contains("\"YES\"") {
+        if r.body.contains("\"YES\"") {
             Ok(())
         } else {
-            Err(WrapperError::WebRegError(t))
+            Err(WrapperError::WebRegError(r.body))
         }
     }
 
@@ -818,9 +2011,9 @@ impl<'a> WrapperTermRequest<'a> {
     ///
     /// # Parameters
     /// - `section_id`: The section ID corresponding to the class that you want to change
-    /// the grading option for.
+    ///   the grading option for.
     /// - `new_grade_opt`: The new grading option. This must either be `L` (letter),
-    /// `P` (pass/no pass), or `S` (satisfactory/unsatisfactory), and is enforced via an enum.
+    ///   `P` (pass/no pass), or `S` (satisfactory/unsatisfactory), and is enforced via an enum.
     ///
     /// # Returns
     /// `true` if the process succeeded, or a string containing the error message from WebReg if
@@ -851,77 +2044,32 @@ impl<'a> WrapperTermRequest<'a> {
     /// ```
     pub async fn change_grading_option(
         &self,
-        section_id: &str,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
         new_grade_opt: GradeOption,
     ) -> types::Result<bool> {
-        let new_grade_opt = match new_grade_opt {
-            GradeOption::L => "L",
-            GradeOption::S => "S",
-            GradeOption::P => "P",
-        };
-
-        // "Slice" any zeros off of the left-most side of the string. We need to do this
-        // because, when comparing section IDs in the schedule, WebReg gives us the
-        // section IDs as integers; however, for the rest of the API, it's given as a
-        // string.
-        //
-        // Essentially, this means that, while most of WebReg's API will take `"079911"` as
-        // an input and as an output (e.g. see `get_course_info`), the schedule API will
-        // specifically return an integer `79911`. The `get_schedule` function will simply
-        // convert this integer to a string, e.g. `79911` -> `"79911"` and return that along
-        // with the other parsed info for each scheduled section.
-        //
-        // So, we need to slice off any 0s from the input parameter `section_id` to account
-        // for this.
-        let mut left_idx = 0;
-        for c in section_id.chars() {
-            if c != '0' {
-                break;
-            }
-
-            left_idx += 1;
-            continue;
-        }
-
+        let section_id = to_section_id(section_id)?;
         let poss_class = self
             .get_schedule(None as Option<&str>)
             .await?
             .into_iter()
-            .find(|x| x.section_id == section_id[left_idx..]);
+            .find(|x| x.section_id == section_id);
 
         // don't care about previous poss_class
         let poss_class = match poss_class {
             Some(s) => s,
             None => {
                 return Err(WrapperError::SectionIdNotFound(
-                    section_id.into(),
+                    section_id,
                     SectionIdNotFoundContext::Schedule,
                 ))
             }
         };
 
-        let sec_id = poss_class.section_id.to_string();
-        let units = poss_class.units.to_string();
-
-        process_post_response(
+        process_raw_post_response(
             self.raw
-                .info
-                .req(ReqType::Post(CHANGE_ENROLL))
-                .form(&[
-                    ("section", sec_id.as_str()),
-                    ("subjCode", ""),
-                    ("crseCode", ""),
-                    ("unit", units.as_str()),
-                    ("grade", new_grade_opt),
-                    // You don't actually need these
-                    ("oldGrade", ""),
-                    ("oldUnit", ""),
-                    ("termcode", self.raw.term),
-                ])
-                .send()
-                .await,
+                .change_grading_option(poss_class.section_id, poss_class.units, new_grade_opt)
+                .await?,
         )
-        .await
     }
 
     /// Validates that adding a course to your plan will cause no issue.
@@ -968,21 +2116,7 @@ impl<'a> WrapperTermRequest<'a> {
     /// # }
     /// ```
     pub async fn validate_add_to_plan(&self, plan_options: &PlanAdd<'_>) -> types::Result<bool> {
-        let crsc_code = util::get_formatted_course_num(plan_options.course_code.as_ref());
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(PLAN_EDIT))
-                .form(&[
-                    ("section", plan_options.section_id.as_ref()),
-                    ("subjcode", plan_options.subject_code.as_ref()),
-                    ("crsecode", crsc_code.as_str()),
-                    ("termcode", self.raw.term),
-                ])
-                .send()
-                .await,
-        )
-        .await
+        process_raw_post_response(self.raw.validate_add_to_plan(plan_options).await?)
     }
 
     /// Allows you to plan a course.
@@ -1040,64 +2174,156 @@ impl<'a> WrapperTermRequest<'a> {
         &self,
         plan_options: PlanAdd<'_>,
         validate: bool,
-    ) -> types::Result<bool> {
-        let u = plan_options.unit_count.to_string();
-        let crsc_code = util::get_formatted_course_num(plan_options.course_code.as_ref());
+    ) -> types::Result<bool> {
+        if validate {
+            // We need to call the edit endpoint first, or else we'll have issues where we don't
+            // actually enroll in every component of the course.
+            // Also, this can potentially return "false" due to you not being able to enroll in the
+            // class, e.g. the class you're trying to plan is a major-restricted class.
+            self.validate_add_to_plan(&plan_options)
+                .await
+                .unwrap_or(false);
+        }
+
+        process_raw_post_response(self.raw.add_to_plan(&plan_options).await?)
+    }
+
+    /// Allows you to unplan a course.
+    ///
+    /// # Parameters
+    /// - `section_id`: The section ID.
+    /// - `schedule_name`: The schedule name where the course should be unplanned from.
+    ///
+    /// # Returns
+    /// `true` if the process succeeded, or a string containing the error message from WebReg if
+    /// something wrong happened.
+    ///
+    /// # Example
+    /// Here, we will remove the planned course with section ID `123456` from our default schedule.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let unplan_res = wrapper
+    ///     .req("FA23").parsed()
+    ///     .remove_from_plan("123456", None)
+    ///     .await;
+    ///
+    /// match unplan_res {
+    ///     Ok(res) => println!("Unplanned? {res}"),
+    ///     Err(e) => eprintln!("Unable to remove from plan: {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn remove_from_plan(
+        &self,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+        schedule_name: Option<&str>,
+    ) -> types::Result<bool> {
+        process_raw_post_response(self.raw.remove_from_plan(section_id, schedule_name).await?)
+    }
+
+    /// Plans multiple courses. Each course is planned one after another, and this function
+    /// will not stop if a course couldn't be planned.
+    ///
+    /// # Parameters
+    /// - `plans`: Information for the courses that you want to plan.
+    /// - `validate`: Whether to validate your planning of each course beforehand. See
+    ///   `add_to_plan` for why you probably want this to be `true`.
+    ///
+    /// # Returns
+    /// A report containing the result of planning each course, in the order given.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::input_types::PlanAdd;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let plans = [("079911", "A01"), ("079912", "A02")].map(|(id, code)| {
+    ///     PlanAdd::builder()
+    ///         .with_subject_code("CSE")
+    ///         .with_course_code("100")
+    ///         .with_section_id(id)
+    ///         .with_section_code(code)
+    ///         .with_unit_count(4)
+    ///         .try_build()
+    ///         .unwrap()
+    /// });
+    ///
+    /// let report = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .add_many_to_plan(plans, true)
+    ///     .await;
+    ///
+    /// for item in report.failed() {
+    ///     eprintln!("Unable to plan {}: {:?}", item.section_id, item.result);
+    /// }
+    /// # }
+    /// ```
+    pub async fn add_many_to_plan<'b>(
+        &self,
+        plans: impl IntoIterator<Item = PlanAdd<'b>>,
+        validate: bool,
+    ) -> PlanReport {
+        let mut report = PlanReport::default();
+        for plan in plans {
+            let section_id = plan.section_id;
+            report.items.push(PlanItemResult {
+                section_id,
+                result: self.add_to_plan(plan, validate).await,
+            });
+        }
 
-        if validate {
-            // We need to call the edit endpoint first, or else we'll have issues where we don't
-            // actually enroll in every component of the course.
-            // Also, this can potentially return "false" due to you not being able to enroll in the
-            // class, e.g. the class you're trying to plan is a major-restricted class.
-            self.validate_add_to_plan(&plan_options)
-                .await
-                .unwrap_or(false);
+        report
+    }
+
+    /// Unplans multiple courses. Each course is unplanned one after another, and this
+    /// function will not stop if a course couldn't be unplanned.
+    ///
+    /// # Parameters
+    /// - `section_ids`: The section IDs of the courses to unplan.
+    /// - `schedule_name`: The schedule name where the courses should be unplanned from.
+    ///
+    /// # Returns
+    /// A report containing the result of unplanning each course, in the order given.
+    pub async fn remove_many_from_plan(
+        &self,
+        section_ids: impl IntoIterator<Item = SectionId>,
+        schedule_name: Option<&str>,
+    ) -> PlanReport {
+        let mut report = PlanReport::default();
+        for section_id in section_ids {
+            report.items.push(PlanItemResult {
+                section_id,
+                result: self.remove_from_plan(section_id, schedule_name).await,
+            });
         }
 
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(PLAN_ADD))
-                .form(&[
-                    ("subjcode", plan_options.subject_code.as_ref()),
-                    ("crsecode", crsc_code.as_str()),
-                    ("sectnum", plan_options.section_id.as_ref()),
-                    ("sectcode", plan_options.section_code.as_ref()),
-                    ("unit", u.as_str()),
-                    (
-                        "grade",
-                        plan_options
-                            .grading_option
-                            .unwrap_or(GradeOption::L)
-                            .as_str(),
-                    ),
-                    ("termcode", self.raw.term),
-                    (
-                        "schedname",
-                        match plan_options.schedule_name {
-                            Some(ref r) => r.as_ref(),
-                            None => DEFAULT_SCHEDULE_NAME,
-                        },
-                    ),
-                ])
-                .send()
-                .await,
-        )
-        .await
+        report
     }
 
-    /// Allows you to unplan a course.
+    /// Unplans every planned course in a schedule. Sections that you are enrolled or
+    /// waitlisted in are not affected.
     ///
     /// # Parameters
-    /// - `section_id`: The section ID.
-    /// - `schedule_name`: The schedule name where the course should be unplanned from.
+    /// - `schedule_name`: The schedule to clear. If `None` is given, this will default to your
+    ///   main schedule.
     ///
     /// # Returns
-    /// `true` if the process succeeded, or a string containing the error message from WebReg if
-    /// something wrong happened.
+    /// A report containing the result of unplanning each course, or an error if the schedule
+    /// couldn't be retrieved.
     ///
     /// # Example
-    /// Here, we will remove the planned course with section ID `123456` from our default schedule.
     /// ```rust,no_run
     /// use reqwest::Client;
     /// use webweg::wrapper::WebRegWrapper;
@@ -1106,45 +2332,36 @@ impl<'a> WrapperTermRequest<'a> {
     /// # async fn main() {
     /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
     ///
-    /// let unplan_res = wrapper
-    ///     .req("FA23").parsed()
-    ///     .remove_from_plan("123456", None)
-    ///     .await;
+    /// let report = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .clear_schedule(Some("What If"))
+    ///     .await
+    ///     .expect("An error occurred.");
     ///
-    /// match unplan_res {
-    ///     Ok(res) => println!("Unplanned? {res}"),
-    ///     Err(e) => eprintln!("Unable to remove from plan: {e}"),
-    /// }
+    /// println!("Cleared? {}", report.is_success());
     /// # }
     /// ```
-    pub async fn remove_from_plan(
-        &self,
-        section_id: impl AsRef<str>,
-        schedule_name: Option<&str>,
-    ) -> types::Result<bool> {
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(PLAN_REMOVE))
-                .form(&[
-                    ("sectnum", section_id.as_ref()),
-                    ("termcode", self.raw.term),
-                    ("schedname", schedule_name.unwrap_or(DEFAULT_SCHEDULE_NAME)),
-                ])
-                .send()
-                .await,
-        )
-        .await
+    pub async fn clear_schedule(&self, schedule_name: Option<&str>) -> types::Result<PlanReport> {
+        let section_ids = self
+            .get_schedule(schedule_name)
+            .await?
+            .into_iter()
+            .filter(|s| s.enrolled_status == EnrollmentStatus::Planned)
+            .map(|s| s.section_id)
+            .collect::<Vec<_>>();
+
+        Ok(self.remove_many_from_plan(section_ids, schedule_name).await)
     }
 
     /// Validates that the section that you are trying to enroll in is valid.
     ///
     /// # Parameters
     /// - `add_type`: The add type. As a warning, specifying `DecideForMe` will incur extra
-    /// requests (searching by section ID, then searching for course).
+    ///   requests (searching by section ID, then searching for course).
     /// - `enroll_options`: The enrollment options. Note that the section ID is the only thing
-    /// that matters here. A reference, thus, is expected since you will probably be reusing
-    /// the structure when calling the `add_section` function.
+    ///   that matters here. A reference, thus, is expected since you will probably be reusing
+    ///   the structure when calling the `add_section` function.
     ///
     /// # Returns
     /// `true` if the process succeeded, or a string containing the error message from WebReg if
@@ -1182,36 +2399,19 @@ impl<'a> WrapperTermRequest<'a> {
     pub async fn validate_add_section(
         &self,
         add_type: AddType,
-        enroll_options: &EnrollWaitAdd<'_>,
+        enroll_options: &EnrollWaitAdd,
     ) -> types::Result<bool> {
-        let base_edit_url = match add_type {
-            AddType::Enroll => ENROLL_EDIT,
-            AddType::Waitlist => WAITLIST_EDIT,
-            AddType::DecideForMe => match self
-                .get_add_type(enroll_options.section_id.as_ref())
-                .await?
-            {
-                ExplicitAddType::Enroll => ENROLL_EDIT,
-                ExplicitAddType::Waitlist => WAITLIST_EDIT,
-            },
+        let add_type = match add_type {
+            AddType::Enroll => ExplicitAddType::Enroll,
+            AddType::Waitlist => ExplicitAddType::Waitlist,
+            AddType::DecideForMe => self.get_add_type(enroll_options.section_id).await?,
         };
 
-        process_post_response(
+        process_raw_post_response(
             self.raw
-                .info
-                .req(ReqType::Post(base_edit_url))
-                .form(&[
-                    // These are required
-                    ("section", enroll_options.section_id.as_ref()),
-                    ("termcode", self.raw.term),
-                    // These are optional.
-                    ("subjcode", ""),
-                    ("crsecode", ""),
-                ])
-                .send()
-                .await,
+                .validate_add_section(add_type, enroll_options)
+                .await?,
         )
-        .await
     }
 
     /// Checks whether the user can enroll or waitlist into a section.
@@ -1225,38 +2425,14 @@ impl<'a> WrapperTermRequest<'a> {
     /// # Returns
     /// An enum value that can either be `Enroll` or `Waitlist` depending on whether
     /// the user can enroll into the specified section.
-    pub async fn get_add_type(&self, section_id: &str) -> types::Result<ExplicitAddType> {
-        let search_res = self
-            .search_courses(SearchType::BySection(section_id.to_string()))
-            .await?;
-
-        if search_res.is_empty() {
-            return Err(WrapperError::SectionIdNotFound(
-                section_id.into(),
-                SectionIdNotFoundContext::Catalog,
-            ));
-        }
-
-        let subject_code = search_res[0].subj_code.trim();
-        let course_code = search_res[0].course_code.trim();
-
-        let section_info = self
-            .get_enrollment_count(subject_code, course_code)
-            .await?
-            .into_iter()
-            .find(|sec| sec.section_id == section_id);
-        if let Some(info) = section_info {
-            if info.has_seats() {
-                Ok(ExplicitAddType::Enroll)
-            } else {
-                Ok(ExplicitAddType::Waitlist)
-            }
+    pub async fn get_add_type(
+        &self,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
+    ) -> types::Result<ExplicitAddType> {
+        if self.get_section(section_id).await?.has_seats() {
+            Ok(ExplicitAddType::Enroll)
         } else {
-            // In theory, this should never hit.
-            Err(WrapperError::SectionIdNotFound(
-                section_id.into(),
-                SectionIdNotFoundContext::Catalog,
-            ))
+            Ok(ExplicitAddType::Waitlist)
         }
     }
 
@@ -1264,12 +2440,12 @@ impl<'a> WrapperTermRequest<'a> {
     ///
     /// # Parameters
     /// - `add_type`: The add type (either `Enroll`, `Waitlist`, for `DecideForMe`). As a warning,
-    /// `DecideForMe` will incur extra requests.
+    ///   `DecideForMe` will incur extra requests.
     /// - `enroll_options`: Information for the course that you want to enroll in.
     /// - `validate`: Whether to validate your enrollment of this course beforehand. Note that
-    /// validation is required, so this should be `true`. This should only be `false` if you
-    /// called `validate_add_section` before. If you attempt to call `add_section` without
-    /// validation, then you will get an error.
+    ///   validation is required, so this should be `true`. This should only be `false` if you
+    ///   called `validate_add_section` before. If you attempt to call `add_section` without
+    ///   validation, then you will get an error.
     ///
     /// # Returns
     /// `true` if the process succeeded, or a string containing the error message from WebReg if
@@ -1308,74 +2484,38 @@ impl<'a> WrapperTermRequest<'a> {
     pub async fn add_section(
         &self,
         add_type: AddType,
-        enroll_options: EnrollWaitAdd<'_>,
+        enroll_options: EnrollWaitAdd,
         validate: bool,
     ) -> types::Result<bool> {
-        let base_reg_url = match add_type {
-            AddType::Enroll => ENROLL_ADD,
-            AddType::Waitlist => WAITLIST_ADD,
-            AddType::DecideForMe => match self
-                .get_add_type(enroll_options.section_id.as_ref())
-                .await?
-            {
-                ExplicitAddType::Enroll => ENROLL_ADD,
-                ExplicitAddType::Waitlist => WAITLIST_ADD,
-            },
-        };
-        let u = match enroll_options.unit_count {
-            Some(r) => r.to_string(),
-            None => "".to_string(),
+        let add_type = match add_type {
+            AddType::Enroll => ExplicitAddType::Enroll,
+            AddType::Waitlist => ExplicitAddType::Waitlist,
+            AddType::DecideForMe => self.get_add_type(enroll_options.section_id).await?,
         };
 
         if validate {
-            self.validate_add_section(add_type, &enroll_options).await?;
+            process_raw_post_response(
+                self.raw
+                    .validate_add_section(add_type, &enroll_options)
+                    .await?,
+            )?;
         }
 
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(base_reg_url))
-                .form(&[
-                    // These are required
-                    ("section", enroll_options.section_id.as_ref()),
-                    ("termcode", self.raw.term),
-                    // These are optional.
-                    ("unit", u.as_str()),
-                    (
-                        "grade",
-                        enroll_options
-                            .grading_option
-                            .unwrap_or(GradeOption::L)
-                            .as_str(),
-                    ),
-                    ("crsecode", ""),
-                    ("subjcode", ""),
-                ])
-                .send()
-                .await,
-        )
-        .await?;
+        process_raw_post_response(self.raw.add_section(add_type, &enroll_options).await?)?;
 
         // This will always return true
-        process_post_response(
+        process_raw_post_response(
             self.raw
-                .info
-                .req(ReqType::Post(PLAN_REMOVE_ALL))
-                .form(&[
-                    ("sectnum", enroll_options.section_id.as_ref()),
-                    ("termcode", self.raw.term),
-                ])
-                .send()
-                .await,
+                .remove_from_all_plans(enroll_options.section_id)
+                .await?,
         )
-        .await
     }
 
     /// Drops a section.
     ///
     /// # Parameters
     /// - `prev_enroll_status`: Your enrollment status (either `Enroll` or `Waitlist` if you
-    /// are enrolled or waitlisted in the section, respectively).
+    ///   are enrolled or waitlisted in the section, respectively).
     /// - `section_id`: The section ID corresponding to the section that you want to drop.
     ///
     /// # Returns
@@ -1412,29 +2552,13 @@ impl<'a> WrapperTermRequest<'a> {
     pub async fn drop_section(
         &self,
         prev_enroll_status: ExplicitAddType,
-        section_id: impl AsRef<str>,
+        section_id: impl TryInto<SectionId, Error: Into<WrapperError>>,
     ) -> types::Result<bool> {
-        let base_reg_url = match prev_enroll_status {
-            ExplicitAddType::Enroll => ENROLL_DROP,
-            ExplicitAddType::Waitlist => WAITLIST_DROP,
-        };
-
-        process_post_response(
+        process_raw_post_response(
             self.raw
-                .info
-                .req(ReqType::Post(base_reg_url))
-                .form(&[
-                    // These parameters are optional
-                    ("subjcode", ""),
-                    ("crsecode", ""),
-                    // But these are required
-                    ("section", section_id.as_ref()),
-                    ("termcode", self.raw.term),
-                ])
-                .send()
-                .await,
+                .drop_section(prev_enroll_status, section_id)
+                .await?,
         )
-        .await
     }
 
     /// Renames a schedule to the specified name. You cannot rename the default
@@ -1492,19 +2616,7 @@ impl<'a> WrapperTermRequest<'a> {
             ));
         }
 
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(RENAME_SCHEDULE))
-                .form(&[
-                    ("termcode", self.raw.term),
-                    ("oldschedname", old_name.as_ref()),
-                    ("newschedname", new_name.as_ref()),
-                ])
-                .send()
-                .await,
-        )
-        .await
+        process_raw_post_response(self.raw.rename_schedule(old_name, new_name).await?)
     }
 
     /// Removes a schedule. You cannot delete the default `My Schedule` one.
@@ -1552,18 +2664,79 @@ impl<'a> WrapperTermRequest<'a> {
             ));
         }
 
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(REMOVE_SCHEDULE))
-                .form(&[
-                    ("termcode", self.raw.term),
-                    ("schedname", schedule_name.as_ref()),
-                ])
-                .send()
-                .await,
-        )
-        .await
+        process_raw_post_response(self.raw.remove_schedule(schedule_name).await?)
+    }
+
+    /// Copies a schedule into another schedule, by planning every planned section in the
+    /// original schedule into the new schedule with the same section, number of units, and
+    /// grading option. If the new schedule doesn't exist, WebReg will create it.
+    ///
+    /// Sections that you are enrolled or waitlisted in are not copied, since WebReg shows
+    /// those sections in all of your schedules anyways.
+    ///
+    /// This function will not stop if a section couldn't be planned; instead, the result of
+    /// planning each section is given in the returned report.
+    ///
+    /// # Parameter
+    /// - `from`: The name of the schedule to copy.
+    /// - `to`: The name of the schedule to copy into.
+    ///
+    /// # Returns
+    /// A report containing the result of planning each section, or an error if the original
+    /// schedule couldn't be retrieved.
+    ///
+    /// # Example
+    /// Copy the schedule "`Test Schedule`" into "`What If`."
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let report = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .copy_schedule("Test Schedule", "What If")
+    ///     .await
+    ///     .expect("An error occurred.");
+    ///
+    /// for item in report.failed() {
+    ///     eprintln!("Unable to copy {}: {:?}", item.section_id, item.result);
+    /// }
+    /// # }
+    /// ```
+    pub async fn copy_schedule(
+        &self,
+        from: impl AsRef<str>,
+        to: impl AsRef<str>,
+    ) -> types::Result<PlanReport> {
+        if from.as_ref() == to.as_ref() {
+            return Err(WrapperError::InputError(
+                "to",
+                "You cannot copy a schedule into itself.",
+            ));
+        }
+
+        let schedule = self.get_schedule(Some(from.as_ref())).await?;
+        let mut report = PlanReport::default();
+        for section in schedule
+            .iter()
+            .filter(|s| s.enrolled_status == EnrollmentStatus::Planned)
+        {
+            let result = match PlanAdd::from_scheduled_section(section, to.as_ref()) {
+                Ok(plan) => self.add_to_plan(plan, true).await,
+                Err(e) => Err(e),
+            };
+
+            report.items.push(PlanItemResult {
+                section_id: section.section_id,
+                result,
+            });
+        }
+
+        Ok(report)
     }
 
     /// Adds an event to your WebReg calendar, or edits an existing event.
@@ -1574,8 +2747,8 @@ impl<'a> WrapperTermRequest<'a> {
     /// # Parameter
     /// - `event_info`: The details of the event.
     /// - `event_timestamp`: The timestamp corresponding to the event that you want to
-    /// edit. If this is `None`, then this function will add the event. If this is `Some`,
-    /// then this function will edit an existing event.
+    ///   edit. If this is `None`, then this function will add the event. If this is `Some`,
+    ///   then this function will edit an existing event.
     ///
     /// # Returns
     /// `true` if the process succeeded, or a string containing the error message from WebReg if
@@ -1639,105 +2812,19 @@ impl<'a> WrapperTermRequest<'a> {
         event_info: EventAdd<'_>,
         event_timestamp: impl Into<Option<&str>>,
     ) -> types::Result<bool> {
-        let start_time_full = event_info.start_hr * 100 + event_info.start_min;
-        let end_time_full = event_info.end_hr * 100 + event_info.end_min;
-        if start_time_full >= end_time_full {
-            return Err(WrapperError::InputError(
-                "time",
-                "Start time must be less than end time.",
-            ));
-        }
-
-        if event_info.start_hr < 7 || event_info.start_hr > 12 + 10 {
-            return Err(WrapperError::InputError(
-                "event_info.start_hr",
-                "Start hour must be between 7 and 22 (7am and 10pm)",
-            ));
-        }
-
-        if event_info.start_hr == 12 + 10 && event_info.start_min != 0 {
-            return Err(WrapperError::InputError(
-                "event_info.start",
-                "You cannot exceed 10pm.",
-            ));
-        }
-
-        if event_info.event_days.is_empty() {
-            return Err(WrapperError::InputError(
-                "event_info.event_days",
-                "Must specify one day.",
-            ));
-        }
-
-        let mut days: [bool; 7] = [false; 7];
-        for d in event_info.event_days {
-            let idx = match d {
-                DayOfWeek::Monday => 0,
-                DayOfWeek::Tuesday => 1,
-                DayOfWeek::Wednesday => 2,
-                DayOfWeek::Thursday => 3,
-                DayOfWeek::Friday => 4,
-                DayOfWeek::Saturday => 5,
-                DayOfWeek::Sunday => 6,
-            };
-
-            days[idx] = true;
-        }
-
-        let mut day_str = String::new();
-        for d in days {
-            day_str.push(if d { '1' } else { '0' });
-        }
-
-        let mut start_time_full = start_time_full.to_string();
-        let mut end_time_full = end_time_full.to_string();
-        while start_time_full.len() < 4 {
-            start_time_full.insert(0, '0');
-        }
-
-        while end_time_full.len() < 4 {
-            end_time_full.insert(0, '0');
-        }
-
-        let mut form_data = HashMap::from([
-            ("termcode", self.raw.term),
-            ("aename", event_info.event_name.as_ref()),
-            ("aestarttime", start_time_full.as_str()),
-            ("aeendtime", end_time_full.as_str()),
-            (
-                "aelocation",
-                match event_info.location {
-                    None => "",
-                    Some(ref s) => s.as_ref(),
-                },
-            ),
-            ("aedays", day_str.as_str()),
-        ]);
-
-        let et = event_timestamp.into();
-        if let Some(timestamp) = et {
-            form_data.insert("aetimestamp", timestamp);
-        }
-
-        process_post_response(
+        event_info.validate()?;
+        process_raw_post_response(
             self.raw
-                .info
-                .req(ReqType::Post(match et {
-                    Some(_) => EVENT_EDIT,
-                    None => EVENT_ADD,
-                }))
-                .form(&form_data)
-                .send()
-                .await,
+                .add_or_edit_event(&event_info, event_timestamp.into())
+                .await?,
         )
-        .await
     }
 
     /// Removes an event from your WebReg calendar.
     ///
     /// # Parameter
     /// - `event_timestamp`: The timestamp corresponding to the event that you want to
-    /// remove.
+    ///   remove.
     ///
     /// # Returns
     /// `true` if the process succeeded, or a string containing the error message from WebReg if
@@ -1764,18 +2851,127 @@ impl<'a> WrapperTermRequest<'a> {
     /// # }
     /// ```
     pub async fn remove_event(&self, event_timestamp: impl AsRef<str>) -> types::Result<bool> {
-        process_post_response(
-            self.raw
-                .info
-                .req(ReqType::Post(EVENT_REMOVE))
-                .form(&[
-                    ("aetimestamp", event_timestamp.as_ref()),
-                    ("termcode", self.raw.term),
-                ])
-                .send()
-                .await,
-        )
-        .await
+        process_raw_post_response(self.raw.remove_event(event_timestamp).await?)
+    }
+
+    /// Edits an existing event on your WebReg calendar. The event is converted to an
+    /// `EventAdd`, which is then given to `edit` so it can be modified.
+    ///
+    /// Keep in mind that WebReg effectively just removes the old event and then adds the
+    /// edited event, so the timestamp will change.
+    ///
+    /// # Parameter
+    /// - `event_timestamp`: The timestamp corresponding to the event that you want to edit.
+    /// - `edit`: A function that modifies the event.
+    ///
+    /// # Returns
+    /// `true` if the process succeeded, or an error if no event has the given timestamp, the
+    /// edited event isn't valid, or WebReg couldn't edit the event.
+    ///
+    /// # Example
+    /// Moving the event with timestamp `2022-09-09 21:50:16.846885` 30 minutes later.
+    /// ```rust,no_run
+    /// use reqwest::Client;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let wrapper = WebRegWrapper::new(Client::new(), "my cookies");
+    ///
+    /// let edit_res = wrapper
+    ///     .req("FA23")
+    ///     .parsed()
+    ///     .edit_event("2022-09-09 21:50:16.846885", |e| {
+    ///         let start = e.start_hr * 60 + e.start_min + 30;
+    ///         let end = e.end_hr * 60 + e.end_min + 30;
+    ///         (e.start_hr, e.start_min) = (start / 60, start % 60);
+    ///         (e.end_hr, e.end_min) = (end / 60, end % 60);
+    ///     })
+    ///     .await;
+    ///
+    /// match edit_res {
+    ///     Ok(o) => println!("Edited event? {o}"),
+    ///     Err(e) => println!("Error! {e}"),
+    /// }
+    /// # }
+    /// ```
+    pub async fn edit_event(
+        &self,
+        event_timestamp: impl AsRef<str>,
+        edit: impl FnOnce(&mut EventAdd<'_>),
+    ) -> types::Result<bool> {
+        let events = self.get_events().await?;
+        let event = events
+            .iter()
+            .find(|e| e.timestamp == event_timestamp.as_ref())
+            .ok_or(WrapperError::InputError(
+                "event_timestamp",
+                "No event with the given timestamp was found.",
+            ))?;
+
+        let mut event_add = event.to_event_add()?;
+        edit(&mut event_add);
+        self.add_or_edit_event(event_add, Some(event.timestamp.as_str()))
+            .await
+    }
+
+    /// Adds multiple events to your WebReg calendar. Each event is added one after another,
+    /// and this function will not stop if an event couldn't be added.
+    ///
+    /// # Parameter
+    /// - `events`: The events to add.
+    ///
+    /// # Returns
+    /// A report containing the result of adding each event, in the order given.
+    pub async fn import_events<'b>(
+        &self,
+        events: impl IntoIterator<Item = EventAdd<'b>>,
+    ) -> EventReport {
+        let mut report = EventReport::default();
+        for event in events {
+            let event_name = event.event_name.to_string();
+            report.added.push(EventItemResult {
+                event_name,
+                result: self.add_or_edit_event(event, None).await,
+            });
+        }
+
+        report
+    }
+
+    /// Replaces all events on your WebReg calendar with the given events. All existing
+    /// events are removed, and then the given events are added.
+    ///
+    /// Every given event is validated before any existing event is removed, so an invalid
+    /// event won't leave you with an empty calendar. Past that point, this function will
+    /// not stop if an event couldn't be removed or added.
+    ///
+    /// # Parameter
+    /// - `events`: The events that your calendar should have.
+    ///
+    /// # Returns
+    /// A report containing the result of removing each existing event and adding each new
+    /// event, or an error if your existing events couldn't be retrieved or one of the given
+    /// events isn't valid.
+    pub async fn replace_events<'b>(
+        &self,
+        events: impl IntoIterator<Item = EventAdd<'b>>,
+    ) -> types::Result<EventReport> {
+        let events = events.into_iter().collect::<Vec<_>>();
+        for event in &events {
+            event.validate()?;
+        }
+
+        let mut report = EventReport::default();
+        for existing in self.get_events().await? {
+            report.removed.push(EventItemResult {
+                result: self.remove_event(&existing.timestamp).await,
+                event_name: existing.name,
+            });
+        }
+
+        report.added = self.import_events(events).await.added;
+        Ok(report)
     }
 
     /// Associates the term bound by this request to the cookies that are provided
@@ -1785,6 +2981,31 @@ impl<'a> WrapperTermRequest<'a> {
     /// A result, where nothing is returned if everything went well and an error is returned
     /// if something went wrong.
     pub async fn associate_term(&self) -> types::Result<()> {
-        associate_term_helper(&self.raw.info, self.raw.term).await
+        associate_term_helper(&self.raw.info, self.raw.term.as_ref()).await
     }
 }
+
+/// Normalizes and deduplicates a list of courses.
+///
+/// # Parameters
+/// - `courses`: The courses.
+///
+/// # Returns
+/// The distinct courses, as trimmed and uppercase subject code and course number pairs, in
+/// the order that they were first given.
+fn normalize_courses<'b>(
+    courses: impl IntoIterator<Item = impl Into<CourseKey<'b>>>,
+) -> Vec<(String, String)> {
+    let mut seen = HashSet::new();
+    courses
+        .into_iter()
+        .map(|course| {
+            let course = course.into();
+            (
+                course.subject().trim().to_uppercase(),
+                course.course_num().trim().to_uppercase(),
+            )
+        })
+        .filter(|course| seen.insert(course.clone()))
+        .collect()
+}
@@ -0,0 +1,298 @@
+use std::ops::Deref;
+use std::sync::{Mutex, MutexGuard};
+use std::time::Instant;
+
+use reqwest::Client;
+
+use crate::wrapper::WebRegWrapper;
+
+/// How a [`SessionPool`] picks the next session to hand out.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum SelectionStrategy {
+    /// Hand out the healthy sessions in order, wrapping around at the end.
+    #[default]
+    RoundRobin,
+    /// Hand out the healthy session that was handed out the longest time ago.
+    LeastRecentlyUsed,
+}
+
+/// The health of a single session in a [`SessionPool`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SessionHealth {
+    /// The index of the session in the pool.
+    pub index: usize,
+    /// Whether the session is in rotation. Sessions start out healthy, and are taken out of
+    /// rotation when a health check fails or when they're marked as unhealthy.
+    pub healthy: bool,
+    /// The number of health checks that have failed in a row.
+    pub consecutive_failures: u32,
+    /// When the session was last health checked, if ever.
+    pub last_checked: Option<Instant>,
+    /// When the session was last handed out, if ever.
+    pub last_used: Option<Instant>,
+    /// The number of times that the session was handed out.
+    pub uses: u64,
+}
+
+/// A pool of independent `WebRegWrapper`s, each for a different (authorized) account. This
+/// is meant for spreading a large number of requests (e.g., when scraping) across several
+/// sessions without switching cookies on a single wrapper.
+///
+/// Each wrapper keeps its own client, and therefore its own connection pool, so there's no
+/// need to close connections after each request.
+///
+/// # Example
+/// ```rust,no_run
+/// use webweg::wrapper::session_pool::{SelectionStrategy, SessionPool};
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let pool = SessionPool::from_cookies(
+///     ["cookies for account 1", "cookies for account 2"],
+///     SelectionStrategy::RoundRobin,
+/// );
+///
+/// pool.check_health().await;
+/// for subject in ["CSE", "MATH", "COGS"] {
+///     let Some(session) = pool.get() else {
+///         break;
+///     };
+///
+///     let courses = session.req("FA23").parsed().get_course_info((subject, "100")).await;
+///     println!("session {}: {:?}", session.index(), courses.map(|c| c.len()));
+/// }
+/// # }
+/// ```
+pub struct SessionPool {
+    sessions: Vec<WebRegWrapper>,
+    strategy: SelectionStrategy,
+    state: Mutex<PoolState>,
+}
+
+/// The bookkeeping for a `SessionPool`.
+struct PoolState {
+    /// The index of the next session to consider when using round-robin selection.
+    cursor: usize,
+    health: Vec<SessionHealth>,
+}
+
+impl SessionPool {
+    /// Creates a new pool out of the specified wrappers. Every session starts out healthy.
+    ///
+    /// # Parameters
+    /// - `sessions`: The wrappers. These should each have their own client.
+    /// - `strategy`: How to pick the next session.
+    ///
+    /// # Returns
+    /// The pool.
+    pub fn new(
+        sessions: impl IntoIterator<Item = WebRegWrapper>,
+        strategy: SelectionStrategy,
+    ) -> Self {
+        let sessions = sessions.into_iter().collect::<Vec<_>>();
+        let health = (0..sessions.len())
+            .map(|index| SessionHealth {
+                index,
+                healthy: true,
+                consecutive_failures: 0,
+                last_checked: None,
+                last_used: None,
+                uses: 0,
+            })
+            .collect();
+
+        Self {
+            sessions,
+            strategy,
+            state: Mutex::new(PoolState { cursor: 0, health }),
+        }
+    }
+
+    /// Creates a new pool with one wrapper per set of cookies. Each wrapper gets its own
+    /// default client, user agent, and timeout. To customize the wrappers, build them
+    /// yourself and use [`SessionPool::new`].
+    ///
+    /// # Parameters
+    /// - `cookies`: The cookies for each session.
+    /// - `strategy`: How to pick the next session.
+    ///
+    /// # Returns
+    /// The pool.
+    pub fn from_cookies(
+        cookies: impl IntoIterator<Item = impl Into<String>>,
+        strategy: SelectionStrategy,
+    ) -> Self {
+        Self::new(
+            cookies
+                .into_iter()
+                .map(|c| WebRegWrapper::new(Client::new(), c)),
+            strategy,
+        )
+    }
+
+    /// Gets the next healthy session, according to the pool's selection strategy.
+    ///
+    /// # Returns
+    /// The session, or `None` if there are no healthy sessions.
+    pub fn get(&self) -> Option<PooledSession<'_>> {
+        let mut state = self.state();
+        let len = state.health.len();
+        let index = match self.strategy {
+            SelectionStrategy::RoundRobin => {
+                let start = state.cursor;
+                let index = (0..len)
+                    .map(|offset| (start + offset) % len)
+                    .find(|&i| state.health[i].healthy)?;
+                state.cursor = (index + 1) % len;
+                index
+            }
+            // Sessions that were never used (`None`) sort before every used session.
+            SelectionStrategy::LeastRecentlyUsed => {
+                state
+                    .health
+                    .iter()
+                    .filter(|h| h.healthy)
+                    .min_by_key(|h| h.last_used)?
+                    .index
+            }
+        };
+
+        let health = &mut state.health[index];
+        health.last_used = Some(Instant::now());
+        health.uses += 1;
+        Some(PooledSession {
+            index,
+            wrapper: &self.sessions[index],
+        })
+    }
+
+    /// Gets a session by its index, regardless of its health.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the session.
+    ///
+    /// # Returns
+    /// The session, or `None` if the index is out of range.
+    pub fn session(&self, index: usize) -> Option<&WebRegWrapper> {
+        self.sessions.get(index)
+    }
+
+    /// Pings every session, taking sessions that fail the ping out of rotation and putting
+    /// sessions that pass it back into rotation. Sessions are pinged one at a time.
+    ///
+    /// # Returns
+    /// The health of every session after the check.
+    pub async fn check_health(&self) -> Vec<SessionHealth> {
+        for (index, session) in self.sessions.iter().enumerate() {
+            let ok = session.ping_server().await;
+            let mut state = self.state();
+            let health = &mut state.health[index];
+            health.last_checked = Some(Instant::now());
+            health.healthy = ok;
+            if ok {
+                health.consecutive_failures = 0;
+            } else {
+                health.consecutive_failures += 1;
+            }
+        }
+
+        self.health()
+    }
+
+    /// Takes a session out of rotation until it passes a health check. This is useful if
+    /// a request made with the session failed because the session is no longer valid.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the session.
+    pub fn mark_unhealthy(&self, index: usize) {
+        if let Some(health) = self.state().health.get_mut(index) {
+            health.healthy = false;
+        }
+    }
+
+    /// Puts a session back into rotation without checking its health.
+    ///
+    /// # Parameters
+    /// - `index`: The index of the session.
+    pub fn mark_healthy(&self, index: usize) {
+        if let Some(health) = self.state().health.get_mut(index) {
+            health.healthy = true;
+            health.consecutive_failures = 0;
+        }
+    }
+
+    /// Gets the health of every session.
+    ///
+    /// # Returns
+    /// The health of every session, in order.
+    pub fn health(&self) -> Vec<SessionHealth> {
+        self.state().health.clone()
+    }
+
+    /// Gets the number of sessions that are in rotation.
+    ///
+    /// # Returns
+    /// The number of healthy sessions.
+    pub fn healthy_count(&self) -> usize {
+        self.state().health.iter().filter(|h| h.healthy).count()
+    }
+
+    /// Gets the number of sessions in the pool, healthy or not.
+    ///
+    /// # Returns
+    /// The number of sessions.
+    pub fn len(&self) -> usize {
+        self.sessions.len()
+    }
+
+    /// Checks whether the pool has no sessions.
+    ///
+    /// # Returns
+    /// `true` if there are no sessions, and `false` otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    /// Locks the pool's bookkeeping, even if a previous lock holder panicked.
+    ///
+    /// # Returns
+    /// The bookkeeping.
+    fn state(&self) -> MutexGuard<'_, PoolState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A session handed out by a [`SessionPool`]. This dereferences to the session's
+/// `WebRegWrapper`.
+#[derive(Clone, Copy)]
+pub struct PooledSession<'a> {
+    index: usize,
+    wrapper: &'a WebRegWrapper,
+}
+
+impl<'a> PooledSession<'a> {
+    /// Gets the index of this session in the pool. This can be given to
+    /// [`SessionPool::mark_unhealthy`] if the session stops working.
+    ///
+    /// # Returns
+    /// The index.
+    pub fn index(&self) -> usize {
+        self.index
+    }
+
+    /// Gets the wrapper for this session.
+    ///
+    /// # Returns
+    /// The wrapper.
+    pub fn wrapper(&self) -> &'a WebRegWrapper {
+        self.wrapper
+    }
+}
+
+impl<'a> Deref for PooledSession<'a> {
+    type Target = WebRegWrapper;
+
+    fn deref(&self) -> &Self::Target {
+        self.wrapper
+    }
+}
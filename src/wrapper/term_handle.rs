@@ -0,0 +1,89 @@
+use std::sync::Arc;
+
+use crate::wrapper::request_builder::WrapperTermRequestBuilder;
+use crate::wrapper::requester_term::{WrapperTermRawRequest, WrapperTermRequest};
+use crate::wrapper::WebRegWrapper;
+
+/// An owned handle for making requests for a single term. Unlike the requesters returned by
+/// [`WebRegWrapper::req`], this doesn't borrow the wrapper or the term, so it can be stored
+/// in a struct or moved into a spawned task.
+///
+/// The handle shares everything (including the cookies) with the wrapper that it was made
+/// from, and cloning it is cheap.
+///
+/// # Example
+/// ```rust,no_run
+/// use webweg::wrapper::WebRegWrapper;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let wrapper = WebRegWrapper::builder()
+///     .with_cookies("my cookies")
+///     .try_build_wrapper()
+///     .unwrap();
+///
+/// let fall = wrapper.term_handle("FA23");
+/// let task = tokio::spawn(async move { fall.parsed().get_schedule(None).await });
+/// println!("{:?}", task.await.unwrap());
+/// # }
+/// ```
+#[derive(Clone)]
+pub struct TermHandle {
+    wrapper: WebRegWrapper,
+    term: Arc<str>,
+}
+
+impl TermHandle {
+    /// Creates a new handle.
+    ///
+    /// # Parameters
+    /// - `wrapper`: The wrapper to make requests with.
+    /// - `term`: The term to make requests for.
+    ///
+    /// # Returns
+    /// The handle.
+    pub(crate) fn new(wrapper: WebRegWrapper, term: Arc<str>) -> Self {
+        Self { wrapper, term }
+    }
+
+    /// Gets the term that this handle makes requests for.
+    ///
+    /// # Returns
+    /// The term.
+    pub fn term(&self) -> &str {
+        &self.term
+    }
+
+    /// Gets the wrapper that this handle makes requests with.
+    ///
+    /// # Returns
+    /// The wrapper.
+    pub fn wrapper(&self) -> &WebRegWrapper {
+        &self.wrapper
+    }
+
+    /// Returns a request builder for this term, which can be used to override settings
+    /// (e.g., the cookies or timeout) for a specific request only.
+    ///
+    /// # Returns
+    /// The request builder.
+    pub fn req(&self) -> WrapperTermRequestBuilder<'_> {
+        self.wrapper.req(self.term.as_ref())
+    }
+
+    /// Returns the parsed requester for this term, using the wrapper's settings.
+    ///
+    /// # Returns
+    /// The parsed requester.
+    pub fn parsed(&self) -> WrapperTermRequest<'_> {
+        self.req().parsed()
+    }
+
+    /// Returns the raw requester for this term, using the wrapper's settings.
+    ///
+    /// # Returns
+    /// The raw requester.
+    pub fn raw(&self) -> WrapperTermRawRequest<'_> {
+        self.req().raw()
+    }
+}
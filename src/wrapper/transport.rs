@@ -0,0 +1,583 @@
+use std::collections::HashMap;
+use std::fmt::{Debug, Formatter};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::task::Poll;
+use std::time::Duration;
+
+use reqwest::Client;
+use url::Url;
+
+use crate::constants::WRADAPTER_BASE;
+use crate::types::{self, RawResponse};
+use crate::wrapper::input_types::HttpMethod;
+
+/// The future returned by [`Transport::send`].
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = types::Result<RawResponse>> + Send + 'a>>;
+
+/// A request that is about to be sent to WebReg.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpRequest {
+    /// The HTTP method.
+    pub method: HttpMethod,
+    /// The URL, including the query string.
+    pub url: Url,
+    /// The request headers (e.g., the cookies and user agent).
+    pub headers: Vec<(String, String)>,
+    /// The request body, if any. For `POST` requests, this is a URL-encoded form.
+    pub body: Option<String>,
+    /// How long to wait for a response before giving up.
+    pub timeout: Duration,
+}
+
+impl HttpRequest {
+    /// Gets the value of a header. Header names are case-insensitive.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the header.
+    ///
+    /// # Returns
+    /// The value of the first header with that name, if any.
+    pub fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(k, _)| k.eq_ignore_ascii_case(name))
+            .map(|(_, v)| v.as_str())
+    }
+
+    /// Sets a header, replacing any existing headers with the same name.
+    ///
+    /// # Parameters
+    /// - `name`: The name of the header.
+    /// - `value`: The value of the header.
+    pub fn set_header(&mut self, name: impl Into<String>, value: impl Into<String>) {
+        let name = name.into();
+        self.headers.retain(|(k, _)| !k.eq_ignore_ascii_case(&name));
+        self.headers.push((name, value.into()));
+    }
+
+    /// Decodes the body as a URL-encoded form.
+    ///
+    /// # Returns
+    /// The form fields, in order. This is empty if there is no body.
+    pub fn form(&self) -> Vec<(String, String)> {
+        self.body
+            .as_deref()
+            .map(|body| {
+                url::form_urlencoded::parse(body.as_bytes())
+                    .into_owned()
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+}
+
+/// Something that can send requests to WebReg. By default, the wrapper uses
+/// [`ReqwestTransport`], but any HTTP client (or a test double, like
+/// [`InMemoryTransport`]) can be used instead by implementing this trait.
+pub trait Transport: Send + Sync {
+    /// Sends a request.
+    ///
+    /// # Parameters
+    /// - `req`: The request.
+    ///
+    /// # Returns
+    /// The status code, headers, and body of the response, or an error if the request
+    /// couldn't be made. A response with a bad status code should still be returned as a
+    /// response, not an error.
+    fn send(&self, req: HttpRequest) -> TransportFuture<'_>;
+
+    /// Creates a transport that doesn't share any connections with this one. This is used
+    /// when the cookies are overridden for a request, so that a keep-alive connection opened
+    /// for one session is never reused for another.
+    ///
+    /// By default, this returns `None`, meaning that this transport can't be isolated. In
+    /// that case, requests with overridden cookies are sent through this transport with the
+    /// connection closed after each request.
+    ///
+    /// # Returns
+    /// The new transport, or `None` if this transport can't be isolated.
+    fn isolated(&self) -> Option<Arc<dyn Transport>> {
+        None
+    }
+
+    /// Whether this transport can be used with any cookies as-is, because it never reuses a
+    /// connection between requests (e.g., a test double). Transports that return `true` are
+    /// used for requests with overridden cookies without being isolated.
+    ///
+    /// By default, this returns `false`.
+    ///
+    /// # Returns
+    /// Whether this transport can be shared between sessions.
+    fn is_shareable(&self) -> bool {
+        false
+    }
+}
+
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn send(&self, req: HttpRequest) -> TransportFuture<'_> {
+        (**self).send(req)
+    }
+
+    fn isolated(&self) -> Option<Arc<dyn Transport>> {
+        (**self).isolated()
+    }
+
+    fn is_shareable(&self) -> bool {
+        (**self).is_shareable()
+    }
+}
+
+/// A function that creates a new client. Every client that it creates should be configured
+/// the same way.
+pub type ClientFactory = Arc<dyn Fn() -> Client + Send + Sync>;
+
+/// A transport that sends requests using a
+/// [`reqwest::Client`](https://docs.rs/reqwest/latest/reqwest/struct.Client.html).
+#[derive(Clone)]
+pub struct ReqwestTransport {
+    client: Client,
+    /// Creates the clients for isolated transports, if this transport can be isolated.
+    factory: Option<ClientFactory>,
+}
+
+impl ReqwestTransport {
+    /// Creates a new transport using the specified client. Note that the timeout set on the
+    /// client will be ignored in favor of the timeout on each request.
+    ///
+    /// Since a `Client` can't be copied with its settings, this transport can't be isolated,
+    /// so requests with overridden cookies will close the connection after each request. To
+    /// avoid this, use [`ReqwestTransport::with_factory`] instead.
+    ///
+    /// # Parameters
+    /// - `client`: The client.
+    ///
+    /// # Returns
+    /// The transport.
+    pub fn new(client: Client) -> Self {
+        Self {
+            client,
+            factory: None,
+        }
+    }
+
+    /// Creates a new transport using clients created by the specified function. Isolated
+    /// transports (see [`Transport::isolated`]) get their own client from this function, so
+    /// they have the same settings (e.g., proxy or TLS roots) as this one.
+    ///
+    /// # Parameters
+    /// - `factory`: The function that creates a client.
+    ///
+    /// # Returns
+    /// The transport.
+    pub fn with_factory(factory: impl Fn() -> Client + Send + Sync + 'static) -> Self {
+        let factory: ClientFactory = Arc::new(factory);
+        Self {
+            client: factory(),
+            factory: Some(factory),
+        }
+    }
+}
+
+impl Default for ReqwestTransport {
+    fn default() -> Self {
+        Self::with_factory(Client::new)
+    }
+}
+
+impl Debug for ReqwestTransport {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ReqwestTransport")
+            .field("client", &self.client)
+            .field("has_factory", &self.factory.is_some())
+            .finish()
+    }
+}
+
+impl Transport for ReqwestTransport {
+    fn send(&self, req: HttpRequest) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let mut builder = match req.method {
+                HttpMethod::Get => self.client.get(req.url),
+                HttpMethod::Post => self.client.post(req.url),
+            }
+            .timeout(req.timeout);
+
+            for (name, value) in req.headers {
+                builder = builder.header(name, value);
+            }
+
+            if let Some(body) = req.body {
+                builder = builder.body(body);
+            }
+
+            let res = builder.send().await?;
+            let status = res.status().as_u16();
+            let headers = res
+                .headers()
+                .iter()
+                .map(|(k, v)| {
+                    (
+                        k.as_str().to_owned(),
+                        String::from_utf8_lossy(v.as_bytes()).into_owned(),
+                    )
+                })
+                .collect();
+
+            Ok(RawResponse {
+                status,
+                headers,
+                body: res.text().await?,
+            })
+        })
+    }
+
+    /// Creates a transport with a new client from this transport's factory, which has its own
+    /// connection pool. Transports without a factory can't be isolated.
+    fn isolated(&self) -> Option<Arc<dyn Transport>> {
+        let factory = self.factory.as_ref()?;
+        Some(Arc::new(ReqwestTransport {
+            client: factory(),
+            factory: Some(factory.clone()),
+        }))
+    }
+}
+
+/// A transport cached for a session, along with the cookies of that session.
+type CachedTransport = (Arc<str>, Arc<dyn Transport>);
+
+/// The transports used for requests whose cookies were overridden, keyed by the cookies. The least recently used transport is dropped once the cache is full.
+pub(crate) struct TransportCache {
+    capacity: usize,
+    /// The cached transports, from least to most recently used.
+    transports: Mutex<Vec<CachedTransport>>,
+}
+
+impl TransportCache {
+    /// Creates a new, empty cache.
+    ///
+    /// # Parameters
+    /// - `capacity`: The maximum number of transports to keep.
+    ///
+    /// # Returns
+    /// The cache.
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            transports: Mutex::new(vec![]),
+        }
+    }
+
+    /// Gets the transport to use for the specified cookies, creating (and caching) one from
+    /// `base` if needed. If the cache can't hold any transports, a new isolated transport is
+    /// created every time.
+    ///
+    /// # Parameters
+    /// - `cookies`: The cookies that the transport will be used with.
+    /// - `base`: The wrapper's transport.
+    ///
+    /// # Returns
+    /// The transport, which is `base` itself if `base` is shareable, or `None` if `base` can't
+    /// be isolated. In the latter case, the request must close its connection.
+    pub fn get(&self, cookies: &Arc<str>, base: &Arc<dyn Transport>) -> Option<Arc<dyn Transport>> {
+        if base.is_shareable() {
+            return Some(base.clone());
+        }
+
+        if self.capacity == 0 {
+            return base.isolated();
+        }
+
+        let mut transports = self.transports.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(idx) = transports.iter().position(|(k, _)| k == cookies) {
+            let entry = transports.remove(idx);
+            let transport = entry.1.clone();
+            transports.push(entry);
+            return Some(transport);
+        }
+
+        let transport = base.isolated()?;
+        if transports.len() >= self.capacity {
+            transports.remove(0);
+        }
+
+        transports.push((cookies.clone(), transport.clone()));
+        Some(transport)
+    }
+}
+
+/// A transport that never makes a network request, and instead responds with canned
+/// responses. This is meant for testing code that uses the wrapper.
+///
+/// Responses are looked up by endpoint, which is the URL without the query string. If the
+/// URL is a WebReg (`wradapter`) URL, the endpoint can also be given relative to
+/// `https://act.ucsd.edu/webreg2/svc/wradapter/` (e.g., `secure/get-class`). A response
+/// can also be computed from the request with [`InMemoryTransport::with_handler`] or
+/// [`InMemoryTransport::with_fallback`]. Any request to an endpoint without a canned
+/// response or fallback gets a `404` response.
+///
+/// # Example
+/// ```rust
+/// use std::sync::Arc;
+/// use webweg::wrapper::transport::InMemoryTransport;
+/// use webweg::wrapper::WebRegWrapper;
+///
+/// # #[tokio::main(flavor = "current_thread")]
+/// # async fn main() {
+/// let transport = Arc::new(
+///     InMemoryTransport::new().with_json("secure/sched-get-schednames", r#"["My Schedule"]"#),
+/// );
+///
+/// let wrapper = WebRegWrapper::builder()
+///     .with_cookies("abc")
+///     .with_transport(transport.clone())
+///     .try_build_wrapper()
+///     .unwrap();
+///
+/// let schedules = wrapper.req("FA23").parsed().get_schedule_list().await.unwrap();
+/// assert_eq!(vec!["My Schedule"], schedules);
+/// assert_eq!(1, transport.requests().len());
+/// # }
+/// ```
+#[derive(Debug, Default)]
+pub struct InMemoryTransport {
+    responses: HashMap<String, Responder>,
+    fallback: Option<Responder>,
+    requests: Mutex<Vec<HttpRequest>>,
+    in_flight: AtomicUsize,
+    max_in_flight: AtomicUsize,
+}
+
+/// A function that computes the response to a request.
+type Handler = Arc<dyn Fn(&HttpRequest) -> RawResponse + Send + Sync>;
+
+/// How an [`InMemoryTransport`] responds to requests for an endpoint.
+#[derive(Clone)]
+enum Responder {
+    /// Always gives the same response.
+    Fixed(RawResponse),
+    /// Computes the response from the request.
+    Handler(Handler),
+}
+
+impl Responder {
+    /// Gets the response to a request.
+    ///
+    /// # Parameters
+    /// - `req`: The request.
+    ///
+    /// # Returns
+    /// The response.
+    fn respond(&self, req: &HttpRequest) -> RawResponse {
+        match self {
+            Responder::Fixed(res) => res.clone(),
+            Responder::Handler(handler) => handler(req),
+        }
+    }
+}
+
+impl Debug for Responder {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Responder::Fixed(res) => f.debug_tuple("Fixed").field(res).finish(),
+            Responder::Handler(_) => f.write_str("Handler(..)"),
+        }
+    }
+}
+
+impl InMemoryTransport {
+    /// Creates a new transport without any canned responses.
+    ///
+    /// # Returns
+    /// The transport.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the response for an endpoint, replacing any existing response for it.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The endpoint.
+    /// - `response`: The response to give for every request to the endpoint.
+    ///
+    /// # Returns
+    /// The transport.
+    pub fn with_response(mut self, endpoint: impl Into<String>, response: RawResponse) -> Self {
+        self.responses.insert(
+            Self::normalize(&endpoint.into()).to_owned(),
+            Responder::Fixed(response),
+        );
+        self
+    }
+
+    /// Sets a function that computes the response for an endpoint from each request to it,
+    /// replacing any existing response for it.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The endpoint.
+    /// - `handler`: The function to call with every request to the endpoint.
+    ///
+    /// # Returns
+    /// The transport.
+    ///
+    /// # Example
+    /// ```rust
+    /// use std::sync::Arc;
+    /// use webweg::types::RawResponse;
+    /// use webweg::wrapper::transport::InMemoryTransport;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// # #[tokio::main(flavor = "current_thread")]
+    /// # async fn main() {
+    /// let transport = Arc::new(InMemoryTransport::new().with_handler(
+    ///     "secure/sched-get-schednames",
+    ///     |req| RawResponse {
+    ///         status: 200,
+    ///         headers: vec![],
+    ///         body: format!(r#"["{}"]"#, req.header("cookie").unwrap_or_default()),
+    ///     },
+    /// ));
+    ///
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("abc")
+    ///     .with_transport(transport)
+    ///     .try_build_wrapper()
+    ///     .unwrap();
+    ///
+    /// let schedules = wrapper.req("FA23").parsed().get_schedule_list().await.unwrap();
+    /// assert_eq!(vec!["abc"], schedules);
+    /// # }
+    /// ```
+    pub fn with_handler(
+        mut self,
+        endpoint: impl Into<String>,
+        handler: impl Fn(&HttpRequest) -> RawResponse + Send + Sync + 'static,
+    ) -> Self {
+        self.responses.insert(
+            Self::normalize(&endpoint.into()).to_owned(),
+            Responder::Handler(Arc::new(handler)),
+        );
+        self
+    }
+
+    /// Sets a function that computes the response for any request to an endpoint without a
+    /// canned response, instead of responding with a `404` response.
+    ///
+    /// # Parameters
+    /// - `handler`: The function to call with every such request.
+    ///
+    /// # Returns
+    /// The transport.
+    pub fn with_fallback(
+        mut self,
+        handler: impl Fn(&HttpRequest) -> RawResponse + Send + Sync + 'static,
+    ) -> Self {
+        self.fallback = Some(Responder::Handler(Arc::new(handler)));
+        self
+    }
+
+    /// Sets the response for an endpoint to a successful (`200`) response with the
+    /// specified body, replacing any existing response for it.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The endpoint.
+    /// - `body`: The body to give for every request to the endpoint.
+    ///
+    /// # Returns
+    /// The transport.
+    pub fn with_json(self, endpoint: impl Into<String>, body: impl Into<String>) -> Self {
+        self.with_response(
+            endpoint,
+            RawResponse {
+                status: 200,
+                headers: vec![("content-type".into(), "application/json".into())],
+                body: body.into(),
+            },
+        )
+    }
+
+    /// Gets every request that was sent through this transport.
+    ///
+    /// # Returns
+    /// The requests, in the order that they were sent.
+    pub fn requests(&self) -> Vec<HttpRequest> {
+        self.requests
+            .lock()
+            .map(|r| r.clone())
+            .unwrap_or_else(|e| e.into_inner().clone())
+    }
+
+    /// Gets the most requests that were waiting on a response from this transport at once.
+    /// Each request waits once before getting its response, so requests that are sent
+    /// concurrently are counted together.
+    ///
+    /// # Returns
+    /// The most requests that were in flight at once.
+    pub fn max_in_flight(&self) -> usize {
+        self.max_in_flight.load(Ordering::SeqCst)
+    }
+
+    /// Strips the query string and, for WebReg URLs, the `wradapter` prefix from an
+    /// endpoint.
+    ///
+    /// # Parameters
+    /// - `endpoint`: The endpoint or URL.
+    ///
+    /// # Returns
+    /// The normalized endpoint.
+    fn normalize(endpoint: &str) -> &str {
+        let endpoint = endpoint.split('?').next().unwrap_or(endpoint);
+        endpoint
+            .strip_prefix(WRADAPTER_BASE)
+            .unwrap_or(endpoint)
+            .trim_start_matches('/')
+    }
+}
+
+impl Transport for InMemoryTransport {
+    fn send(&self, req: HttpRequest) -> TransportFuture<'_> {
+        let endpoint = Self::normalize(req.url.as_str()).to_owned();
+        let res = match self.responses.get(&endpoint).or(self.fallback.as_ref()) {
+            Some(responder) => responder.respond(&req),
+            None => RawResponse {
+                status: 404,
+                headers: vec![],
+                body: format!("no canned response for {endpoint}"),
+            },
+        };
+
+        match self.requests.lock() {
+            Ok(mut requests) => requests.push(req),
+            Err(e) => e.into_inner().push(req),
+        }
+
+        Box::pin(async move {
+            let now = self.in_flight.fetch_add(1, Ordering::SeqCst) + 1;
+            self.max_in_flight.fetch_max(now, Ordering::SeqCst);
+
+            // Give any other requests a chance to start, like a real request would.
+            let mut yielded = false;
+            std::future::poll_fn(|cx| {
+                if yielded {
+                    Poll::Ready(())
+                } else {
+                    yielded = true;
+                    cx.waker().wake_by_ref();
+                    Poll::Pending
+                }
+            })
+            .await;
+
+            self.in_flight.fetch_sub(1, Ordering::SeqCst);
+            Ok(res)
+        })
+    }
+
+    /// No connections are ever made, so this transport can be used with any cookies.
+    fn is_shareable(&self) -> bool {
+        true
+    }
+}
@@ -1,9 +1,11 @@
-#[cfg(feature = "multi")]
-use parking_lot::lock_api::Mutex;
+use std::sync::{Arc, RwLock};
 use std::time::Duration;
 
-use crate::constants::MY_USER_AGENT;
+use crate::constants::{MY_USER_AGENT, OVERRIDE_TRANSPORT_CACHE_SIZE};
+use crate::schema::{SchemaDrift, SchemaObserver};
+use crate::wrapper::middleware::Middleware;
 use crate::wrapper::request_data::WebRegWrapperData;
+use crate::wrapper::transport::{ReqwestTransport, Transport, TransportCache};
 use reqwest::Client;
 
 use crate::wrapper::WebRegWrapper;
@@ -26,10 +28,13 @@ use crate::wrapper::WebRegWrapper;
 /// ```
 pub struct WebRegWrapperBuilder {
     cookies: Option<String>,
-    client: Client,
+    transport: Arc<dyn Transport>,
     user_agent: String,
     default_timeout: Duration,
     close_after_request: bool,
+    schema_observer: Option<SchemaObserver>,
+    middleware: Vec<Arc<dyn Middleware>>,
+    max_override_transports: usize,
 }
 
 impl WebRegWrapperBuilder {
@@ -41,10 +46,13 @@ impl WebRegWrapperBuilder {
     pub fn new() -> Self {
         Self {
             cookies: None,
-            client: Client::new(),
+            transport: Arc::new(ReqwestTransport::default()),
             user_agent: MY_USER_AGENT.to_owned(),
             default_timeout: Duration::from_secs(30),
             close_after_request: false,
+            schema_observer: None,
+            middleware: vec![],
+            max_override_transports: OVERRIDE_TRANSPORT_CACHE_SIZE,
         }
     }
 
@@ -62,13 +70,48 @@ impl WebRegWrapperBuilder {
 
     /// Sets the client to the specified client.
     ///
+    /// Since a client can't be copied with its settings, requests that override the cookies
+    /// can't be given a separate client, so they close the connection after each request. If
+    /// you override the cookies, consider using
+    /// [`with_client_factory`](WebRegWrapperBuilder::with_client_factory) instead.
+    ///
     /// # Parameters
     /// - `client`: The client to use.
     ///
     /// # Returns
     /// The builder.
     pub fn with_client(mut self, client: Client) -> Self {
-        self.client = client;
+        self.transport = Arc::new(ReqwestTransport::new(client));
+        self
+    }
+
+    /// Sets the function used to create clients. The wrapper's client is created right away,
+    /// and requests that override the cookies are given their own client from this function,
+    /// so they have the same settings as the wrapper's client.
+    ///
+    /// # Parameters
+    /// - `factory`: The function that creates a client.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_client_factory(
+        mut self,
+        factory: impl Fn() -> Client + Send + Sync + 'static,
+    ) -> Self {
+        self.transport = Arc::new(ReqwestTransport::with_factory(factory));
+        self
+    }
+
+    /// Sets the transport used to send requests. This replaces any client set with
+    /// `with_client`.
+    ///
+    /// # Parameters
+    /// - `transport`: The transport to use.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_transport(mut self, transport: impl Transport + 'static) -> Self {
+        self.transport = Arc::new(transport);
         self
     }
 
@@ -98,13 +141,11 @@ impl WebRegWrapperBuilder {
 
     /// Whether the client should close the connection after completing the request.
     ///
-    /// If you plan on overriding the session cookies when making a request under this wrapper,
-    /// consider setting this to `true`. Otherwise, you might get stale login errors when overriding
-    /// cookies for a particular request. That being said, setting this to `true` may incur
-    /// performance penalties; in particular, requests may be up to 3-4 times slower if this is `true`.
-    ///
-    /// It is recommended that this field's value is set to `false` if you do not need to switch
-    /// cookies for this wrapper.
+    /// This is not needed to override the session cookies for a request, since requests with
+    /// overridden cookies are either made with a separate client or close their connection
+    /// (see [`with_max_override_transports`](WebRegWrapperBuilder::with_max_override_transports)).
+    /// Setting this to `true` may incur performance penalties; in particular, requests may be
+    /// up to 3-4 times slower if this is `true`.
     ///
     /// # Parameters
     /// - `close`: Whether to close the connection after completing the request.
@@ -116,6 +157,95 @@ impl WebRegWrapperBuilder {
         self
     }
 
+    /// Sets the maximum number of separate transports to keep for requests that override the
+    /// cookies. By default, this is 16.
+    ///
+    /// When the cookies are overridden for a request, the request is made with a transport
+    /// (for the default transport, a new client with its own connection pool) that is only
+    /// used for those cookies, so that connections are never shared between sessions. These
+    /// transports are kept so that later requests with the same cookies can reuse their
+    /// connections; once there are too many, the least recently used one is dropped.
+    ///
+    /// If this is `0`, a new transport is made for every request with overridden cookies.
+    /// If the wrapper's transport can't be isolated (e.g., a client set with
+    /// [`with_client`](WebRegWrapperBuilder::with_client)), requests with overridden cookies
+    /// are made with the wrapper's transport but close the connection after each request.
+    ///
+    /// # Parameters
+    /// - `max`: The maximum number of transports to keep.
+    ///
+    /// # Returns
+    /// The builder.
+    pub fn with_max_override_transports(mut self, max: usize) -> Self {
+        self.max_override_transports = max;
+        self
+    }
+
+    /// Sets a callback that is given any schema drift found in WebReg's responses. When set,
+    /// every JSON response that the parsed requester deserializes is first compared against
+    /// the keys that this crate expects, and the callback is called whenever WebReg returns
+    /// a key that this crate doesn't know about or leaves out a key that this crate expects.
+    ///
+    /// This is off by default, since checking every response has a small cost.
+    ///
+    /// # Parameters
+    /// - `observer`: The callback.
+    ///
+    /// # Returns
+    /// The builder.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("abc")
+    ///     .with_schema_observer(|drift| eprintln!("WebReg changed: {drift}"))
+    ///     .try_build_wrapper();
+    /// ```
+    pub fn with_schema_observer(
+        mut self,
+        observer: impl Fn(&SchemaDrift) + Send + Sync + 'static,
+    ) -> Self {
+        self.schema_observer = Some(Arc::new(observer));
+        self
+    }
+
+    /// Adds a middleware that is run around every request made by the wrapper. This can be
+    /// called multiple times; middleware is run in the order that it was added.
+    ///
+    /// # Parameters
+    /// - `middleware`: The middleware.
+    ///
+    /// # Returns
+    /// The builder.
+    ///
+    /// # Example
+    /// ```rust,no_run
+    /// use webweg::types;
+    /// use webweg::wrapper::middleware::{Middleware, RequestMeta};
+    /// use webweg::wrapper::transport::HttpRequest;
+    /// use webweg::wrapper::WebRegWrapper;
+    ///
+    /// struct TraceHeader;
+    ///
+    /// impl Middleware for TraceHeader {
+    ///     fn before_request(&self, req: &mut HttpRequest, meta: &RequestMeta<'_>) -> types::Result<()> {
+    ///         req.set_header("X-Operation", meta.operation);
+    ///         Ok(())
+    ///     }
+    /// }
+    ///
+    /// let wrapper = WebRegWrapper::builder()
+    ///     .with_cookies("abc")
+    ///     .with_middleware(TraceHeader)
+    ///     .try_build_wrapper();
+    /// ```
+    pub fn with_middleware(mut self, middleware: impl Middleware + 'static) -> Self {
+        self.middleware.push(Arc::new(middleware));
+        self
+    }
+
     /// Attempts to build the wrapper. To successfully build the wrapper, the cookies and term
     /// must be provided.
     ///
@@ -125,16 +255,16 @@ impl WebRegWrapperBuilder {
     pub fn try_build_wrapper(self) -> Option<WebRegWrapper> {
         if let Some(cookies) = self.cookies {
             Some(WebRegWrapper {
-                data: WebRegWrapperData {
-                    #[cfg(feature = "multi")]
-                    cookies: Mutex::new(cookies),
-                    #[cfg(not(feature = "multi"))]
-                    cookies,
-                    client: self.client,
+                data: Arc::new(WebRegWrapperData {
+                    cookies: RwLock::new(cookies.into()),
+                    transport: self.transport,
                     user_agent: self.user_agent,
                     timeout: self.default_timeout,
                     close_after_request: self.close_after_request,
-                },
+                    schema_observer: self.schema_observer,
+                    middleware: self.middleware,
+                    transport_cache: TransportCache::new(self.max_override_transports),
+                }),
             })
         } else {
             None
@@ -1,14 +1,87 @@
-use reqwest::{Error, Response};
+use std::future::{poll_fn, Future};
+use std::pin::Pin;
+use std::task::Poll;
+
 use serde::de::DeserializeOwned;
 use serde_json::Value;
 use url::Url;
 
 use crate::constants::{ELIGIBILITY, STATUS_START, VERIFY_FAIL_ERR};
-use crate::types::WrapperError;
-use crate::util::get_term_seq_id;
+use crate::schema::{check_schema, RawSchema, SchemaObserver};
+use crate::types::{RawResponse, TermCode, WrapperError};
+use crate::wrapper::middleware::Hooks;
 use crate::wrapper::request_data::{ReqType, ReqwestWebRegClientData};
+use crate::wrapper::transport::{HttpRequest, Transport};
 use crate::{types, util};
 
+/// Sends a request using the given transport, giving the response to the middleware.
+///
+/// # Parameters
+/// - `transport`: The transport to send the request with.
+/// - `req`: The request, which should already have been run through the middleware.
+/// - `hooks`: The middleware to run for this request.
+///
+/// # Returns
+/// The untouched response, or an error if the request couldn't be made.
+pub(crate) async fn send(
+    transport: &dyn Transport,
+    req: HttpRequest,
+    hooks: &Hooks<'_>,
+) -> types::Result<RawResponse> {
+    let res = transport.send(req).await?;
+    hooks.after_response(&res);
+    Ok(res)
+}
+
+/// Runs futures concurrently, with at most `limit` of them running at once.
+///
+/// # Parameters
+/// - `futures`: The futures to run. These are started in order.
+/// - `limit`: The maximum number of futures to run at once. If this is `0`, the futures are
+///   run one at a time.
+///
+/// # Returns
+/// The output of each future, in the order that the futures were given.
+pub(crate) async fn join_limited<F: Future>(
+    futures: impl IntoIterator<Item = F>,
+    limit: usize,
+) -> Vec<F::Output> {
+    let mut pending = futures.into_iter().enumerate().peekable();
+    let mut running: Vec<(usize, Pin<Box<F>>)> = vec![];
+    let mut outputs = vec![];
+
+    poll_fn(|cx| loop {
+        while running.len() < limit.max(1) {
+            match pending.next() {
+                Some((idx, fut)) => running.push((idx, Box::pin(fut))),
+                None => break,
+            }
+        }
+
+        let before = running.len();
+        running.retain_mut(|(idx, fut)| match fut.as_mut().poll(cx) {
+            Poll::Ready(output) => {
+                outputs.push((*idx, output));
+                false
+            }
+            Poll::Pending => true,
+        });
+
+        if running.is_empty() && pending.peek().is_none() {
+            return Poll::Ready(());
+        }
+
+        // Only stop polling once nothing has finished; otherwise, there's room to start more.
+        if running.len() == before {
+            return Poll::Pending;
+        }
+    })
+    .await;
+
+    outputs.sort_by_key(|(idx, _)| *idx);
+    outputs.into_iter().map(|(_, output)| output).collect()
+}
+
 /// Extracts text from the given response, handling the possibility that a bad status code
 /// or a verification error occurs.
 ///
@@ -17,21 +90,18 @@ use crate::{types, util};
 ///
 /// # Returns
 /// The result of processing the response.
-pub(crate) async fn extract_text(res: Result<Response, Error>) -> types::Result<String> {
+pub(crate) fn extract_text(res: types::Result<RawResponse>) -> types::Result<String> {
     let r = res?;
-    let status_code = r.status();
-    if !status_code.is_success() {
-        let text = r.text().await.ok();
-        return Err(WrapperError::BadStatusCode(status_code.as_u16(), text));
+    if !r.is_success() {
+        return Err(WrapperError::BadStatusCode(r.status, Some(r.body)));
     }
 
-    let text = r.text().await?;
-    if text.contains(VERIFY_FAIL_ERR) {
+    if r.body.contains(VERIFY_FAIL_ERR) {
         Err(WrapperError::WebRegError(
             "Verification error: register your term using the `associate_term` function.".into(),
         ))
     } else {
-        Ok(text)
+        Ok(r.body)
     }
 }
 
@@ -46,6 +116,31 @@ pub(crate) fn process_get_text<T: DeserializeOwned>(res: String) -> types::Resul
     serde_json::from_str::<T>(&res).map_err(WrapperError::SerdeError)
 }
 
+/// Processes a GET response from the resulting text representing JSON, if any, first giving
+/// any schema drift to the observer.
+///
+/// # Parameters
+/// - `observer`: The callback given any schema drift, if any.
+/// - `endpoint`: The endpoint that the response came from (e.g., `get_schedule`).
+/// - `res`: The string containing JSON to convert.
+///
+/// # Returns
+/// The result of converting the JSON to the deserialized form.
+pub(crate) fn process_get_text_observed<T: RawSchema>(
+    observer: Option<&SchemaObserver>,
+    endpoint: &'static str,
+    res: String,
+) -> types::Result<Vec<T>> {
+    if let Some(observer) = observer {
+        match check_schema::<T>(endpoint, &res) {
+            Ok(drift) if !drift.is_empty() => observer(&drift),
+            _ => {}
+        }
+    }
+
+    process_get_text(res)
+}
+
 /// Processes a GET response from the resulting JSON, if any. This is equivalent to calling
 /// - `extract_text`, followed by
 /// - `process_get_text`.
@@ -55,34 +150,28 @@ pub(crate) fn process_get_text<T: DeserializeOwned>(res: String) -> types::Resul
 ///
 /// # Returns
 /// The result of processing the response.
-pub(crate) async fn process_get_result<T: DeserializeOwned>(
-    res: Result<Response, Error>,
+pub(crate) fn process_get_result<T: DeserializeOwned>(
+    res: types::Result<RawResponse>,
 ) -> types::Result<T> {
-    let r = extract_text(res).await?;
+    let r = extract_text(res)?;
     process_get_text(r)
 }
 
-/// Processes a POST response from the resulting JSON, if any.
+/// Processes a POST response that was already extracted.
 ///
 /// # Parameters
-/// - `res`: The initial response.
+/// - `res`: The untouched response.
 ///
 /// # Returns
 /// Either one of:
 /// - `true` or `false`, depending on what WebReg returns.
 /// - or some error message if an error occurred.
-pub(crate) async fn process_post_response(res: Result<Response, Error>) -> types::Result<bool> {
-    let r = res?;
-    let status_code = r.status();
-    if !status_code.is_success() {
-        let text = r.text().await.ok();
-        return Err(WrapperError::BadStatusCode(status_code.as_u16(), text));
+pub(crate) fn process_raw_post_response(res: RawResponse) -> types::Result<bool> {
+    if !res.is_success() {
+        return Err(WrapperError::BadStatusCode(res.status, Some(res.body)));
     }
 
-    let text = r.text().await?;
-    // Unwrap should not be a problem since we should be getting a valid JSON response
-    // every time.
-    let json: Value = serde_json::from_str(&text)?;
+    let json: Value = serde_json::from_str(&res.body)?;
     if json["OPS"].is_string() && json["OPS"].as_str().unwrap() == "SUCCESS" {
         return Ok(true);
     }
@@ -130,11 +219,9 @@ pub(crate) async fn associate_term_helper<'a>(
     obj: &'a impl ReqwestWebRegClientData<'a>,
     term: impl AsRef<str>,
 ) -> types::Result<()> {
-    let term = term.as_ref().to_uppercase();
-    let seq_id = get_term_seq_id(&term);
-    if seq_id == 0 {
-        return Err(WrapperError::InputError("term", "term is not valid."));
-    }
+    let term_code = term.as_ref().parse::<TermCode>()?;
+    let term = term_code.to_string();
+    let seq_id = term_code.seq_id();
 
     let seqid_str = seq_id.to_string();
     // Step 1: call get_status_start endpoint
@@ -147,7 +234,9 @@ pub(crate) async fn associate_term_helper<'a>(
         ],
     )?;
 
-    process_get_result::<Value>(obj.req(ReqType::Get(status_start_url)).send().await).await?;
+    let hooks = obj.hooks(STATUS_START, Some(&term), "associate_term");
+    let req = obj.req(ReqType::Get(status_start_url), &hooks)?;
+    process_get_result::<Value>(send(obj.get_transport(), req, &hooks).await)?;
 
     // Step 2: call eligibility endpoint
     let eligibility_url = Url::parse_with_params(
@@ -160,6 +249,8 @@ pub(crate) async fn associate_term_helper<'a>(
         ],
     )?;
 
-    process_get_result::<Value>(obj.req(ReqType::Get(eligibility_url)).send().await).await?;
+    let hooks = obj.hooks(ELIGIBILITY, Some(&term), "associate_term");
+    let req = obj.req(ReqType::Get(eligibility_url), &hooks)?;
+    process_get_result::<Value>(send(obj.get_transport(), req, &hooks).await)?;
     Ok(())
 }
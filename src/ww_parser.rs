@@ -1,15 +1,18 @@
 use std::cmp::max;
 use std::collections::{HashMap, HashSet};
+use std::fmt::Display;
 
 use url::Url;
 
 use crate::constants::*;
 use crate::raw_types::{
-    RawCoursePrerequisite, RawEvent, RawPrerequisite, RawScheduledMeeting, RawWebRegMeeting,
+    RawCoursePrerequisite, RawDepartmentElement, RawEvent, RawPrerequisite, RawScheduledMeeting,
+    RawSubjectElement, RawWebRegMeeting,
 };
 use crate::types::{
-    CoursePrerequisite, CourseSection, Courses, EnrollmentStatus, Event, Events, Meeting,
-    MeetingDay, PrerequisiteInfo, Schedule, ScheduledSection, TimeType, WrapperError,
+    CoursePrerequisite, CourseSection, Courses, Department, EnrollmentStatus, Event, Events,
+    LenientParse, Meeting, MeetingDay, ParseWarning, PrerequisiteInfo, Schedule, ScheduledSection,
+    SectionId, Subject, TimeType, WrapperError,
 };
 use crate::util::parse_binary_days;
 use crate::wrapper::input_types::SearchType;
@@ -48,6 +51,7 @@ pub fn parse_prerequisites(res: Vec<RawPrerequisite>) -> types::Result<Prerequis
             cleaned_reqs.push(CoursePrerequisite {
                 subj_course_id: format!("{} {}", req.subject_code.trim(), req.course_code.trim()),
                 course_title: req.course_title.trim().to_string(),
+                min_grade_seq_id: req.grade_seq_id.trim().parse().ok(),
             });
         }
 
@@ -63,8 +67,36 @@ pub fn parse_prerequisites(res: Vec<RawPrerequisite>) -> types::Result<Prerequis
 /// - `res`: The vector of raw scheduled meeting objects.
 ///
 /// # Returns
-/// Either the parsed schedule information or an error.
+/// Either the parsed schedule information or an error. If any section couldn't be
+/// parsed, the error describes the field and section that had a problem.
 pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule> {
+    parse_schedule_with(res, &mut Diagnostics::strict())
+}
+
+/// Processes the vector containing the raw scheduled meeting objects, skipping any section
+/// that couldn't be parsed.
+///
+/// # Parameters
+/// - `res`: The vector of raw scheduled meeting objects.
+///
+/// # Returns
+/// The parsed schedule information, along with why each skipped section couldn't be parsed.
+pub fn parse_schedule_lenient(res: Vec<RawScheduledMeeting>) -> LenientParse<Schedule> {
+    Diagnostics::lenient(|diag| parse_schedule_with(res, diag))
+}
+
+/// Processes the vector containing the raw scheduled meeting objects.
+///
+/// # Parameters
+/// - `res`: The vector of raw scheduled meeting objects.
+/// - `diag`: Decides what happens to sections that couldn't be parsed.
+///
+/// # Returns
+/// Either the parsed schedule information or an error.
+fn parse_schedule_with(
+    res: Vec<RawScheduledMeeting>,
+    diag: &mut Diagnostics,
+) -> types::Result<Schedule> {
     if res.is_empty() {
         return Ok(vec![]);
     }
@@ -79,10 +111,22 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
             continue;
         }
 
-        if s_meeting.sect_code.as_bytes()[0].is_ascii_digit() {
+        let Some(first_char) = s_meeting.sect_code.chars().next() else {
+            diag.check(
+                Err::<(), _>(field_error(
+                    "sect_code",
+                    "missing section code",
+                    schedule_record(s_meeting),
+                )),
+                scheduled_section_id(&[s_meeting]),
+            )?;
+            continue;
+        };
+
+        if first_char.is_ascii_digit() {
             special_classes
                 .entry(s_meeting.course_title.trim())
-                .or_insert_with(Vec::new)
+                .or_default()
                 .push(s_meeting);
 
             continue;
@@ -90,7 +134,7 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
 
         base_group_secs
             .entry(s_meeting.course_title.trim())
-            .or_insert_with(Vec::new)
+            .or_default()
             .push(s_meeting);
     }
 
@@ -99,233 +143,252 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
     // We next begin processing the general sections. Each key/value pair represents a course
     // section. We do not care about the key; the value is a vector of meetings, which we will
     // clean up.
-    //
-    // Every meeting is separated. For example, if we have a MWF meeting, then there will
-    // be three meeting objects -- one for M, one for W, and one for F.
     for (_, sch_meetings) in base_group_secs {
-        // First, let's get all instructors associated with this course section.
-        let instructors = util::get_all_instructors(
-            sch_meetings
-                .iter()
-                .flat_map(|x| util::get_instructor_names(&x.person_full_name)),
-        );
-
-        // Here, we want to find the main meetings. We note that the main meetings are the
-        // ones which have a section code ending with 00 AND doesn't have a special meeting
-        // associated with it (e.g., it's not a final exam or midterm).
-        let all_main = sch_meetings
-            .iter()
-            .filter(|x| {
-                x.sect_code.ends_with("00")
-                    && x.special_meeting.replace("TBA", "").trim().is_empty()
-            })
-            .collect::<Vec<_>>();
-
-        // This should never be empty, since every section must have a main meeting.
-        assert!(
-            !all_main.is_empty()
-                && all_main
-                    .iter()
-                    .all(|x| x.meeting_type == all_main[0].meeting_type)
-        );
-
-        // We now parse the main meetings.
-        let mut all_meetings: Vec<Meeting> = vec![];
-        for main in all_main {
-            all_meetings.push(Meeting {
-                meeting_type: main.meeting_type.to_string(),
-                meeting_days: if main.day_code.trim().is_empty() {
-                    MeetingDay::None
-                } else {
-                    MeetingDay::Repeated(util::parse_day_code(main.day_code.trim()))
-                },
-                start_min: TimeType::try_from(main.start_time_min)
-                    .map_err(|_| WrapperError::BadTimeError)?,
-                start_hr: TimeType::try_from(main.start_time_hr)
-                    .map_err(|_| WrapperError::BadTimeError)?,
-                end_min: TimeType::try_from(main.end_time_min)
-                    .map_err(|_| WrapperError::BadTimeError)?,
-                end_hr: TimeType::try_from(main.end_time_hr)
-                    .map_err(|_| WrapperError::BadTimeError)?,
-                building: main.bldg_code.trim().to_string(),
-                room: main.room_code.trim().to_string(),
-                instructors: util::get_instructor_names(&main.person_full_name),
-            });
-        }
-
-        // Parse the remaining meetings.
-        // Here, we want to parse any midterm and exam meetings.
-        for meeting in sch_meetings
-            .iter()
-            .filter(|x| {
-                x.sect_code.ends_with("00")
-                    && !x.special_meeting.replace("TBA", "").trim().is_empty()
-            })
-            .map(|x| -> types::Result<Meeting> {
-                Ok(Meeting {
-                    meeting_type: x.meeting_type.to_string(),
-                    meeting_days: MeetingDay::OneTime(x.start_date.to_string()),
-                    start_min: TimeType::try_from(x.start_time_min)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    start_hr: TimeType::try_from(x.start_time_hr)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    end_min: TimeType::try_from(x.end_time_min)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    end_hr: TimeType::try_from(x.end_time_hr)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    building: x.bldg_code.trim().to_string(),
-                    room: x.room_code.trim().to_string(),
-                    instructors: util::get_instructor_names(&x.person_full_name),
-                })
-            })
+        let section_id = scheduled_section_id(&sch_meetings);
+        if let Some(section) =
+            diag.check(parse_general_scheduled_section(&sch_meetings), section_id)?
         {
-            all_meetings.push(meeting?);
+            schedule.push(section);
         }
+    }
 
-        // Finally, we parse the general meetings.
-        for meeting in sch_meetings
-            .iter()
-            .filter(|x| !x.sect_code.ends_with("00"))
-            .map(|x| -> types::Result<Meeting> {
-                Ok(Meeting {
-                    meeting_type: x.meeting_type.to_string(),
-                    meeting_days: MeetingDay::Repeated(util::parse_day_code(&x.day_code)),
-                    start_min: TimeType::try_from(x.start_time_min)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    start_hr: TimeType::try_from(x.start_time_hr)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    end_min: TimeType::try_from(x.end_time_min)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    end_hr: TimeType::try_from(x.end_time_hr)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    building: x.bldg_code.trim().to_string(),
-                    room: x.room_code.trim().to_string(),
-                    instructors: util::get_instructor_names(&x.person_full_name),
-                })
-            })
+    // Now, we look into parsing the special sections. This is trivial to parse.
+    for (_, sch_meetings) in special_classes {
+        let section_id = scheduled_section_id(&sch_meetings);
+        if let Some(section) =
+            diag.check(parse_special_scheduled_section(&sch_meetings), section_id)?
         {
-            all_meetings.push(meeting?);
+            schedule.push(section);
         }
+    }
+
+    Ok(schedule)
+}
+
+/// Processes the meetings of one general section (a section whose section code starts with a
+/// letter, e.g., `A01`) in your schedule.
+///
+/// # Parameters
+/// - `sch_meetings`: All meetings of the section.
+///
+/// # Returns
+/// Either the parsed section or an error.
+fn parse_general_scheduled_section(
+    sch_meetings: &[&RawScheduledMeeting],
+) -> types::Result<ScheduledSection> {
+    let record = || {
+        sch_meetings
+            .first()
+            .map(|m| schedule_record(m))
+            .unwrap_or_else(|| "an empty section".to_owned())
+    };
 
-        // Find the main meeting (the one that you can enroll in). This meeting object has
-        // information like how many people are enrolled, capacity, etc. (the others will not).
-        let main_meeting = sch_meetings
+    // First, let's get all instructors associated with this course section.
+    let instructors = util::get_all_instructors(
+        sch_meetings
             .iter()
-            .find(|m| m.enrolled_count.is_some() && m.section_capacity.is_some());
-
-        match main_meeting {
-            None => {
-                // If we cannot find the meeting, then assume the schedule is deformed and return.
-                return if sch_meetings.is_empty() {
-                    Err(WrapperError::WrapperParsingError(format!(
-                        "{} {} is deformed",
-                        sch_meetings[0].sect_code, sch_meetings[0].course_code
-                    )))
-                } else {
-                    Err(WrapperError::WrapperParsingError(
-                        "schedule is deformed".to_owned(),
-                    ))
-                };
-            }
-            Some(data) => {
-                // At this point, we now want to look for data like section capacity, number of
-                // students on the waitlist, and so on. `data` is the main section that should
-                // have all this data.
-                let enrolled_count = data.enrolled_count.unwrap_or(-1);
-                let section_capacity = data.section_capacity.unwrap_or(-1);
-
-                schedule.push(ScheduledSection {
-                    section_id: data.section_id.to_string(),
-                    all_instructors: instructors.clone(),
-                    subject_code: data.subj_code.trim().to_string(),
-                    course_code: data.course_code.trim().to_string(),
-                    course_title: data.course_title.trim().to_string(),
-                    section_code: match sch_meetings.iter().find(|x| !x.sect_code.ends_with("00")) {
-                        Some(r) => r.sect_code.to_string(),
-                        None => data.sect_code.to_string(),
-                    },
-                    section_capacity,
-                    enrolled_count,
-                    available_seats: max(section_capacity - enrolled_count, 0),
-                    grade_option: data.grade_option.to_string(),
-                    units: data.sect_credit_hrs.trunc() as i64,
-                    enrolled_status: match data.enroll_status.as_str() {
-                        STATUS_ENROLL => EnrollmentStatus::Enrolled,
-                        STATUS_WAITLIST => EnrollmentStatus::Waitlist {
-                            waitlist_pos: data.waitlist_pos.parse().unwrap_or(-1),
-                        },
-                        STATUS_PLANNED => EnrollmentStatus::Planned,
-                        _ => EnrollmentStatus::Unknown,
-                    },
-                    waitlist_ct: data.count_on_waitlist.unwrap_or(0),
-                    meetings: all_meetings,
-                });
-            }
+            .flat_map(|x| util::get_instructor_names(&x.person_full_name)),
+    );
+
+    // Here, we want to find the main meetings. We note that the main meetings are the
+    // ones which have a section code ending with 00 AND doesn't have a special meeting
+    // associated with it (e.g., it's not a final exam or midterm).
+    let all_main = sch_meetings
+        .iter()
+        .filter(|x| {
+            x.sect_code.ends_with("00") && x.special_meeting.replace("TBA", "").trim().is_empty()
+        })
+        .collect::<Vec<_>>();
+
+    // Every section must have a main meeting, and all main meetings should be of the same type.
+    match all_main.first() {
+        None => {
+            return Err(field_error("sect_code", "no main meeting found", record()));
         }
+        Some(first)
+            if all_main
+                .iter()
+                .any(|x| x.meeting_type != first.meeting_type) =>
+        {
+            return Err(field_error(
+                "meeting_type",
+                "main meetings have different meeting types",
+                record(),
+            ));
+        }
+        Some(_) => {}
     }
 
-    // Now, we look into parsing the special sections. This is trivial to parse.
-    // Note: we're making the assumption that these sections have one meeting.
-    for (_, sch_meetings) in special_classes {
-        let day_code = sch_meetings
-            .iter()
-            .map(|x| x.day_code.trim())
-            .collect::<Vec<_>>()
-            .join("");
-
-        let parsed_day_code = if day_code.is_empty() {
-            MeetingDay::None
-        } else {
-            MeetingDay::Repeated(util::parse_day_code(&day_code))
-        };
+    // Every meeting is separated. For example, if we have a MWF meeting, then there will
+    // be three meeting objects -- one for M, one for W, and one for F.
+    //
+    // We now parse the main meetings.
+    let mut all_meetings: Vec<Meeting> = vec![];
+    for main in all_main {
+        all_meetings.push(parse_scheduled_meeting(
+            main,
+            if main.day_code.trim().is_empty() {
+                MeetingDay::None
+            } else {
+                MeetingDay::Repeated(util::parse_day_code(main.day_code.trim()))
+            },
+        )?);
+    }
 
-        let section_capacity = sch_meetings[0].section_capacity.unwrap_or(-1);
-        let enrolled_count = sch_meetings[0].enrolled_count.unwrap_or(-1);
+    // Parse the remaining meetings.
+    // Here, we want to parse any midterm and exam meetings.
+    for meeting in sch_meetings.iter().filter(|x| {
+        x.sect_code.ends_with("00") && !x.special_meeting.replace("TBA", "").trim().is_empty()
+    }) {
+        all_meetings.push(parse_scheduled_meeting(
+            meeting,
+            MeetingDay::OneTime(meeting.start_date.to_string()),
+        )?);
+    }
 
-        schedule.push(ScheduledSection {
-            section_id: sch_meetings[0].section_id.to_string(),
-            all_instructors: util::get_all_instructors(
-                sch_meetings
-                    .iter()
-                    .flat_map(|x| util::get_instructor_names(&x.person_full_name)),
-            ),
-            subject_code: sch_meetings[0].subj_code.trim().to_string(),
-            course_code: sch_meetings[0].course_code.trim().to_string(),
-            course_title: sch_meetings[0].course_title.trim().to_string(),
-            section_code: sch_meetings[0].sect_code.to_string(),
-            section_capacity,
-            enrolled_count,
-            available_seats: max(section_capacity - enrolled_count, 0),
-            grade_option: sch_meetings[0].grade_option.trim().to_string(),
-            units: sch_meetings[0].sect_credit_hrs.trunc() as i64,
-            enrolled_status: match sch_meetings[0].enroll_status.as_str() {
-                STATUS_ENROLL => EnrollmentStatus::Enrolled,
-                STATUS_WAITLIST => EnrollmentStatus::Waitlist {
-                    waitlist_pos: sch_meetings[0].waitlist_pos.parse().unwrap_or(-1),
-                },
-                STATUS_PLANNED => EnrollmentStatus::Planned,
-                _ => EnrollmentStatus::Unknown,
-            },
-            waitlist_ct: sch_meetings[0].count_on_waitlist.unwrap_or(0),
-            meetings: vec![Meeting {
-                meeting_type: sch_meetings[0].meeting_type.to_string(),
-                meeting_days: parsed_day_code,
-                start_min: TimeType::try_from(sch_meetings[0].start_time_min)
-                    .map_err(|_| WrapperError::BadTimeError)?,
-                start_hr: TimeType::try_from(sch_meetings[0].start_time_hr)
-                    .map_err(|_| WrapperError::BadTimeError)?,
-                end_min: TimeType::try_from(sch_meetings[0].end_time_min)
-                    .map_err(|_| WrapperError::BadTimeError)?,
-                end_hr: TimeType::try_from(sch_meetings[0].start_time_hr)
-                    .map_err(|_| WrapperError::BadTimeError)?,
-                building: sch_meetings[0].bldg_code.trim().to_string(),
-                room: sch_meetings[0].room_code.trim().to_string(),
-                instructors: util::get_instructor_names(&sch_meetings[0].person_full_name),
-            }],
-        });
+    // Finally, we parse the general meetings.
+    for meeting in sch_meetings.iter().filter(|x| !x.sect_code.ends_with("00")) {
+        all_meetings.push(parse_scheduled_meeting(
+            meeting,
+            MeetingDay::Repeated(util::parse_day_code(&meeting.day_code)),
+        )?);
     }
 
-    Ok(schedule)
+    // Find the main meeting (the one that you can enroll in). This meeting object has
+    // information like how many people are enrolled, capacity, etc. (the others will not).
+    let data = sch_meetings
+        .iter()
+        .find(|m| m.enrolled_count.is_some() && m.section_capacity.is_some())
+        .ok_or_else(|| {
+            field_error(
+                "enrolled_count",
+                "no meeting has enrollment information",
+                record(),
+            )
+        })?;
+
+    // At this point, we now want to look for data like section capacity, number of
+    // students on the waitlist, and so on. `data` is the main section that should
+    // have all this data.
+    let enrolled_count = data.enrolled_count.unwrap_or(-1);
+    let section_capacity = data.section_capacity.unwrap_or(-1);
+
+    Ok(ScheduledSection {
+        section_id: parse_section_id(data.section_id, || schedule_record(data))?,
+        all_instructors: instructors,
+        subject_code: data.subj_code.trim().to_string(),
+        course_code: data.course_code.trim().to_string(),
+        course_title: data.course_title.trim().to_string(),
+        section_code: match sch_meetings.iter().find(|x| !x.sect_code.ends_with("00")) {
+            Some(r) => r.sect_code.to_string(),
+            None => data.sect_code.to_string(),
+        },
+        section_capacity,
+        enrolled_count,
+        available_seats: max(section_capacity - enrolled_count, 0),
+        grade_option: data.grade_option.to_string(),
+        units: data.sect_credit_hrs.trunc() as i64,
+        enrolled_status: parse_enroll_status(data),
+        waitlist_ct: data.count_on_waitlist.unwrap_or(0),
+        meetings: all_meetings,
+    })
+}
+
+/// Processes the meetings of one special section (a section whose section code is all
+/// numerical digits, e.g., `001`) in your schedule. We're making the assumption that these
+/// sections have one meeting.
+///
+/// # Parameters
+/// - `sch_meetings`: All meetings of the section.
+///
+/// # Returns
+/// Either the parsed section or an error.
+fn parse_special_scheduled_section(
+    sch_meetings: &[&RawScheduledMeeting],
+) -> types::Result<ScheduledSection> {
+    let first = sch_meetings.first().ok_or_else(|| {
+        WrapperError::WrapperParsingError("special section has no meetings".to_owned())
+    })?;
+
+    let day_code = sch_meetings
+        .iter()
+        .map(|x| x.day_code.trim())
+        .collect::<Vec<_>>()
+        .join("");
+
+    let parsed_day_code = if day_code.is_empty() {
+        MeetingDay::None
+    } else {
+        MeetingDay::Repeated(util::parse_day_code(&day_code))
+    };
+
+    let section_capacity = first.section_capacity.unwrap_or(-1);
+    let enrolled_count = first.enrolled_count.unwrap_or(-1);
+
+    Ok(ScheduledSection {
+        section_id: parse_section_id(first.section_id, || schedule_record(first))?,
+        all_instructors: util::get_all_instructors(
+            sch_meetings
+                .iter()
+                .flat_map(|x| util::get_instructor_names(&x.person_full_name)),
+        ),
+        subject_code: first.subj_code.trim().to_string(),
+        course_code: first.course_code.trim().to_string(),
+        course_title: first.course_title.trim().to_string(),
+        section_code: first.sect_code.to_string(),
+        section_capacity,
+        enrolled_count,
+        available_seats: max(section_capacity - enrolled_count, 0),
+        grade_option: first.grade_option.trim().to_string(),
+        units: first.sect_credit_hrs.trunc() as i64,
+        enrolled_status: parse_enroll_status(first),
+        waitlist_ct: first.count_on_waitlist.unwrap_or(0),
+        meetings: vec![parse_scheduled_meeting(first, parsed_day_code)?],
+    })
+}
+
+/// Parses a single meeting in your schedule.
+///
+/// # Parameters
+/// - `meeting`: The raw meeting.
+/// - `meeting_days`: The day(s) that this meeting occurs.
+///
+/// # Returns
+/// Either the parsed meeting or an error if any of the times are invalid.
+fn parse_scheduled_meeting(
+    meeting: &RawScheduledMeeting,
+    meeting_days: MeetingDay,
+) -> types::Result<Meeting> {
+    let record = || schedule_record(meeting);
+    Ok(Meeting {
+        meeting_type: meeting.meeting_type.to_string(),
+        meeting_days,
+        start_min: parse_time(meeting.start_time_min, "start_time_min", record)?,
+        start_hr: parse_time(meeting.start_time_hr, "start_time_hr", record)?,
+        end_min: parse_time(meeting.end_time_min, "end_time_min", record)?,
+        end_hr: parse_time(meeting.end_time_hr, "end_time_hr", record)?,
+        building: meeting.bldg_code.trim().to_string(),
+        room: meeting.room_code.trim().to_string(),
+        instructors: util::get_instructor_names(&meeting.person_full_name),
+    })
+}
+
+/// Parses your enrollment status for a meeting in your schedule.
+///
+/// # Parameters
+/// - `meeting`: The raw meeting.
+///
+/// # Returns
+/// The enrollment status.
+fn parse_enroll_status(meeting: &RawScheduledMeeting) -> EnrollmentStatus {
+    match meeting.enroll_status.as_str() {
+        STATUS_ENROLL => EnrollmentStatus::Enrolled,
+        STATUS_WAITLIST => EnrollmentStatus::Waitlist {
+            waitlist_pos: meeting.waitlist_pos.parse().unwrap_or(-1),
+        },
+        STATUS_PLANNED => EnrollmentStatus::Planned,
+        _ => EnrollmentStatus::Unknown,
+    }
 }
 
 /// Processes the vector containing raw meeting information into enrollment
@@ -342,6 +405,41 @@ pub fn parse_schedule(res: Vec<RawScheduledMeeting>) -> types::Result<Schedule>
 pub fn parse_enrollment_count(
     meetings: Vec<RawWebRegMeeting>,
     subj_num: String,
+) -> types::Result<Courses> {
+    parse_enrollment_count_with(meetings, subj_num, &mut Diagnostics::strict())
+}
+
+/// Processes the vector containing raw meeting information into enrollment
+/// count data, skipping any section that couldn't be parsed.
+///
+/// # Parameters
+/// - `meetings`: The vector of meetings.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+///
+/// # Returns
+/// The parsed enrollment count information, along with why each skipped section
+/// couldn't be parsed.
+pub fn parse_enrollment_count_lenient(
+    meetings: Vec<RawWebRegMeeting>,
+    subj_num: String,
+) -> LenientParse<Courses> {
+    Diagnostics::lenient(|diag| parse_enrollment_count_with(meetings, subj_num, diag))
+}
+
+/// Processes the vector containing raw meeting information into enrollment
+/// count data.
+///
+/// # Parameters
+/// - `meetings`: The vector of meetings.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+/// - `diag`: Decides what happens to sections that couldn't be parsed.
+///
+/// # Returns
+/// Either the parsed enrollment count information or an error.
+fn parse_enrollment_count_with(
+    meetings: Vec<RawWebRegMeeting>,
+    subj_num: String,
+    diag: &mut Diagnostics,
 ) -> types::Result<Courses> {
     if meetings.is_empty() {
         return Ok(vec![]);
@@ -368,23 +466,34 @@ pub fn parse_enrollment_count(
         meetings_to_parse.push(meeting);
     }
 
-    Ok(meetings_to_parse
+    let mut sections = vec![];
+    // Only want available sections, AC = displayed
+    for x in meetings_to_parse
         .into_iter()
-        // Only want available sections, AC = displayed
         .filter(|x| x.display_type == "AC")
-        .map(|x| CourseSection {
-            is_visible: x.is_visible(),
-            subj_course_id: subj_num.to_owned(),
-            section_id: x.section_id.trim().to_string(),
-            section_code: x.sect_code.trim().to_string(),
-            all_instructors: util::get_instructor_names(&x.person_full_name),
-            available_seats: max(x.avail_seat, 0),
-            enrolled_ct: x.enrolled_count,
-            total_seats: x.section_capacity,
-            waitlist_ct: x.count_on_waitlist,
-            meetings: vec![],
-        })
-        .collect())
+    {
+        let section =
+            parse_section_id(&x.section_id, || webreg_record(&subj_num, x)).map(|section_id| {
+                CourseSection {
+                    is_visible: x.is_visible(),
+                    subj_course_id: subj_num.to_owned(),
+                    section_id,
+                    section_code: x.sect_code.trim().to_string(),
+                    all_instructors: util::get_instructor_names(&x.person_full_name),
+                    available_seats: max(x.avail_seat, 0),
+                    enrolled_ct: x.enrolled_count,
+                    total_seats: x.section_capacity,
+                    waitlist_ct: x.count_on_waitlist,
+                    meetings: vec![],
+                }
+            });
+
+        if let Some(section) = diag.check(section, webreg_section_id(x))? {
+            sections.push(section);
+        }
+    }
+
+    Ok(sections)
 }
 
 pub enum CourseInfoType {
@@ -399,7 +508,7 @@ pub enum CourseInfoType {
 /// - `meetings`: The vector of meetings.
 /// - `subj_num`: The subject course number (e.g., `CSE 100`).
 /// - `data_type`: The data type (either "Full" for all data, or "Count"
-///                for partial).
+///   for partial).
 ///
 /// # Returns
 /// Either the parsed course information or an error.
@@ -422,10 +531,46 @@ pub fn parse_course_info_or_enrollment_ct(
 /// - `subj_num`: The subject course number (e.g., `CSE 100`).
 ///
 /// # Returns
-/// Either the parsed course information or an error.
+/// Either the parsed course information or an error. If any section couldn't be
+/// parsed, the error describes the field and section that had a problem.
 pub fn parse_course_info(
     parsed: Vec<RawWebRegMeeting>,
     subj_num: String,
+) -> types::Result<Courses> {
+    parse_course_info_with(parsed, subj_num, &mut Diagnostics::strict())
+}
+
+/// Processes the vector containing raw meeting information into parsed
+/// course information, skipping any section that couldn't be parsed.
+///
+/// # Parameters
+/// - `meetings`: The vector of meetings.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+///
+/// # Returns
+/// The parsed course information, along with why each skipped section couldn't
+/// be parsed.
+pub fn parse_course_info_lenient(
+    parsed: Vec<RawWebRegMeeting>,
+    subj_num: String,
+) -> LenientParse<Courses> {
+    Diagnostics::lenient(|diag| parse_course_info_with(parsed, subj_num, diag))
+}
+
+/// Processes the vector containing raw meeting information into parsed
+/// course information.
+///
+/// # Parameters
+/// - `meetings`: The vector of meetings.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+/// - `diag`: Decides what happens to sections that couldn't be parsed.
+///
+/// # Returns
+/// Either the parsed course information or an error.
+fn parse_course_info_with(
+    parsed: Vec<RawWebRegMeeting>,
+    subj_num: String,
+    diag: &mut Diagnostics,
 ) -> types::Result<Courses> {
     let mut sections: Courses = vec![];
     let mut unprocessed_meetings: Vec<RawWebRegMeeting> = vec![];
@@ -443,36 +588,29 @@ pub fn parse_course_info(
 
         // Next, we check to see if the meeting is a special meeting. To do so, we can just
         // check to make sure the first character in the section code is a digit (e.g. *0*01)
-        if meeting.sect_code.as_bytes()[0].is_ascii_digit() {
-            let (m_type, m_days) = util::parse_meeting_type_date(&meeting);
-            sections.push(CourseSection {
-                is_visible: meeting.is_visible(),
-                subj_course_id: subj_num.to_owned(),
-                section_id: meeting.section_id.trim().to_string(),
-                section_code: meeting.sect_code.trim().to_string(),
-                all_instructors: util::get_instructor_names(&meeting.person_full_name),
-                // Because it turns out that you can have negative available seats.
-                available_seats: max(meeting.avail_seat, 0),
-                enrolled_ct: meeting.enrolled_count,
-                total_seats: meeting.section_capacity,
-                waitlist_ct: meeting.count_on_waitlist,
-                meetings: vec![Meeting {
-                    start_hr: TimeType::try_from(meeting.start_time_hr)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    start_min: TimeType::try_from(meeting.start_time_min)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    end_hr: TimeType::try_from(meeting.end_time_hr)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    end_min: TimeType::try_from(meeting.end_time_min)
-                        .map_err(|_| WrapperError::BadTimeError)?,
-                    meeting_type: m_type.to_string(),
-                    meeting_days: m_days,
-                    building: meeting.bldg_code.trim().to_string(),
-                    room: meeting.room_code.trim().to_string(),
-                    instructors: util::get_instructor_names(&meeting.person_full_name),
-                }],
+        if meeting.sect_code.starts_with(|c: char| c.is_ascii_digit()) {
+            let section = parse_webreg_meeting(&meeting, &subj_num).and_then(|m| {
+                Ok(CourseSection {
+                    is_visible: meeting.is_visible(),
+                    subj_course_id: subj_num.to_owned(),
+                    section_id: parse_section_id(&meeting.section_id, || {
+                        webreg_record(&subj_num, &meeting)
+                    })?,
+                    section_code: meeting.sect_code.trim().to_string(),
+                    all_instructors: util::get_instructor_names(&meeting.person_full_name),
+                    // Because it turns out that you can have negative available seats.
+                    available_seats: max(meeting.avail_seat, 0),
+                    enrolled_ct: meeting.enrolled_count,
+                    total_seats: meeting.section_capacity,
+                    waitlist_ct: meeting.count_on_waitlist,
+                    meetings: vec![m],
+                })
             });
 
+            if let Some(section) = diag.check(section, webreg_section_id(&meeting))? {
+                sections.push(section);
+            }
+
             continue;
         }
 
@@ -504,10 +642,19 @@ pub fn parse_course_info(
     // value will be the corresponding meetings.
     let mut map: HashMap<char, GroupedSection<RawWebRegMeeting>> = HashMap::new();
     for meeting in &unprocessed_meetings {
-        // Get the section family, which *should* exist (i.e., no panic should occur here).
-        let sec_fam = meeting.sect_code.chars().next().ok_or_else(|| {
-            WrapperError::WrapperParsingError("Non-existent section code.".into())
-        })?;
+        // Get the section family, which *should* exist since we skipped any meetings without
+        // a section code above.
+        let Some(sec_fam) = meeting.sect_code.chars().next() else {
+            diag.check(
+                Err::<(), _>(field_error(
+                    "sect_code",
+                    "missing section code",
+                    webreg_record(&subj_num, meeting),
+                )),
+                webreg_section_id(meeting),
+            )?;
+            continue;
+        };
 
         let entry = map.entry(sec_fam).or_insert(GroupedSection {
             child_meetings: vec![],
@@ -549,19 +696,32 @@ pub fn parse_course_info(
 
     // Now that we have all of the meetings, categorizing should be easier.
     for key in keys {
-        // We're making a bold assumption that the `general_meetings` vector will never be
-        // empty. However, we note from various courses that sections will *always* either
-        // have at least ONE meeting with
+        // We note from various courses that sections will *always* either have at least
+        // ONE meeting with
         // - section code X00 (where X is a letter), or         handled here.
         // - 0YY (where Y is a digit)                           handled above.
         // In other words, it's *very* unlikely that we'll see a section where there's no
-        // meeting that meets the above patterns, so we have little to hopefully worry about.
+        // meeting that meets the above patterns, but if we do, then we can't process it.
         let entry = &map[key];
-        if entry.general_meetings.is_empty() {
-            // This should never hit, but sanity check nonetheless.
-            dbg!(&subj_num);
+        let Some(first_general) = entry.general_meetings.first() else {
+            let record = entry
+                .child_meetings
+                .first()
+                .map(|m| webreg_record(&subj_num, m))
+                .unwrap_or_else(|| format!("{subj_num} section family '{key}'"));
+            diag.check(
+                Err::<(), _>(field_error(
+                    "sect_code",
+                    "no lecture or other general meeting found",
+                    record,
+                )),
+                entry
+                    .child_meetings
+                    .first()
+                    .and_then(|m| webreg_section_id(m)),
+            )?;
             continue;
-        }
+        };
 
         // First, get the base instructors. These are all of the instructors for the lectures.
         // Note that, for a majority of courses, there will only be one instructor. However,
@@ -579,26 +739,7 @@ pub fn parse_course_info(
         let process_meetings =
             |from: &[&RawWebRegMeeting], to: &mut Vec<Meeting>| -> types::Result<()> {
                 for meeting in from {
-                    let (m_m_type, m_days) = util::parse_meeting_type_date(meeting);
-
-                    to.push(Meeting {
-                        meeting_type: m_m_type.to_string(),
-                        meeting_days: m_days,
-                        building: meeting.bldg_code.trim().to_string(),
-                        room: meeting.room_code.trim().to_string(),
-                        start_hr: TimeType::try_from(meeting.start_time_hr)
-                            .map_err(|_| WrapperError::BadTimeError)?,
-                        start_min: TimeType::try_from(meeting.start_time_min)
-                            .map_err(|_| WrapperError::BadTimeError)?,
-                        end_hr: TimeType::try_from(meeting.end_time_hr)
-                            .map_err(|_| WrapperError::BadTimeError)?,
-                        end_min: TimeType::try_from(meeting.end_time_min)
-                            .map_err(|_| WrapperError::BadTimeError)?,
-                        // These are instructors specifically assigned to this meeting. For most
-                        // cases, these will be the same instructors assigned to the lecture
-                        // meetings.
-                        instructors: util::get_instructor_names(&meeting.person_full_name),
-                    });
+                    to.push(parse_webreg_meeting(meeting, &subj_num)?);
                 }
 
                 Ok(())
@@ -612,25 +753,33 @@ pub fn parse_course_info(
             // this means that information like enrolled count, waitlist count, and so on will
             // be reflected across both structures accurately, so there's no need to search
             // for one particular meeting.
-            let mut section = CourseSection {
-                is_visible: entry.general_meetings[0].is_visible(),
-                subj_course_id: subj_num.to_owned(),
-                section_id: entry.general_meetings[0].section_id.to_owned(),
-                section_code: entry.general_meetings[0].sect_code.to_owned(),
-                all_instructors: util::get_instructor_names(
-                    &entry.general_meetings[0].person_full_name,
-                ),
-                available_seats: max(entry.general_meetings[0].avail_seat, 0),
-                enrolled_ct: entry.general_meetings[0].enrolled_count,
-                total_seats: entry.general_meetings[0].section_capacity,
-                waitlist_ct: entry.general_meetings[0].count_on_waitlist,
-                meetings: vec![],
-            };
+            let section = parse_section_id(&first_general.section_id, || {
+                webreg_record(&subj_num, first_general)
+            })
+            .and_then(|section_id| {
+                let mut section = CourseSection {
+                    is_visible: first_general.is_visible(),
+                    subj_course_id: subj_num.to_owned(),
+                    section_id,
+                    section_code: first_general.sect_code.to_owned(),
+                    all_instructors: util::get_instructor_names(&first_general.person_full_name),
+                    available_seats: max(first_general.avail_seat, 0),
+                    enrolled_ct: first_general.enrolled_count,
+                    total_seats: first_general.section_capacity,
+                    waitlist_ct: first_general.count_on_waitlist,
+                    meetings: vec![],
+                };
+
+                // Then, iterate through the rest of the general meetings.
+                process_meetings(&entry.general_meetings, &mut section.meetings)?;
+                Ok(section)
+            });
 
-            // Then, iterate through the rest of the general meetings.
-            process_meetings(&entry.general_meetings, &mut section.meetings)?;
             // Finally, add it to the sections.
-            sections.push(section);
+            if let Some(section) = diag.check(section, webreg_section_id(first_general))? {
+                sections.push(section);
+            }
+
             continue;
         }
 
@@ -643,30 +792,72 @@ pub fn parse_course_info(
             instructors.dedup();
 
             // Process the general section info.
-            let mut section = CourseSection {
-                is_visible: c_meeting.is_visible(),
-                subj_course_id: subj_num.to_owned(),
-                section_id: c_meeting.section_id.to_owned(),
-                section_code: c_meeting.sect_code.to_owned(),
-                all_instructors: instructors,
-                available_seats: max(c_meeting.avail_seat, 0),
-                enrolled_ct: c_meeting.enrolled_count,
-                total_seats: c_meeting.section_capacity,
-                waitlist_ct: c_meeting.count_on_waitlist,
-                meetings: vec![],
-            };
+            let section = parse_section_id(&c_meeting.section_id, || {
+                webreg_record(&subj_num, c_meeting)
+            })
+            .and_then(|section_id| {
+                let mut section = CourseSection {
+                    is_visible: c_meeting.is_visible(),
+                    subj_course_id: subj_num.to_owned(),
+                    section_id,
+                    section_code: c_meeting.sect_code.to_owned(),
+                    all_instructors: instructors,
+                    available_seats: max(c_meeting.avail_seat, 0),
+                    enrolled_ct: c_meeting.enrolled_count,
+                    total_seats: c_meeting.section_capacity,
+                    waitlist_ct: c_meeting.count_on_waitlist,
+                    meetings: vec![],
+                };
+
+                // Iterate through the general and child meetings.
+                process_meetings(&entry.general_meetings, &mut section.meetings)?;
+                process_meetings(&[c_meeting], &mut section.meetings)?;
+                Ok(section)
+            });
 
-            // Iterate through the general and child meetings.
-            process_meetings(&entry.general_meetings, &mut section.meetings)?;
-            process_meetings(&[c_meeting], &mut section.meetings)?;
             // Finally, add it to the sections as usual.
-            sections.push(section);
+            if let Some(section) = diag.check(section, webreg_section_id(c_meeting))? {
+                sections.push(section);
+            }
         }
     }
 
     Ok(sections)
 }
 
+/// Parses a single meeting of a course.
+///
+/// # Parameters
+/// - `meeting`: The raw meeting.
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+///
+/// # Returns
+/// Either the parsed meeting or an error if the meeting's type, days, or times are invalid.
+fn parse_webreg_meeting(meeting: &RawWebRegMeeting, subj_num: &str) -> types::Result<Meeting> {
+    let record = || webreg_record(subj_num, meeting);
+    let (m_type, m_days) = util::parse_meeting_type_date(meeting).map_err(|e| match e {
+        WrapperError::WrapperParsingError(msg) => {
+            WrapperError::WrapperParsingError(format!("{msg} (in {})", record()))
+        }
+        e => e,
+    })?;
+
+    Ok(Meeting {
+        meeting_type: m_type.to_string(),
+        meeting_days: m_days,
+        building: meeting.bldg_code.trim().to_string(),
+        room: meeting.room_code.trim().to_string(),
+        start_hr: parse_time(meeting.start_time_hr, "start_time_hr", record)?,
+        start_min: parse_time(meeting.start_time_min, "start_time_min", record)?,
+        end_hr: parse_time(meeting.end_time_hr, "end_time_hr", record)?,
+        end_min: parse_time(meeting.end_time_min, "end_time_min", record)?,
+        // These are instructors specifically assigned to this meeting. For most
+        // cases, these will be the same instructors assigned to the lecture
+        // meetings.
+        instructors: util::get_instructor_names(&meeting.person_full_name),
+    })
+}
+
 /// Builds the URL that can be used to search courses on WebReg.
 ///
 /// # Parameters
@@ -679,12 +870,20 @@ pub(crate) fn build_search_course_url(filter_by: SearchType, term: &str) -> type
     Ok(match filter_by {
         SearchType::BySection(section) => Url::parse_with_params(
             WEBREG_SEARCH_SEC,
-            &[("sectionid", section), ("termcode", term.into())],
+            &[("sectionid", section.padded().as_str()), ("termcode", term)],
         )?,
         SearchType::ByMultipleSections(sections) => Url::parse_with_params(
             WEBREG_SEARCH_SEC,
             &[
-                ("sectionid", sections.join(":").as_str()),
+                (
+                    "sectionid",
+                    sections
+                        .iter()
+                        .map(|s| s.padded())
+                        .collect::<Vec<_>>()
+                        .join(":")
+                        .as_str(),
+                ),
                 ("termcode", term),
             ],
         )?,
@@ -699,7 +898,14 @@ pub(crate) fn build_search_course_url(filter_by: SearchType, term: &str) -> type
             let course_code = if request_filter.courses.is_empty() {
                 "".to_string()
             } else {
-                util::format_multiple_courses(&request_filter.courses)
+                // Courses are separated by ';'
+                request_filter
+                    .courses
+                    .iter()
+                    .map(|course| course.to_wire())
+                    .filter(|course| !course.is_empty())
+                    .collect::<Vec<_>>()
+                    .join(";")
             };
 
             let department = if request_filter.departments.is_empty() {
@@ -796,32 +1002,312 @@ pub(crate) fn build_search_course_url(filter_by: SearchType, term: &str) -> type
 /// - `raw_events`: The raw events.
 ///
 /// # Returns
-/// The parsed events.
-pub(crate) fn parse_get_events(raw_events: Vec<RawEvent>) -> types::Result<Events> {
+/// Either the parsed events or an error. If any event couldn't be parsed, the error
+/// describes the field and event that had a problem.
+pub fn parse_get_events(raw_events: Vec<RawEvent>) -> types::Result<Events> {
+    parse_get_events_with(raw_events, &mut Diagnostics::strict())
+}
+
+/// Parses events from the vector of raw events, skipping any event that couldn't be parsed.
+///
+/// # Parameters
+/// - `raw_events`: The raw events.
+///
+/// # Returns
+/// The parsed events, along with why each skipped event couldn't be parsed.
+pub fn parse_get_events_lenient(raw_events: Vec<RawEvent>) -> LenientParse<Events> {
+    Diagnostics::lenient(|diag| parse_get_events_with(raw_events, diag))
+}
+
+/// Parses events from the vector of raw events.
+///
+/// # Parameters
+/// - `raw_events`: The raw events.
+/// - `diag`: Decides what happens to events that couldn't be parsed.
+///
+/// # Returns
+/// Either the parsed events or an error.
+fn parse_get_events_with(
+    raw_events: Vec<RawEvent>,
+    diag: &mut Diagnostics,
+) -> types::Result<Events> {
     let mut res = vec![];
     for event in raw_events {
-        let start_chars = event.start_time.chars().collect::<Vec<_>>();
-        let start_hr = start_chars[0].to_digit(10).unwrap_or_default()
-            * start_chars[1].to_digit(10).unwrap_or_default();
-        let start_min = start_chars[2].to_digit(10).unwrap_or_default()
-            * start_chars[3].to_digit(10).unwrap_or_default();
-        let end_chars = event.end_time.chars().collect::<Vec<_>>();
-        let end_hr = end_chars[0].to_digit(10).unwrap_or_default()
-            * end_chars[1].to_digit(10).unwrap_or_default();
-        let end_min = end_chars[2].to_digit(10).unwrap_or_default()
-            * end_chars[3].to_digit(10).unwrap_or_default();
-
-        res.push(Event {
-            location: event.location,
-            start_hr,
-            start_min,
-            end_hr,
-            end_min,
-            name: event.description,
-            days: parse_binary_days(&event.days),
-            timestamp: event.time_stamp,
-        });
+        if let Some(event) = diag.check(parse_event(event), None)? {
+            res.push(event);
+        }
     }
 
     Ok(res)
 }
+
+/// Parses a single event.
+///
+/// # Parameters
+/// - `event`: The raw event.
+///
+/// # Returns
+/// Either the parsed event or an error if the event's times or days are invalid.
+fn parse_event(event: RawEvent) -> types::Result<Event> {
+    let record = || format!("event '{}' ({})", event.description, event.time_stamp);
+    let (start_hr, start_min) = parse_event_time(&event.start_time, "start_time", record)?;
+    let (end_hr, end_min) = parse_event_time(&event.end_time, "end_time", record)?;
+    if event.days.chars().count() != 7 || !event.days.chars().all(|c| c == '0' || c == '1') {
+        return Err(field_error(
+            "days",
+            format!("expected 7 binary digits, got '{}'", event.days),
+            record(),
+        ));
+    }
+
+    let days = parse_binary_days(&event.days);
+    Ok(Event {
+        location: event.location,
+        start_hr,
+        start_min,
+        end_hr,
+        end_min,
+        name: event.description,
+        days,
+        timestamp: event.time_stamp,
+    })
+}
+
+/// Parses an event time given by WebReg.
+///
+/// # Parameters
+/// - `time`: The time, in the form `HHMM` (e.g., `0930`).
+/// - `field`: The name of the field that the time came from.
+/// - `record`: Describes the event that the time came from.
+///
+/// # Returns
+/// Either the hour and minute, or an error if the time isn't four digits.
+fn parse_event_time(
+    time: &str,
+    field: &str,
+    record: impl FnOnce() -> String,
+) -> types::Result<(TimeType, TimeType)> {
+    let digits = time
+        .chars()
+        .map(|c| c.to_digit(10))
+        .collect::<Option<Vec<_>>>()
+        .filter(|d| d.len() == 4)
+        .ok_or_else(|| field_error(field, format!("expected HHMM, got '{time}'"), record()))?;
+
+    Ok((digits[0] * 10 + digits[1], digits[2] * 10 + digits[3]))
+}
+
+/// Processes the vector containing the raw subject objects.
+///
+/// # Parameters
+/// - `res`: The vector of raw subject objects.
+///
+/// # Returns
+/// The subjects, with their codes and descriptions trimmed.
+pub fn parse_subjects(res: Vec<RawSubjectElement>) -> Vec<Subject> {
+    res.into_iter()
+        .map(|s| Subject {
+            code: s.subject_code.trim().to_string(),
+            description: s.long_desc.trim().to_string(),
+        })
+        .collect()
+}
+
+/// Processes the vector containing the raw department objects.
+///
+/// # Parameters
+/// - `res`: The vector of raw department objects.
+///
+/// # Returns
+/// The departments, with their codes and descriptions trimmed.
+pub fn parse_departments(res: Vec<RawDepartmentElement>) -> Vec<Department> {
+    res.into_iter()
+        .map(|d| Department {
+            code: d.dep_code.trim().to_string(),
+            description: d.dep_desc.trim().to_string(),
+        })
+        .collect()
+}
+
+/// Parses a section ID given by WebReg.
+///
+/// # Parameters
+/// - `section_id`: The section ID, either as a string (e.g., `079911`) or a number.
+/// - `record`: Describes the record that the section ID came from.
+///
+/// # Returns
+/// The section ID, or a parsing error if WebReg gave an invalid section ID.
+fn parse_section_id<T>(section_id: T, record: impl FnOnce() -> String) -> types::Result<SectionId>
+where
+    T: TryInto<SectionId, Error = WrapperError> + Display + Copy,
+{
+    section_id.try_into().map_err(|_| {
+        field_error(
+            "section_id",
+            format!("invalid section ID '{section_id}'"),
+            record(),
+        )
+    })
+}
+
+/// Parses a meeting time given by WebReg.
+///
+/// # Parameters
+/// - `value`: The hour or minute.
+/// - `field`: The name of the field that the time came from.
+/// - `record`: Describes the record that the time came from.
+///
+/// # Returns
+/// The time, or a parsing error if WebReg gave a negative time.
+fn parse_time(value: i16, field: &str, record: impl FnOnce() -> String) -> types::Result<TimeType> {
+    TimeType::try_from(value)
+        .map_err(|_| field_error(field, format!("invalid time '{value}'"), record()))
+}
+
+/// Creates a parsing error describing what went wrong, and where.
+///
+/// # Parameters
+/// - `field`: The name of the field that couldn't be parsed.
+/// - `problem`: What was wrong with the field.
+/// - `record`: Describes the record (e.g., section) that the field came from.
+///
+/// # Returns
+/// The parsing error.
+fn field_error(field: &str, problem: impl Display, record: impl Display) -> WrapperError {
+    WrapperError::WrapperParsingError(format!("{field}: {problem} (in {record})"))
+}
+
+/// Describes a meeting from a course's search results, for use in error messages.
+///
+/// # Parameters
+/// - `subj_num`: The subject course number (e.g., `CSE 100`).
+/// - `meeting`: The raw meeting.
+///
+/// # Returns
+/// The description, e.g., `CSE 100 A01 (section 079912)`.
+fn webreg_record(subj_num: &str, meeting: &RawWebRegMeeting) -> String {
+    format!(
+        "{subj_num} {} (section {})",
+        meeting.sect_code.trim(),
+        meeting.section_id.trim()
+    )
+}
+
+/// Gets the section that a meeting from a course's search results belongs to.
+///
+/// # Parameters
+/// - `meeting`: The raw meeting.
+///
+/// # Returns
+/// The section ID, or `None` if WebReg gave an invalid one.
+fn webreg_section_id(meeting: &RawWebRegMeeting) -> Option<SectionId> {
+    SectionId::try_from(meeting.section_id.trim()).ok()
+}
+
+/// Gets the section that the meetings of one section in your schedule belong to.
+///
+/// # Parameters
+/// - `sch_meetings`: The meetings of the section.
+///
+/// # Returns
+/// The section ID, or `None` if there are no meetings or WebReg gave an invalid one.
+fn scheduled_section_id(sch_meetings: &[&RawScheduledMeeting]) -> Option<SectionId> {
+    sch_meetings
+        .first()
+        .and_then(|m| SectionId::try_from(m.section_id).ok())
+}
+
+/// Describes a meeting from your schedule, for use in error messages.
+///
+/// # Parameters
+/// - `meeting`: The raw meeting.
+///
+/// # Returns
+/// The description, e.g., `CSE 100 A01 (section 079912)`.
+fn schedule_record(meeting: &RawScheduledMeeting) -> String {
+    format!(
+        "{} {} {} (section {})",
+        meeting.subj_code.trim(),
+        meeting.course_code.trim(),
+        meeting.sect_code.trim(),
+        meeting.section_id
+    )
+}
+
+/// Decides what happens to records (e.g., sections or events) that couldn't be parsed.
+struct Diagnostics {
+    /// Whether bad records should be skipped instead of failing the entire parse.
+    lenient: bool,
+    /// Why each skipped record couldn't be parsed.
+    warnings: Vec<ParseWarning>,
+}
+
+impl Diagnostics {
+    /// Creates diagnostics that fail the entire parse on the first bad record.
+    ///
+    /// # Returns
+    /// The diagnostics.
+    fn strict() -> Self {
+        Self {
+            lenient: false,
+            warnings: vec![],
+        }
+    }
+
+    /// Runs a parse that skips bad records.
+    ///
+    /// # Parameters
+    /// - `parse`: The parse to run.
+    ///
+    /// # Returns
+    /// The parsed result, along with why each skipped record couldn't be parsed. If the
+    /// parse failed entirely, the result is empty and the failure is the only warning.
+    fn lenient<T: Default>(
+        parse: impl FnOnce(&mut Diagnostics) -> types::Result<T>,
+    ) -> LenientParse<T> {
+        let mut diag = Self {
+            lenient: true,
+            warnings: vec![],
+        };
+
+        let parsed = match parse(&mut diag) {
+            Ok(parsed) => parsed,
+            Err(error) => {
+                diag.warnings.push(ParseWarning {
+                    section_id: None,
+                    error,
+                });
+                T::default()
+            }
+        };
+
+        LenientParse {
+            parsed,
+            warnings: diag.warnings,
+        }
+    }
+
+    /// Checks the result of parsing a single record.
+    ///
+    /// # Parameters
+    /// - `res`: The result.
+    /// - `section_id`: The section that the record belongs to, if known.
+    ///
+    /// # Returns
+    /// The parsed record, `None` if the record should be skipped, or an error if the
+    /// entire parse should fail.
+    fn check<T>(
+        &mut self,
+        res: types::Result<T>,
+        section_id: Option<SectionId>,
+    ) -> types::Result<Option<T>> {
+        match res {
+            Ok(t) => Ok(Some(t)),
+            Err(error) if self.lenient => {
+                self.warnings.push(ParseWarning { section_id, error });
+                Ok(None)
+            }
+            Err(e) => Err(e),
+        }
+    }
+}
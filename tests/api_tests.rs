@@ -0,0 +1,192 @@
+use std::sync::Arc;
+
+use webweg::types::{
+    self, CourseId, CourseSection, EnrollmentStatus, SectionId, SectionIdNotFoundContext,
+    WrapperError,
+};
+use webweg::wrapper::api::{InMemoryWebReg, WebRegApi};
+use webweg::wrapper::input_types::{
+    AddType, DayOfWeek, EnrollWaitAdd, EventAdd, ExplicitAddType, GradeOption, PlanAdd,
+};
+use webweg::wrapper::transport::InMemoryTransport;
+
+mod common;
+
+fn section(id: u32, code: &str, available_seats: i64) -> CourseSection {
+    CourseSection {
+        subj_course_id: "CSE 100".into(),
+        section_id: SectionId::new(id),
+        section_code: code.into(),
+        all_instructors: vec!["Doe, John".into()],
+        available_seats,
+        enrolled_ct: 30 - available_seats,
+        total_seats: 30,
+        waitlist_ct: 0,
+        meetings: vec![],
+        is_visible: true,
+    }
+}
+
+fn fake() -> InMemoryWebReg {
+    InMemoryWebReg::new().with_course(
+        "CSE",
+        "100",
+        vec![section(79913, "A01", 1), section(79914, "A02", 0)],
+    )
+}
+
+/// Enrolls in a section if there's a seat, and waitlists it otherwise. Written against the
+/// trait so that it can be used with both the real requester and the fake.
+async fn enroll_or_waitlist(api: &impl WebRegApi, section_id: &str) -> types::Result<bool> {
+    let enroll = EnrollWaitAdd::builder()
+        .with_section_id(section_id)
+        .with_grading_option(GradeOption::P)
+        .try_build()
+        .unwrap();
+    api.add_section(AddType::DecideForMe, enroll, true).await
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_fake_course_info() {
+    let api = fake();
+    assert_eq!(2, api.get_course_info(("cse", " 100")).await.unwrap().len());
+    assert!(api
+        .get_enrollment_count(("CSE", "101"))
+        .await
+        .unwrap()
+        .is_empty());
+
+    let id: CourseId = "CSE 100".parse().unwrap();
+    assert_eq!(2, api.get_course_info(&id).await.unwrap().len());
+
+    let prereqs = api.get_prerequisites(id).await.unwrap();
+    assert!(prereqs.course_prerequisites.is_empty());
+    assert!(prereqs.exam_prerequisites.is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_fake_enroll_and_drop() {
+    let api = fake();
+    assert!(enroll_or_waitlist(&api, "79913").await.unwrap());
+    assert!(enroll_or_waitlist(&api, "79914").await.unwrap());
+    assert!(enroll_or_waitlist(&api, "79913").await.is_err());
+
+    let schedule = api.get_schedule(None).await.unwrap();
+    assert_eq!(2, schedule.len());
+    assert_eq!(EnrollmentStatus::Enrolled, schedule[0].enrolled_status);
+    assert_eq!("P", schedule[0].grade_option);
+    assert_eq!(
+        EnrollmentStatus::Waitlist { waitlist_pos: 1 },
+        schedule[1].enrolled_status
+    );
+
+    let courses = api.get_enrollment_count(("CSE", "100")).await.unwrap();
+    assert_eq!(0, courses[0].available_seats);
+    assert_eq!(1, courses[1].waitlist_ct);
+
+    assert!(api
+        .drop_section(ExplicitAddType::Enroll, SectionId::new(79913))
+        .await
+        .unwrap());
+    assert!(matches!(
+        api.drop_section(ExplicitAddType::Enroll, SectionId::new(79914))
+            .await,
+        Err(WrapperError::SectionIdNotFound(
+            _,
+            SectionIdNotFoundContext::Schedule
+        ))
+    ));
+
+    let courses = api.get_enrollment_count(("CSE", "100")).await.unwrap();
+    assert_eq!(1, courses[0].available_seats);
+    assert_eq!(1, api.get_schedule(None).await.unwrap().len());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_fake_plan() {
+    let api = fake();
+    let plan = |id: &'static str| {
+        PlanAdd::builder()
+            .with_subject_code("CSE")
+            .with_course_code("100")
+            .with_section_id(id)
+            .with_section_code("A01")
+            .with_schedule_name("Backup")
+            .with_unit_count(4)
+            .try_build()
+            .unwrap()
+    };
+
+    assert!(api.add_to_plan(plan("79913"), true).await.unwrap());
+    assert!(matches!(
+        api.add_to_plan(plan("12345"), true).await,
+        Err(WrapperError::SectionIdNotFound(
+            _,
+            SectionIdNotFoundContext::Catalog
+        ))
+    ));
+
+    let backup = api.get_schedule(Some("Backup")).await.unwrap();
+    assert_eq!(1, backup.len());
+    assert_eq!(EnrollmentStatus::Planned, backup[0].enrolled_status);
+    assert!(api.get_schedule(None).await.unwrap().is_empty());
+
+    // Enrolling in a planned section removes it from the plan.
+    assert!(enroll_or_waitlist(&api, "79913").await.unwrap());
+    assert!(api.get_schedule(Some("Backup")).await.unwrap().is_empty());
+    assert!(api
+        .remove_from_plan(SectionId::new(79913), Some("Backup"))
+        .await
+        .is_err());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_fake_events() {
+    let api = InMemoryWebReg::new();
+    let event = |name: &'static str| {
+        EventAdd::builder()
+            .with_name(name)
+            .with_day(DayOfWeek::Monday)
+            .with_start_time(10, 0)
+            .with_end_time(11, 0)
+            .try_build()
+            .unwrap()
+    };
+
+    assert!(api.add_or_edit_event(event("Gym"), None).await.unwrap());
+    let events = api.get_events().await.unwrap();
+    assert_eq!(1, events.len());
+    assert_eq!(vec!["M"], events[0].days);
+
+    let timestamp = events[0].timestamp.clone();
+    assert!(api
+        .add_or_edit_event(event("Study"), Some(&timestamp))
+        .await
+        .unwrap());
+    let events = api.get_events().await.unwrap();
+    assert_eq!(1, events.len());
+    assert_eq!("Study", events[0].name);
+
+    assert!(api.remove_event(&timestamp).await.unwrap());
+    assert!(api.remove_event(&timestamp).await.is_err());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_fake_failure() {
+    let api = fake().with_failure("add_section", "You have a hold on your account.");
+    assert!(matches!(
+        enroll_or_waitlist(&api, "79913").await,
+        Err(WrapperError::WebRegError(ref e)) if e == "You have a hold on your account."
+    ));
+    assert!(api.get_schedule(None).await.unwrap().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_requester_implements_api() {
+    let transport = Arc::new(InMemoryTransport::new());
+    let wrapper = common::wrapper(&transport);
+
+    let term = wrapper.req("FA23").parsed();
+    assert!(enroll_or_waitlist(&term, "79913").await.is_err());
+    assert!(!transport.requests().is_empty());
+}
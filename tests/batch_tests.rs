@@ -0,0 +1,237 @@
+use std::sync::Arc;
+
+use webweg::types::{SectionId, SectionIdNotFoundContext, WrapperError};
+use webweg::wrapper::input_types::ExplicitAddType;
+use webweg::wrapper::transport::InMemoryTransport;
+
+mod common;
+
+use common::{ok, query_param, status, wrapper};
+
+/// Creates a transport that responds to course information requests based on the subject
+/// code, and to section ID searches based on the section IDs.
+///
+/// # Parameters
+/// - `broken_a03`: Whether the MATH 20C A03 section (254672) has a malformed meeting.
+fn catalog(broken_a03: bool) -> Arc<InMemoryTransport> {
+    let transport = InMemoryTransport::new()
+        .with_handler("secure/search-by-sectionid", |req| {
+            let ids = query_param(req, "sectionid");
+            let mut items = vec![];
+            if ids.contains("260739") {
+                items.push(r#"{"UNIT_TO": 4.0, "SUBJ_CODE": "CSE ", "CRSE_TITLE": "Algorithms", "UNIT_FROM": 4.0, "CRSE_CODE": "101"}"#);
+            }
+            if ids.contains("100000") {
+                items.push(r#"{"UNIT_TO": 4.0, "SUBJ_CODE": "COGS", "CRSE_TITLE": "Data Science", "UNIT_FROM": 4.0, "CRSE_CODE": "108"}"#);
+            }
+            if ["142034", "254672", "142099"]
+                .iter()
+                .any(|id| ids.contains(id))
+            {
+                items.push(r#"{"UNIT_TO": 4.0, "SUBJ_CODE": "MATH", "CRSE_TITLE": "Calculus", "UNIT_FROM": 4.0, "CRSE_CODE": " 20C"}"#);
+            }
+
+            ok(format!("[{}]", items.join(",")))
+        })
+        .with_handler("secure/search-load-group-data", move |req| {
+            match query_param(req, "subjcode").as_str() {
+                "CSE" => ok(include_str!("json/courseinfo1.json")),
+                "MATH" if broken_a03 => {
+                    let body = include_str!("json/courseinfo3.json");
+                    let (head, tail) = body.split_at(body.find("254672").unwrap());
+                    ok(head.to_owned()
+                        + &tail.replacen(r#""DAY_CODE": "2""#, r#""DAY_CODE": "9X""#, 1))
+                }
+                "MATH" => ok(include_str!("json/courseinfo3.json")),
+                _ => status(500, ""),
+            }
+        });
+
+    Arc::new(transport)
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_course_info_many() {
+    let catalog = catalog(false);
+    let wrapper = wrapper(&catalog);
+    let courses = [
+        ("CSE", "101"),
+        ("math", " 20c"),
+        ("COGS", "108"),
+        ("ECE", "45"),
+        ("cse", "101"),
+    ];
+
+    let res = wrapper
+        .req("FA23")
+        .parsed()
+        .get_course_info_many(courses, 2)
+        .await;
+
+    assert_eq!(
+        vec!["COGS 108", "CSE 101", "ECE 45", "MATH 20C"],
+        res.keys().collect::<Vec<_>>()
+    );
+    assert!(!res["CSE 101"].as_ref().unwrap().is_empty());
+    assert!(!res["MATH 20C"].as_ref().unwrap().is_empty());
+    assert!(matches!(
+        res["COGS 108"],
+        Err(WrapperError::BadStatusCode(500, _))
+    ));
+
+    // Duplicate courses are only requested once.
+    assert_eq!(4, catalog.requests().len());
+    assert_eq!(2, catalog.max_in_flight());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_enrollment_count_many_sequential() {
+    let catalog = catalog(false);
+    let wrapper = wrapper(&catalog);
+
+    let res = wrapper
+        .req("FA23")
+        .parsed()
+        .get_enrollment_count_many([("CSE", "101"), ("MATH", "20C"), ("COGS", "108")], 0)
+        .await;
+
+    assert_eq!(3, res.len());
+    assert!(res["CSE 101"].is_ok());
+    assert!(res["COGS 108"].is_err());
+    assert_eq!(1, catalog.max_in_flight());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_sections_by_id() {
+    let catalog = catalog(false);
+    let wrapper = wrapper(&catalog);
+    let term = wrapper.req("FA23").parsed();
+
+    let sections = term
+        .get_sections_by_id(&[
+            SectionId::new(260739),
+            SectionId::new(142034),
+            SectionId::new(999999),
+            SectionId::new(142034),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(3, sections.len());
+    assert_eq!(
+        "CSE 101",
+        sections[&SectionId::new(260739)]
+            .as_ref()
+            .unwrap()
+            .subj_course_id
+    );
+    assert_eq!(
+        "MATH 20C",
+        sections[&SectionId::new(142034)]
+            .as_ref()
+            .unwrap()
+            .subj_course_id
+    );
+    assert!(matches!(
+        sections[&SectionId::new(999999)],
+        Err(WrapperError::SectionIdNotFound(
+            _,
+            SectionIdNotFoundContext::Catalog
+        ))
+    ));
+    // One search, and one request per course.
+    assert_eq!(3, catalog.requests().len());
+
+    assert!(term.get_sections_by_id(&[]).await.unwrap().is_empty());
+    assert_eq!(3, catalog.requests().len());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_sections_by_id_keeps_partial_failures() {
+    let catalog = catalog(true);
+    let wrapper = wrapper(&catalog);
+    let term = wrapper.req("FA23").parsed();
+
+    let sections = term
+        .get_sections_by_id(&[
+            SectionId::new(260739),
+            SectionId::new(142034),
+            SectionId::new(254672),
+            SectionId::new(100000),
+        ])
+        .await
+        .unwrap();
+
+    assert_eq!(4, sections.len());
+    assert!(sections[&SectionId::new(260739)].is_ok());
+    // The malformed A03 section doesn't stop the rest of MATH 20C from being found.
+    assert_eq!(
+        "A01",
+        sections[&SectionId::new(142034)]
+            .as_ref()
+            .unwrap()
+            .section_code
+    );
+    assert!(matches!(
+        sections[&SectionId::new(254672)],
+        Err(WrapperError::WrapperParsingError(ref msg)) if msg.contains("254672")
+    ));
+    // COGS 108 couldn't be retrieved at all, so its section gets that error.
+    assert!(matches!(
+        sections[&SectionId::new(100000)],
+        Err(WrapperError::BadStatusCode(500, _))
+    ));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_get_section() {
+    let catalog = catalog(false);
+    let wrapper = wrapper(&catalog);
+    let term = wrapper.req("FA23").parsed();
+
+    let section = term.get_section("142034").await.unwrap();
+    assert_eq!(SectionId::new(142034), section.section_id);
+    assert_eq!("MATH 20C", section.subj_course_id);
+    assert_eq!(
+        ExplicitAddType::Enroll,
+        term.get_add_type("142034").await.unwrap()
+    );
+
+    // Not offered this term, or offered by a course that doesn't list it.
+    for id in ["999999", "142099"] {
+        assert!(matches!(
+            term.get_section(id).await,
+            Err(WrapperError::SectionIdNotFound(
+                _,
+                SectionIdNotFoundContext::Catalog
+            ))
+        ));
+    }
+
+    // The reason that the section ID is invalid is kept.
+    assert!(matches!(
+        term.get_section("not a section").await,
+        Err(WrapperError::InputError("section_id", msg)) if msg.contains("only contain digits")
+    ));
+    assert!(matches!(
+        term.get_section(-1_i64).await,
+        Err(WrapperError::InputError("section_id", msg)) if msg.contains("out of range")
+    ));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_get_section_ignores_other_malformed_sections() {
+    let catalog = catalog(true);
+    let wrapper = wrapper(&catalog);
+    let term = wrapper.req("FA23").parsed();
+
+    let section = term.get_section("142034").await.unwrap();
+    assert_eq!("A01", section.section_code);
+    assert_eq!(
+        ExplicitAddType::Enroll,
+        term.get_add_type("142034").await.unwrap()
+    );
+
+    let err = term.get_section("254672").await.unwrap_err();
+    assert!(matches!(err, WrapperError::WrapperParsingError(ref msg) if msg.contains("254672")));
+}
@@ -1,7 +1,10 @@
 use reqwest::Client;
+use webweg::raw_types::RawScheduledMeeting;
+use webweg::types::{Event, SectionId, WrapperError};
 use webweg::wrapper::input_types::{DayOfWeek, EnrollWaitAdd, EventAdd, GradeOption, PlanAdd};
 use webweg::wrapper::wrapper_builder::WebRegWrapperBuilder;
 use webweg::wrapper::WebRegWrapper;
+use webweg::ww_parser::parse_schedule;
 
 #[test]
 fn fail_construct_wrapper() {
@@ -32,25 +35,22 @@ fn success_override_cookies() {
 }
 
 #[test]
-#[should_panic]
-fn fail_override_cookies() {
+fn success_override_cookies_keep_alive() {
     let wrapper = WebRegWrapper::builder()
         .with_cookies("ABC")
         // Not specifying close_after_request
         .try_build_wrapper()
         .unwrap();
 
-    wrapper
-        .req("FA23")
-        // This should panic
-        .override_cookies("abc")
-        .parsed();
+    // This test should pass if nothing panics
+    wrapper.req("FA23").override_cookies("abc").parsed();
+    wrapper.req("FA23").override_cookies("def").raw();
 }
 
 #[test]
 fn success_construct_plan_add() {
     let plan_add = PlanAdd::builder()
-        .with_section_id("my section id")
+        .with_section_id("079911")
         .with_unit_count(41)
         .with_grading_option(GradeOption::P)
         .with_subject_code("CSE")
@@ -60,7 +60,8 @@ fn success_construct_plan_add() {
         .try_build()
         .unwrap();
 
-    assert_eq!(plan_add.section_id, "my section id");
+    assert_eq!(plan_add.section_id, "79911");
+    assert_eq!(plan_add.section_id.to_string(), "079911");
     assert_eq!(plan_add.unit_count, 41);
     assert!(matches!(plan_add.grading_option, Some(GradeOption::P)));
     assert_eq!(plan_add.subject_code, "CSE");
@@ -73,7 +74,7 @@ fn success_construct_plan_add() {
 fn fail_construct_plan_add() {
     // No unit count
     let plan_add = PlanAdd::builder()
-        .with_section_id("my section id")
+        .with_section_id("079911")
         .with_grading_option(GradeOption::P)
         .with_subject_code("CSE")
         .with_course_code("291")
@@ -83,6 +84,44 @@ fn fail_construct_plan_add() {
     assert!(plan_add.is_none());
 }
 
+#[test]
+fn success_construct_plan_add_from_schedule() {
+    let schedule = include_str!("json/schedule2.json");
+    let raw_schedule = serde_json::from_str::<Vec<RawScheduledMeeting>>(schedule).unwrap();
+    let schedule = parse_schedule(raw_schedule).unwrap();
+
+    let plan_add = PlanAdd::from_scheduled_section(&schedule[0], "What If").unwrap();
+    assert_eq!(plan_add.section_id, "290181");
+    assert_eq!(plan_add.unit_count, 2);
+    assert!(matches!(plan_add.grading_option, Some(GradeOption::P)));
+    assert_eq!(plan_add.subject_code, "CSE");
+    assert_eq!(plan_add.course_code, "199");
+    assert_eq!(plan_add.schedule_name.unwrap(), "What If");
+    assert_eq!(plan_add.section_code, "001");
+}
+
+#[test]
+fn fail_construct_plan_add_from_schedule() {
+    let schedule = include_str!("json/schedule2.json");
+    let raw_schedule = serde_json::from_str::<Vec<RawScheduledMeeting>>(schedule).unwrap();
+    let mut schedule = parse_schedule(raw_schedule).unwrap();
+
+    schedule[0].grade_option = "X".into();
+    assert!(PlanAdd::from_scheduled_section(&schedule[0], "What If").is_err());
+
+    schedule[0].grade_option = "L".into();
+    schedule[0].units = 1000;
+    assert!(PlanAdd::from_scheduled_section(&schedule[0], "What If").is_err());
+}
+
+#[test]
+fn parse_grade_option() {
+    assert_eq!(GradeOption::L, "L".parse().unwrap());
+    assert_eq!(GradeOption::P, " p ".parse().unwrap());
+    assert_eq!(GradeOption::S, "S".parse().unwrap());
+    assert!("LP".parse::<GradeOption>().is_err());
+}
+
 #[test]
 fn success_construct_event_add() {
     let event_add = EventAdd::builder()
@@ -122,6 +161,112 @@ fn fail_construct_event_add() {
     assert!(event_add.is_none());
 }
 
+#[test]
+fn validate_event_add() {
+    let event = |start: (u32, u32), end: (u32, u32), days: &[DayOfWeek]| EventAdd {
+        event_name: "Gym".into(),
+        location: None,
+        event_days: days.to_vec(),
+        start_hr: start.0,
+        start_min: start.1,
+        end_hr: end.0,
+        end_min: end.1,
+    };
+
+    assert!(event((7, 0), (8, 0), &[DayOfWeek::Monday])
+        .validate()
+        .is_ok());
+    assert!(event((22, 0), (23, 0), &[DayOfWeek::Monday])
+        .validate()
+        .is_ok());
+    // Start must be before end
+    assert!(event((9, 0), (9, 0), &[DayOfWeek::Monday])
+        .validate()
+        .is_err());
+    // Must start between 7am and 10pm
+    assert!(event((6, 59), (8, 0), &[DayOfWeek::Monday])
+        .validate()
+        .is_err());
+    assert!(event((22, 1), (23, 0), &[DayOfWeek::Monday])
+        .validate()
+        .is_err());
+    // Minutes and hours must be in range
+    assert!(event((8, 75), (9, 0), &[DayOfWeek::Monday])
+        .validate()
+        .is_err());
+    assert!(event((8, 0), (9, 60), &[DayOfWeek::Monday])
+        .validate()
+        .is_err());
+    assert!(event((8, 0), (24, 0), &[DayOfWeek::Monday])
+        .validate()
+        .is_err());
+    assert!(event((8, 0), (23, 59), &[DayOfWeek::Monday])
+        .validate()
+        .is_ok());
+    // Must have at least one day
+    assert!(event((9, 0), (10, 0), &[]).validate().is_err());
+}
+
+#[test]
+fn event_to_event_add() {
+    let event = Event {
+        location: "RIMAC".into(),
+        start_hr: 8,
+        start_min: 0,
+        end_hr: 9,
+        end_min: 15,
+        name: "Gym".into(),
+        days: vec!["Tu".into(), "Th".into(), "Su".into()],
+        timestamp: "2022-09-09 21:50:16.846885".into(),
+    };
+
+    let event_add = event.to_event_add().unwrap();
+    assert_eq!(event_add.event_name, "Gym");
+    assert_eq!(event_add.location.as_deref(), Some("RIMAC"));
+    assert_eq!(
+        event_add.event_days,
+        vec![DayOfWeek::Tuesday, DayOfWeek::Thursday, DayOfWeek::Sunday]
+    );
+    assert_eq!(
+        (8, 0, 9, 15),
+        (
+            event_add.start_hr,
+            event_add.start_min,
+            event_add.end_hr,
+            event_add.end_min
+        )
+    );
+
+    let no_location = Event {
+        location: "".into(),
+        ..event.clone()
+    };
+    assert!(no_location.to_event_add().unwrap().location.is_none());
+
+    let bad_day = Event {
+        days: vec!["X".into()],
+        ..event
+    };
+    assert!(bad_day.to_event_add().is_err());
+}
+
+#[test]
+fn parse_day_of_week() {
+    for day in [
+        DayOfWeek::Monday,
+        DayOfWeek::Tuesday,
+        DayOfWeek::Wednesday,
+        DayOfWeek::Thursday,
+        DayOfWeek::Friday,
+        DayOfWeek::Saturday,
+        DayOfWeek::Sunday,
+    ] {
+        assert_eq!(day, day.as_str().parse().unwrap());
+    }
+
+    assert!("T".parse::<DayOfWeek>().is_err());
+}
+
 #[test]
 fn success_construct_enroll_wait() {
     let enroll_add = EnrollWaitAdd::builder()
@@ -145,3 +290,37 @@ fn fail_construct_enroll_wait() {
 
     assert!(enroll_add.is_none());
 }
+
+#[test]
+fn fail_construct_enroll_wait_invalid_section_id() {
+    let enroll_add = EnrollWaitAdd::builder()
+        .with_section_id("my section id")
+        .try_build();
+
+    assert!(enroll_add.is_none());
+}
+
+#[test]
+fn try_with_section_id_reports_error() {
+    let enroll_add = EnrollWaitAdd::builder()
+        .try_with_section_id("12345")
+        .unwrap()
+        .try_build()
+        .unwrap();
+    assert_eq!(enroll_add.section_id, "12345");
+
+    assert!(matches!(
+        EnrollWaitAdd::builder().try_with_section_id("my section id"),
+        Err(WrapperError::InputError("section_id", _))
+    ));
+    assert!(matches!(
+        PlanAdd::builder().try_with_section_id(-1_i64),
+        Err(WrapperError::InputError(
+            "section_id",
+            "section ID is out of range."
+        ))
+    ));
+    assert!(PlanAdd::builder()
+        .try_with_section_id(SectionId::new(12345))
+        .is_ok());
+}
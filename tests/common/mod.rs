@@ -0,0 +1,71 @@
+//! Helpers shared by the integration tests.
+#![allow(dead_code)]
+
+use std::sync::Arc;
+
+use webweg::raw_types::RawWebRegMeeting;
+use webweg::types::{Courses, RawResponse};
+use webweg::wrapper::transport::{HttpRequest, InMemoryTransport};
+use webweg::wrapper::wrapper_builder::WebRegWrapperBuilder;
+use webweg::wrapper::WebRegWrapper;
+use webweg::ww_parser::parse_course_info;
+
+/// The cookies that the test wrappers are built with.
+pub const COOKIES: &str = "my cookies";
+
+/// The user agent that the test wrappers are built with.
+pub const USER_AGENT: &str = "webweg tests";
+
+/// Creates a wrapper builder that sends every request through `transport`.
+pub fn builder(transport: &Arc<InMemoryTransport>) -> WebRegWrapperBuilder {
+    WebRegWrapper::builder()
+        .with_cookies(COOKIES)
+        .with_user_agent(USER_AGENT)
+        .with_transport(transport.clone())
+}
+
+/// Creates a wrapper that sends every request through `transport`.
+pub fn wrapper(transport: &Arc<InMemoryTransport>) -> WebRegWrapper {
+    builder(transport).try_build_wrapper().unwrap()
+}
+
+/// Creates a successful (`200`) response with the specified body.
+pub fn ok(body: impl Into<String>) -> RawResponse {
+    status(200, body)
+}
+
+/// Creates a response with the specified status code and body.
+pub fn status(status: u16, body: impl Into<String>) -> RawResponse {
+    RawResponse {
+        status,
+        headers: vec![],
+        body: body.into(),
+    }
+}
+
+/// Gets the value of a query string parameter of a request, or an empty string if the
+/// parameter isn't there.
+pub fn query_param(req: &HttpRequest, name: &str) -> String {
+    req.url
+        .query_pairs()
+        .find(|(k, _)| k == name)
+        .map(|(_, v)| v.into_owned())
+        .unwrap_or_default()
+}
+
+/// Gets the last segment of the path of a request (e.g., `plan-add`).
+pub fn endpoint(req: &HttpRequest) -> String {
+    req.url
+        .path()
+        .rsplit('/')
+        .next()
+        .unwrap_or_default()
+        .to_owned()
+}
+
+/// Parses the CSE 101 course information fixture.
+pub fn cse_101() -> Courses {
+    let course_info = include_str!("../json/courseinfo1.json");
+    let raw = serde_json::from_str::<Vec<RawWebRegMeeting>>(course_info).unwrap();
+    parse_course_info(raw, "CSE 101".into()).unwrap()
+}
@@ -0,0 +1,70 @@
+use webweg::compare::{compare_schedules, render_json, render_text, ScheduleStats};
+use webweg::raw_types::RawScheduledMeeting;
+use webweg::types::{Schedule, SectionId};
+use webweg::ww_parser::parse_schedule;
+
+fn schedule() -> Schedule {
+    let schedule = include_str!("json/schedule1.json");
+    let raw_schedule = serde_json::from_str::<Vec<RawScheduledMeeting>>(schedule).unwrap();
+    parse_schedule(raw_schedule).unwrap()
+}
+
+#[test]
+fn test_same_schedule() {
+    let a = schedule();
+    let comparison = compare_schedules("A", &a, "B", &a);
+    assert!(comparison.only_in_a.is_empty());
+    assert!(comparison.only_in_b.is_empty());
+    assert!(comparison.different.is_empty());
+    assert_eq!(comparison.stats_a, comparison.stats_b);
+}
+
+#[test]
+fn test_stats() {
+    let stats = ScheduleStats::new(&schedule());
+    assert_eq!(8, stats.total_units);
+    assert_eq!(vec!["M", "Tu", "W", "Th"], stats.days_on_campus);
+    assert_eq!(Some("12:30"), stats.earliest_start.as_deref());
+    assert_eq!(Some("19:50"), stats.latest_end.as_deref());
+    assert_eq!(Some(&2), stats.finals_per_day.get("2023-09-08"));
+
+    let empty = ScheduleStats::new(&[]);
+    assert_eq!(0, empty.total_units);
+    assert!(empty.days_on_campus.is_empty());
+    assert!(empty.earliest_start.is_none());
+    assert!(empty.finals_per_day.is_empty());
+}
+
+#[test]
+fn test_only_in_and_different() {
+    let a = schedule();
+    let mut b = a.clone();
+    b.retain(|s| s.subject_code == "COGS");
+    b[0].grade_option = "P".into();
+    b[0].units = 2;
+
+    let comparison = compare_schedules("My Schedule", &a, "What If", &b);
+    assert_eq!(1, comparison.only_in_a.len());
+    assert_eq!("HILA 102", comparison.only_in_a[0].subj_course_id);
+    assert!(comparison.only_in_b.is_empty());
+    assert_eq!(1, comparison.different.len());
+    assert_eq!(SectionId::new(184959), comparison.different[0].a.section_id);
+    assert_eq!(2, comparison.stats_b.total_units);
+
+    let text = render_text(&comparison);
+    assert!(text.starts_with("Only in My Schedule:\n\t[HILA 102 A00 / 185826] 4 units, P\n"));
+    assert!(text.contains("[COGS 118B A01 / 184959] Grade Option: L -> P Units: 4 -> 2"));
+}
+
+#[test]
+fn test_render_json() {
+    let a = schedule();
+    let comparison = compare_schedules("A", &a, "B", &[]);
+    let value: serde_json::Value =
+        serde_json::from_str(&render_json(&comparison).unwrap()).unwrap();
+    assert_eq!("COGS 118B", value["only_in_a"][0]["subj_course_id"]);
+    assert_eq!("184959", value["only_in_a"][0]["section_id"]);
+    assert_eq!(8, value["stats_a"]["total_units"]);
+    assert_eq!(2, value["stats_a"]["finals_per_day"]["2023-09-08"]);
+    assert!(value["stats_b"]["earliest_start"].is_null());
+}
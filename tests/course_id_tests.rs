@@ -0,0 +1,167 @@
+use std::sync::Arc;
+
+use webweg::types::{CourseId, CourseKey};
+use webweg::util;
+use webweg::wrapper::input_types::{SearchCourse, SearchRequestBuilder, SearchType};
+use webweg::wrapper::transport::InMemoryTransport;
+
+mod common;
+
+#[test]
+fn test_parse_and_round_trip() {
+    for (input, subject, number, suffix, display) in [
+        ("CSE 100", "CSE", 100, "", "CSE 100"),
+        ("cse100", "CSE", 100, "", "CSE 100"),
+        ("MATH 20C", "MATH", 20, "C", "MATH 20C"),
+        ("ECE 199H", "ECE", 199, "H", "ECE 199H"),
+        ("BILD 1", "BILD", 1, "", "BILD 1"),
+        ("  math   20c ", "MATH", 20, "C", "MATH 20C"),
+    ] {
+        let id: CourseId = input.parse().unwrap();
+        assert_eq!(subject, id.subject());
+        assert_eq!(number, id.number());
+        assert_eq!(suffix, id.suffix());
+        assert_eq!(display, id.to_string());
+        assert_eq!(id, id.to_string().parse().unwrap());
+    }
+}
+
+#[test]
+fn test_invalid_course_ids() {
+    for bad in ["", "CSE", "100", "CSE 10-0", "C$E 100", "CSE 99999999999"] {
+        assert!(bad.parse::<CourseId>().is_err(), "{bad} should not parse");
+    }
+
+    assert!(CourseId::new("", 100, "").is_none());
+    assert!(CourseId::new("CSE", 100, "1").is_none());
+}
+
+#[test]
+fn test_formatted_course_num() {
+    for (input, formatted) in [
+        ("CSE 8B", "  8B"),
+        ("BILD 1", "  1"),
+        ("MATH 20C", " 20C"),
+        ("CSE 100", "100"),
+        ("ECE 199H", "199H"),
+    ] {
+        let id: CourseId = input.parse().unwrap();
+        assert_eq!(formatted, id.formatted_course_num());
+        assert_eq!(
+            util::get_formatted_course_num(&id.course_num()),
+            id.formatted_course_num()
+        );
+    }
+}
+
+#[test]
+fn test_natural_ordering() {
+    let mut ids = ["CSE 100A", "CSE 10", "MATH 2", "CSE 100", "CSE 2"]
+        .into_iter()
+        .map(|s| s.parse::<CourseId>().unwrap())
+        .collect::<Vec<_>>();
+    ids.sort();
+
+    assert_eq!(
+        vec!["CSE 2", "CSE 10", "CSE 100", "CSE 100A", "MATH 2"],
+        ids.iter().map(|id| id.to_string()).collect::<Vec<_>>()
+    );
+}
+
+#[test]
+fn test_serde() {
+    let id: CourseId = "math20c".parse().unwrap();
+    let json = serde_json::to_string(&id).unwrap();
+    assert_eq!("\"MATH 20C\"", json);
+    assert_eq!(id, serde_json::from_str::<CourseId>(&json).unwrap());
+    assert!(serde_json::from_str::<CourseId>("\"MATH\"").is_err());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_search_with_course_id() {
+    let id: CourseId = "cse8b".parse().unwrap();
+    let search = SearchRequestBuilder::new()
+        .add_course(&id)
+        .add_course("math 20d")
+        .add_course(CourseId::new("bild", 1, "").unwrap());
+    assert_eq!(
+        vec![
+            SearchCourse::Id(id.clone()),
+            SearchCourse::Query("math 20d".to_owned()),
+            SearchCourse::Id("BILD 1".parse().unwrap()),
+        ],
+        search.courses
+    );
+
+    let transport = Arc::new(InMemoryTransport::new().with_json("secure/search-by-all", "[]"));
+    let wrapper = common::wrapper(&transport);
+    wrapper
+        .req("FA23")
+        .raw()
+        .search_courses(SearchType::Advanced(search))
+        .await
+        .unwrap();
+
+    let url = &transport.requests()[0].url;
+    assert!(url
+        .query_pairs()
+        .any(|(k, v)| k == "crsecode" && v == "CSE:  8B;MATH: 20D;BILD:  1"));
+}
+
+#[test]
+fn test_course_key() {
+    let id: CourseId = "math20c".parse().unwrap();
+    for key in [
+        CourseKey::from(&id),
+        CourseKey::from(id.clone()),
+        CourseKey::from(("MATH", "20C")),
+        CourseKey::from((" math".to_owned(), "20c ".to_owned())),
+    ] {
+        assert_eq!("MATH 20C", key.to_string());
+    }
+
+    let key = CourseKey::from(("cse", " 8b"));
+    assert_eq!("cse", key.subject());
+    assert_eq!(" 8b", key.course_num());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_course_key_is_normalized_on_the_wire() {
+    let transport = Arc::new(
+        InMemoryTransport::new()
+            .with_json("secure/search-load-group-data", "[]")
+            .with_json("secure/get-prerequisites", "[]"),
+    );
+    let wrapper = common::wrapper(&transport);
+    let raw = wrapper.req("FA23").raw();
+
+    let id: CourseId = "cse8b".parse().unwrap();
+    raw.get_course_info(("math", " 20c")).await.unwrap();
+    raw.get_prerequisites((" bild ", "1")).await.unwrap();
+    raw.get_course_info(&id).await.unwrap();
+    raw.get_course_info(("cse", "100")).await.unwrap();
+
+    let sent = transport
+        .requests()
+        .iter()
+        .map(|req| {
+            let get = |name: &str| {
+                req.url
+                    .query_pairs()
+                    .find(|(k, _)| k == name)
+                    .map(|(_, v)| v.into_owned())
+                    .unwrap()
+            };
+            (get("subjcode"), get("crsecode"))
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            ("MATH".to_owned(), " 20C".to_owned()),
+            ("BILD".to_owned(), "  1".to_owned()),
+            ("CSE".to_owned(), "  8B".to_owned()),
+            ("CSE".to_owned(), "100".to_owned()),
+        ],
+        sent
+    );
+}
@@ -0,0 +1,99 @@
+use webweg::diff::{
+    diff_catalogs, diff_courses, render_json, render_text, ChangeKind, CountChange, MeetingField,
+};
+use webweg::types::SectionId;
+
+mod common;
+
+use common::cse_101;
+
+#[test]
+fn test_no_changes() {
+    let courses = cse_101();
+    assert!(diff_courses(&courses, &courses).is_empty());
+}
+
+#[test]
+fn test_seat_changes() {
+    let old = cse_101();
+    let mut new = old.clone();
+    new[0].enrolled_ct += 2;
+    new[0].waitlist_ct -= 2;
+
+    let changes = diff_courses(&old, &new);
+    assert_eq!(2, changes.len());
+    assert_eq!(
+        ChangeKind::Enrolled(CountChange::new(329, 331)),
+        changes[0].kind
+    );
+    assert_eq!(
+        ChangeKind::Waitlist(CountChange::new(125, 123)),
+        changes[1].kind
+    );
+    assert_eq!(
+        "[CSE 101 A01 / 260739] Enrolled: 329 -> 331 (+2)\n\
+         [CSE 101 A01 / 260739] Waitlist: 125 -> 123 (-2)\n",
+        render_text(&changes)
+    );
+}
+
+#[test]
+fn test_added_and_canceled() {
+    let old = cse_101();
+    let mut new = old.clone();
+    new[0].section_id = SectionId::new(999999);
+    new[0].section_code = "B01".into();
+
+    let changes = diff_catalogs([&old], [&new]);
+    assert_eq!(2, changes.len());
+    assert_eq!(ChangeKind::Canceled, changes[0].kind);
+    assert_eq!("260739", changes[0].section_id);
+    assert_eq!(ChangeKind::Added, changes[1].kind);
+    assert_eq!("999999", changes[1].section_id);
+}
+
+#[test]
+fn test_meeting_changes() {
+    let old = cse_101();
+    let mut new = old.clone();
+    let lecture = new[0]
+        .meetings
+        .iter_mut()
+        .find(|m| m.meeting_type == "LE")
+        .unwrap();
+    lecture.room = "2005".into();
+    lecture.start_hr = 15;
+    lecture.end_hr = 15;
+
+    let changes = diff_courses(&old, &new);
+    let fields = changes
+        .iter()
+        .filter_map(|c| match &c.kind {
+            ChangeKind::MeetingChanged { field, .. } => Some(*field),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(vec![MeetingField::Time, MeetingField::Room], fields);
+    assert!(render_text(&changes).contains("LE Room: 2001 -> 2005"));
+
+    // A snapshot without meetings (e.g., from get_enrollment_count) shouldn't be reported
+    // as every meeting being removed.
+    let mut counts = old.clone();
+    counts[0].meetings.clear();
+    assert!(diff_courses(&old, &counts).is_empty());
+}
+
+#[test]
+fn test_render_json() {
+    let old = cse_101();
+    let mut new = old.clone();
+    new[0].total_seats = 250;
+
+    let json = render_json(&diff_courses(&old, &new)).unwrap();
+    let value: serde_json::Value = serde_json::from_str(&json).unwrap();
+    assert_eq!("total_seats", value[0]["change"]);
+    assert_eq!("260739", value[0]["section_id"]);
+    assert_eq!(245, value[0]["old"]);
+    assert_eq!(250, value[0]["new"]);
+    assert_eq!(5, value[0]["delta"]);
+}
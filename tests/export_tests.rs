@@ -0,0 +1,81 @@
+use webweg::export::{
+    meeting_rows, schedule_section_rows, section_rows, to_csv, to_ndjson, SnapshotContext,
+    MEETING_COLUMNS, SECTION_COLUMNS,
+};
+use webweg::raw_types::RawScheduledMeeting;
+use webweg::ww_parser::parse_schedule;
+
+mod common;
+
+use common::cse_101;
+
+#[test]
+fn test_section_rows_csv() {
+    let ctx = SnapshotContext::new("FA23", 1700000000000);
+    let rows = section_rows(&ctx, &cse_101());
+    let csv = to_csv(&rows);
+    let lines = csv.lines().collect::<Vec<_>>();
+
+    assert_eq!(SECTION_COLUMNS.join(","), lines[0]);
+    assert_eq!(
+        "1700000000000,FA23,CSE,101,260739,A01,329,0,125,245,\"Bach, Quang Tran\"",
+        lines[1]
+    );
+    assert_eq!(2, lines.len());
+}
+
+#[test]
+fn test_meeting_rows_csv() {
+    let ctx = SnapshotContext::new("FA23", 1700000000000);
+    let rows = meeting_rows(&ctx, &cse_101());
+    assert_eq!(5, rows.len());
+
+    let csv = to_csv(&rows);
+    let lines = csv.lines().collect::<Vec<_>>();
+    assert_eq!(MEETING_COLUMNS.join(","), lines[0]);
+    assert!(lines.contains(
+        &"1700000000000,FA23,CSE,101,260739,A01,LE,MWF,14:00,14:50,WLH,2001,\"Bach, Quang Tran\""
+    ));
+    assert!(lines.contains(
+        &"1700000000000,FA23,CSE,101,260739,A01,FI,2023-12-13,15:00,17:59,WLH,2001,\"Bach, Quang Tran\""
+    ));
+}
+
+#[test]
+fn test_section_rows_ndjson() {
+    let ctx = SnapshotContext::new("FA23", 1700000000000);
+    let rows = section_rows(&ctx, &cse_101());
+    let ndjson = to_ndjson(&rows).unwrap();
+    let lines = ndjson.lines().collect::<Vec<_>>();
+    assert_eq!(1, lines.len());
+
+    let value: serde_json::Value = serde_json::from_str(lines[0]).unwrap();
+    assert_eq!("FA23", value["term"]);
+    assert_eq!("CSE", value["subject_code"]);
+    assert_eq!("101", value["course_code"]);
+    assert_eq!("260739", value["section_id"]);
+    assert_eq!(329, value["enrolled"]);
+    assert_eq!(125, value["waitlist"]);
+}
+
+#[test]
+fn test_schedule_section_rows() {
+    let schedule = include_str!("json/schedule2.json");
+    let raw = serde_json::from_str::<Vec<RawScheduledMeeting>>(schedule).unwrap();
+    let schedule = parse_schedule(raw).unwrap();
+
+    let ctx = SnapshotContext::new("FA23", 0);
+    let csv = to_csv(&schedule_section_rows(&ctx, &schedule));
+    assert_eq!(
+        "0,FA23,CSE,199,290181,001,1,9998,0,9999,\"Sahoo, Debashis\"",
+        csv.lines().nth(1).unwrap()
+    );
+}
+
+#[test]
+fn test_empty_export() {
+    let ctx = SnapshotContext::new("FA23", 0);
+    let rows = section_rows(&ctx, &[]);
+    assert_eq!(format!("{}\n", SECTION_COLUMNS.join(",")), to_csv(&rows));
+    assert_eq!("", to_ndjson(&rows).unwrap());
+}
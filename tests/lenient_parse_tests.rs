@@ -0,0 +1,201 @@
+use webweg::raw_types::{RawEvent, RawScheduledMeeting, RawWebRegMeeting};
+use webweg::types::{SectionId, WrapperError};
+use webweg::ww_parser::{
+    parse_course_info, parse_course_info_lenient, parse_enrollment_count,
+    parse_enrollment_count_lenient, parse_get_events, parse_get_events_lenient, parse_schedule,
+    parse_schedule_lenient,
+};
+
+fn cse_30() -> Vec<RawWebRegMeeting> {
+    let course_info = include_str!("json/courseinfo2.json");
+    serde_json::from_str(course_info).unwrap()
+}
+
+fn schedule() -> Vec<RawScheduledMeeting> {
+    let schedule = include_str!("json/schedule1.json");
+    serde_json::from_str(schedule).unwrap()
+}
+
+fn event(start_time: &str, days: &str) -> RawEvent {
+    RawEvent {
+        location: "Home".into(),
+        start_time: start_time.into(),
+        end_time: "1050".into(),
+        description: "Study".into(),
+        days: days.into(),
+        time_stamp: "2023-07-01 10:00:00.0".into(),
+    }
+}
+
+fn parsing_error(err: &WrapperError) -> &str {
+    match err {
+        WrapperError::WrapperParsingError(msg) => msg,
+        e => panic!("expected a parsing error, got {e:?}"),
+    }
+}
+
+#[test]
+fn test_course_info_bad_day_code() {
+    let bad_day_code = || {
+        let mut raw = cse_30();
+        raw.iter_mut()
+            .find(|m| m.sect_code == "B01")
+            .unwrap()
+            .day_code = "1X".into();
+        raw
+    };
+
+    let err = parse_course_info(bad_day_code(), "CSE 30".into()).unwrap_err();
+    let msg = parsing_error(&err);
+    assert!(msg.starts_with("day_code:"));
+    assert!(msg.contains("CSE 30 B01"));
+
+    let res = parse_course_info_lenient(bad_day_code(), "CSE 30".into());
+    assert!(!res.is_clean());
+    assert_eq!(1, res.warnings.len());
+    assert_eq!(Some(SectionId::new(249208)), res.warnings[0].section_id);
+    assert_eq!(1, res.parsed.len());
+    assert_eq!("A01", res.parsed[0].section_code);
+}
+
+#[test]
+fn test_course_info_no_general_meeting() {
+    let no_lecture = || {
+        cse_30()
+            .into_iter()
+            .filter(|m| m.sect_code != "B00")
+            .collect::<Vec<_>>()
+    };
+
+    let err = parse_course_info(no_lecture(), "CSE 30".into()).unwrap_err();
+    assert!(parsing_error(&err).contains("no lecture"));
+
+    let res = parse_course_info_lenient(no_lecture(), "CSE 30".into());
+    assert_eq!(1, res.warnings.len());
+    assert_eq!(Some(SectionId::new(249208)), res.warnings[0].section_id);
+    assert_eq!(1, res.parsed.len());
+    assert_eq!("A01", res.parsed[0].section_code);
+}
+
+#[test]
+fn test_clean_course_info() {
+    let strict = parse_course_info(cse_30(), "CSE 30".into()).unwrap();
+    let lenient = parse_course_info_lenient(cse_30(), "CSE 30".into());
+    assert!(lenient.is_clean());
+    assert_eq!(strict.len(), lenient.parsed.len());
+}
+
+#[test]
+fn test_enrollment_count_bad_section_id() {
+    let bad_section_id = || {
+        let mut raw = cse_30();
+        for m in raw.iter_mut().filter(|m| m.sect_code == "A01") {
+            m.section_id = "abc".into();
+        }
+
+        raw
+    };
+
+    assert!(parse_enrollment_count(bad_section_id(), "CSE 30".into()).is_err());
+
+    let res = parse_enrollment_count_lenient(bad_section_id(), "CSE 30".into());
+    assert_eq!(1, res.warnings.len());
+    assert!(parsing_error(&res.warnings[0].error).starts_with("section_id:"));
+    assert_eq!(None, res.warnings[0].section_id);
+    assert!(res.parsed.iter().all(|s| s.section_code != "A01"));
+}
+
+#[test]
+fn test_schedule_bad_time() {
+    let bad_schedule = || {
+        let mut raw = schedule();
+        raw.iter_mut()
+            .find(|m| m.subj_code.trim() == "COGS" && m.sect_code == "A01")
+            .unwrap()
+            .start_time_hr = -1;
+        raw
+    };
+
+    let err = parse_schedule(bad_schedule()).unwrap_err();
+    let msg = parsing_error(&err);
+    assert!(msg.starts_with("start_time_hr:"));
+    assert!(msg.contains("COGS"));
+
+    let res = parse_schedule_lenient(bad_schedule());
+    assert_eq!(1, res.warnings.len());
+    assert_eq!(Some(SectionId::new(184959)), res.warnings[0].section_id);
+    assert_eq!(1, res.parsed.len());
+    assert_eq!("HILA", res.parsed[0].subject_code);
+}
+
+#[test]
+fn test_schedule_missing_section_code() {
+    let missing_section_code = || {
+        let mut raw = schedule();
+        raw[0].sect_code = String::new();
+        raw
+    };
+
+    assert!(parse_schedule(missing_section_code()).is_err());
+
+    let res = parse_schedule_lenient(missing_section_code());
+    assert_eq!(1, res.warnings.len());
+    assert!(parsing_error(&res.warnings[0].error).starts_with("sect_code:"));
+    assert_eq!(Some(SectionId::new(185826)), res.warnings[0].section_id);
+    // The rest of the HILA section's meetings are still parsed.
+    assert_eq!(2, res.parsed.len());
+}
+
+#[test]
+fn test_schedule_no_main_meeting() {
+    let no_main = || {
+        schedule()
+            .into_iter()
+            .filter(|m| !(m.subj_code.trim() == "COGS" && m.sect_code == "A00"))
+            .collect::<Vec<_>>()
+    };
+
+    let err = parse_schedule(no_main()).unwrap_err();
+    assert!(parsing_error(&err).contains("no main meeting"));
+
+    let res = parse_schedule_lenient(no_main());
+    assert_eq!(1, res.warnings.len());
+    assert_eq!(SectionId::new(185826), res.parsed[0].section_id);
+}
+
+#[test]
+fn test_event_times_keep_minutes() {
+    let raw = RawEvent {
+        end_time: "1145".into(),
+        ..event("0930", "0100000")
+    };
+
+    let events = parse_get_events(vec![raw]).unwrap();
+    assert_eq!((9, 30), (events[0].start_hr, events[0].start_min));
+    assert_eq!((11, 45), (events[0].end_hr, events[0].end_min));
+}
+
+#[test]
+fn test_events() {
+    let events = parse_get_events(vec![event("0930", "1010100")]).unwrap();
+    assert_eq!((9, 30), (events[0].start_hr, events[0].start_min));
+    assert_eq!((10, 50), (events[0].end_hr, events[0].end_min));
+    assert_eq!(vec!["M", "W", "F"], events[0].days);
+
+    let err = parse_get_events(vec![event("930", "1010100")]).unwrap_err();
+    let msg = parsing_error(&err);
+    assert!(msg.starts_with("start_time:"));
+    assert!(msg.contains("Study"));
+
+    assert!(parse_get_events(vec![event("0930", "10101")]).is_err());
+    assert!(parse_get_events(vec![event("09é0", "1010100")]).is_err());
+
+    let res = parse_get_events_lenient(vec![
+        event("", "1010100"),
+        event("0930", "1010100"),
+        event("0930", "MWF"),
+    ]);
+    assert_eq!(2, res.warnings.len());
+    assert!(res.warnings.iter().all(|w| w.section_id.is_none()));
+    assert_eq!(1, res.parsed.len());
+}
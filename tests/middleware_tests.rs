@@ -0,0 +1,168 @@
+use std::sync::{Arc, Mutex};
+
+use webweg::types::{self, RawResponse, WrapperError};
+use webweg::wrapper::middleware::{Middleware, RequestMeta};
+use webweg::wrapper::transport::{HttpRequest, InMemoryTransport};
+use webweg::wrapper::WebRegWrapper;
+
+mod common;
+
+type Calls = Arc<Mutex<Vec<(&'static str, String, Option<String>, String)>>>;
+
+/// Records every request it sees, and optionally stops the request from being sent.
+struct Recorder {
+    name: &'static str,
+    calls: Calls,
+    block: bool,
+}
+
+impl Middleware for Recorder {
+    fn before_request(&self, req: &mut HttpRequest, meta: &RequestMeta<'_>) -> types::Result<()> {
+        self.calls.lock().unwrap().push((
+            self.name,
+            meta.operation.to_owned(),
+            meta.term.map(str::to_owned),
+            meta.endpoint.to_owned(),
+        ));
+
+        if self.block {
+            Err(WrapperError::WebRegError(format!(
+                "blocked {}",
+                meta.operation
+            )))
+        } else {
+            req.set_header("X-Test", self.name);
+            Ok(())
+        }
+    }
+}
+
+fn wrapper(calls: &Calls) -> WebRegWrapper {
+    common::builder(&Arc::new(InMemoryTransport::new()))
+        .with_middleware(Recorder {
+            name: "first",
+            calls: calls.clone(),
+            block: false,
+        })
+        .with_middleware(Recorder {
+            name: "second",
+            calls: calls.clone(),
+            block: true,
+        })
+        .try_build_wrapper()
+        .unwrap()
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_middleware_order_and_short_circuit() {
+    let calls = Calls::default();
+    let wrapper = wrapper(&calls);
+
+    let err = wrapper.req("FA23").raw().get_schedule(None).await;
+    assert!(matches!(err, Err(WrapperError::WebRegError(ref e)) if e == "blocked get_schedule"));
+
+    let calls = calls.lock().unwrap();
+    assert_eq!(
+        vec!["first", "second"],
+        calls.iter().map(|c| c.0).collect::<Vec<_>>()
+    );
+    assert_eq!("get_schedule", calls[0].1);
+    assert_eq!(Some("FA23"), calls[0].2.as_deref());
+    assert_eq!(
+        "https://act.ucsd.edu/webreg2/svc/wradapter/secure/get-class",
+        calls[0].3
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_middleware_meta() {
+    let calls = Calls::default();
+    let wrapper = wrapper(&calls);
+
+    assert!(wrapper
+        .req("FA23")
+        .raw()
+        .remove_event("2023-07-01 10:00:00.0")
+        .await
+        .is_err());
+    assert!(wrapper
+        .req("WI24")
+        .parsed()
+        .get_subject_codes()
+        .await
+        .is_err());
+    assert!(!wrapper.ping_server().await);
+
+    let calls = calls.lock().unwrap();
+    let seen = calls
+        .iter()
+        .filter(|c| c.0 == "second")
+        .map(|c| (c.1.as_str(), c.2.as_deref(), c.3.as_str()))
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec![
+            (
+                "remove_event",
+                Some("FA23"),
+                "https://act.ucsd.edu/webreg2/svc/wradapter/secure/event-remove"
+            ),
+            (
+                "get_subject_codes",
+                Some("WI24"),
+                "https://act.ucsd.edu/webreg2/svc/wradapter/secure/search-load-subject"
+            ),
+            (
+                "ping_server",
+                None,
+                "https://act.ucsd.edu/webreg2/svc/wradapter/secure/ping-server"
+            ),
+        ],
+        seen
+    );
+}
+
+/// Records the status code of every response it sees.
+#[derive(Clone, Default)]
+struct StatusCounter {
+    statuses: Arc<Mutex<Vec<(&'static str, u16)>>>,
+}
+
+impl Middleware for StatusCounter {
+    fn after_response(&self, res: &RawResponse, meta: &RequestMeta<'_>) {
+        self.statuses
+            .lock()
+            .unwrap()
+            .push((meta.operation, res.status));
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_middleware_sees_request_and_response() {
+    let calls = Calls::default();
+    let counter = StatusCounter::default();
+    let transport = Arc::new(
+        InMemoryTransport::new().with_json("secure/sched-get-schednames", r#"["My Schedule"]"#),
+    );
+
+    let wrapper = common::builder(&transport)
+        .with_middleware(Recorder {
+            name: "first",
+            calls: calls.clone(),
+            block: false,
+        })
+        .with_middleware(counter.clone())
+        .try_build_wrapper()
+        .unwrap();
+
+    let term = wrapper.req("FA23").parsed();
+    assert_eq!(vec!["My Schedule"], term.get_schedule_list().await.unwrap());
+    assert!(term.get_events().await.is_err());
+
+    let requests = transport.requests();
+    assert_eq!(2, requests.len());
+    assert!(requests.iter().all(|r| r.header("x-test") == Some("first")));
+    assert_eq!(
+        vec![("get_schedule_list", 200), ("get_events", 404)],
+        *counter.statuses.lock().unwrap()
+    );
+}
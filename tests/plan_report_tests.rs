@@ -0,0 +1,413 @@
+use std::sync::Arc;
+
+use serde_json::Value;
+use webweg::types::{EventItemResult, PlanItemResult, PlanReport, SectionId, WrapperError};
+use webweg::wrapper::input_types::{DayOfWeek, EventAdd, PlanAdd};
+use webweg::wrapper::transport::InMemoryTransport;
+use webweg::wrapper::WebRegWrapper;
+
+mod common;
+
+use common::{endpoint, ok, status};
+
+/// The fields of a URL-encoded form.
+type Form = Vec<(String, String)>;
+
+fn schedule() -> String {
+    let mut meetings = vec![];
+    for raw in [
+        include_str!("json/schedule1.json")
+            .replace(r#""ENROLL_STATUS": "WT""#, r#""ENROLL_STATUS": "PL""#),
+        include_str!("json/schedule2.json").to_owned(),
+    ] {
+        let Value::Array(m) = serde_json::from_str(&raw).unwrap() else {
+            panic!("expected an array");
+        };
+        meetings.extend(m);
+    }
+
+    Value::Array(meetings).to_string()
+}
+
+const EVENTS: &str = r#"[
+    {"LOCATION": "RIMAC", "START_TIME": "0800", "END_TIME": "0900", "DESCRIPTION": "Gym", "DAYS": "1010000", "TIME_STAMP": "2023-07-01 10:00:00.0"},
+    {"LOCATION": "", "START_TIME": "1930", "END_TIME": "2115", "DESCRIPTION": "Study", "DAYS": "0101000", "TIME_STAMP": "2023-07-02 10:00:00.0"}
+]"#;
+
+/// Creates a wrapper for a fake WebReg that serves a schedule with two planned sections
+/// (COGS 118B, 184959, and CSE 199, 290181) and one enrolled section (HILA 102, 185826),
+/// along with two events. Any `POST` request whose form contains `fail_on` is rejected.
+fn wrapper(fail_on: &'static str) -> (WebRegWrapper, Arc<InMemoryTransport>) {
+    let transport = Arc::new(
+        InMemoryTransport::new()
+            .with_json("secure/get-class", schedule())
+            .with_json("secure/event-get", EVENTS)
+            .with_fallback(move |req| {
+                if req.body.is_none() {
+                    status(404, "")
+                } else if req.form().iter().any(|(_, v)| v == fail_on) {
+                    ok(r#"{"OPS":"FAIL","REASON":"Nope."}"#)
+                } else {
+                    ok(r#"{"OPS":"SUCCESS"}"#)
+                }
+            }),
+    );
+
+    (common::wrapper(&transport), transport)
+}
+
+/// Gets the endpoint and form of every `POST` request sent through `transport`, in order.
+fn posted(transport: &InMemoryTransport) -> Vec<(String, Form)> {
+    transport
+        .requests()
+        .iter()
+        .filter(|req| req.body.is_some())
+        .map(|req| (endpoint(req), req.form()))
+        .collect()
+}
+
+/// Gets the endpoint and the section ID (`sectnum` or `section`) of every `POST` request
+/// sent through `transport`, in order.
+fn posted_sections(transport: &InMemoryTransport) -> Vec<(String, String)> {
+    posted(transport)
+        .into_iter()
+        .map(|(endpoint, form)| {
+            let sectnum = form
+                .into_iter()
+                .find(|(k, _)| k == "sectnum" || k == "section")
+                .map(|(_, v)| v)
+                .unwrap_or_default();
+            (endpoint, sectnum)
+        })
+        .collect()
+}
+
+fn plan(section_id: &str, section_code: &str) -> PlanAdd<'static> {
+    PlanAdd::builder()
+        .with_subject_code("CSE")
+        .with_course_code("100")
+        .with_section_id(section_id)
+        .with_section_code(section_code.to_owned())
+        .with_unit_count(4)
+        .try_build()
+        .unwrap()
+}
+
+fn assert_failed_with_reason(report: &PlanReport, section_id: SectionId) {
+    let failed = report.failed().collect::<Vec<_>>();
+    assert_eq!(1, failed.len());
+    assert_eq!(section_id, failed[0].section_id);
+    assert!(matches!(
+        failed[0].result,
+        Err(WrapperError::WebRegError(ref reason)) if reason == "Nope."
+    ));
+}
+
+#[test]
+fn test_empty_report() {
+    let report = PlanReport::default();
+    assert!(report.is_success());
+    assert_eq!(0, report.failed().count());
+}
+
+#[test]
+fn test_partial_failure() {
+    let report = PlanReport {
+        items: vec![
+            PlanItemResult {
+                section_id: SectionId::new(79911),
+                result: Ok(true),
+            },
+            PlanItemResult {
+                section_id: SectionId::new(79912),
+                result: Err(WrapperError::WebRegError("Section is full.".into())),
+            },
+            PlanItemResult {
+                section_id: SectionId::new(79913),
+                result: Ok(true),
+            },
+        ],
+    };
+
+    assert!(!report.is_success());
+    assert_eq!(
+        vec![SectionId::new(79911), SectionId::new(79913)],
+        report.succeeded().map(|i| i.section_id).collect::<Vec<_>>()
+    );
+
+    let failed = report.failed().collect::<Vec<_>>();
+    assert_eq!(1, failed.len());
+    assert_eq!(failed[0].section_id, "079912");
+    assert!(matches!(
+        failed[0].result,
+        Err(WrapperError::WebRegError(ref reason)) if reason == "Section is full."
+    ));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_add_many_to_plan_continues_after_failure() {
+    let (wrapper, transport) = wrapper("079912");
+    let report = wrapper
+        .req("FA23")
+        .parsed()
+        .add_many_to_plan(
+            [
+                plan("079911", "A01"),
+                plan("079912", "A02"),
+                plan("079913", "A03"),
+            ],
+            true,
+        )
+        .await;
+
+    assert_eq!(3, report.items.len());
+    assert_failed_with_reason(&report, SectionId::new(79912));
+    assert_eq!(
+        vec![SectionId::new(79911), SectionId::new(79913)],
+        report.succeeded().map(|i| i.section_id).collect::<Vec<_>>()
+    );
+
+    // A failed validation doesn't stop the section from being planned, so the failure
+    // comes from planning it.
+    assert_eq!(
+        vec![
+            ("edit-plan".to_owned(), "079911".to_owned()),
+            ("plan-add".to_owned(), "079911".to_owned()),
+            ("edit-plan".to_owned(), "079912".to_owned()),
+            ("plan-add".to_owned(), "079912".to_owned()),
+            ("edit-plan".to_owned(), "079913".to_owned()),
+            ("plan-add".to_owned(), "079913".to_owned()),
+        ],
+        posted_sections(&transport)
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_remove_many_from_plan_continues_after_failure() {
+    let (wrapper, transport) = wrapper("079911");
+    let report = wrapper
+        .req("FA23")
+        .parsed()
+        .remove_many_from_plan([SectionId::new(79911), SectionId::new(79912)], None)
+        .await;
+
+    assert_eq!(2, report.items.len());
+    assert_failed_with_reason(&report, SectionId::new(79911));
+    assert_eq!(
+        vec![
+            ("plan-remove".to_owned(), "079911".to_owned()),
+            ("plan-remove".to_owned(), "079912".to_owned()),
+        ],
+        posted_sections(&transport)
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_clear_schedule_only_removes_planned_sections() {
+    let (wrapper, transport) = wrapper("184959");
+    let report = wrapper
+        .req("FA23")
+        .parsed()
+        .clear_schedule(None)
+        .await
+        .unwrap();
+
+    assert_eq!(2, report.items.len());
+    assert_failed_with_reason(&report, SectionId::new(184959));
+    assert_eq!(
+        vec![SectionId::new(290181)],
+        report.succeeded().map(|i| i.section_id).collect::<Vec<_>>()
+    );
+
+    // The enrolled section (185826) is left alone.
+    let mut removed = posted_sections(&transport);
+    removed.sort();
+    assert_eq!(
+        vec![
+            ("plan-remove".to_owned(), "184959".to_owned()),
+            ("plan-remove".to_owned(), "290181".to_owned()),
+        ],
+        removed
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_copy_schedule_continues_after_failure() {
+    let (wrapper, transport) = wrapper("290181");
+    let report = wrapper
+        .req("FA23")
+        .parsed()
+        .copy_schedule("My Schedule", "What If")
+        .await
+        .unwrap();
+
+    assert_eq!(2, report.items.len());
+    assert_failed_with_reason(&report, SectionId::new(290181));
+    assert_eq!(
+        vec![SectionId::new(184959)],
+        report.succeeded().map(|i| i.section_id).collect::<Vec<_>>()
+    );
+
+    // Only the planned sections are copied, and they're all planned into the new schedule.
+    let posted = posted(&transport);
+    let mut planned = posted
+        .iter()
+        .filter(|(endpoint, _)| endpoint == "plan-add")
+        .map(|(_, form)| {
+            let field = |name: &str| {
+                form.iter()
+                    .find(|(k, _)| k == name)
+                    .map(|(_, v)| v.as_str())
+                    .unwrap_or_default()
+            };
+            (field("sectnum"), field("schedname"))
+        })
+        .collect::<Vec<_>>();
+    planned.sort();
+    assert_eq!(vec![("184959", "What If"), ("290181", "What If")], planned);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_copy_schedule_into_itself() {
+    let (wrapper, transport) = wrapper("000000");
+    let res = wrapper
+        .req("FA23")
+        .parsed()
+        .copy_schedule("What If", "What If")
+        .await;
+
+    assert!(matches!(res, Err(WrapperError::InputError("to", _))));
+    assert!(posted_sections(&transport).is_empty());
+}
+
+fn event_add(name: &'static str, start_hr: u32) -> EventAdd<'static> {
+    EventAdd {
+        event_name: name.into(),
+        location: None,
+        event_days: vec![DayOfWeek::Tuesday],
+        start_hr,
+        start_min: 0,
+        end_hr: start_hr + 1,
+        end_min: 30,
+    }
+}
+
+/// Gets the name of every event in `items` whose result matches `failed`.
+fn event_names(items: &[EventItemResult], failed: bool) -> Vec<&str> {
+    items
+        .iter()
+        .filter(|i| i.result.is_err() == failed)
+        .map(|i| i.event_name.as_str())
+        .collect()
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_edit_event() {
+    let (wrapper, transport) = wrapper("000000");
+    let edited = wrapper
+        .req("FA23")
+        .parsed()
+        .edit_event("2023-07-02 10:00:00.0", |e| {
+            e.end_hr = 22;
+            e.end_min = 0;
+        })
+        .await
+        .unwrap();
+    assert!(edited);
+
+    let posted = posted(&transport);
+    assert_eq!(1, posted.len());
+    assert_eq!("event-edit", posted[0].0);
+    for field in [
+        ("aename", "Study"),
+        ("aestarttime", "1930"),
+        ("aeendtime", "2200"),
+        ("aedays", "0101000"),
+        ("aetimestamp", "2023-07-02 10:00:00.0"),
+    ] {
+        assert!(posted[0]
+            .1
+            .contains(&(field.0.to_owned(), field.1.to_owned())));
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_edit_event_not_found_or_invalid() {
+    let (wrapper, transport) = wrapper("000000");
+    let res = wrapper
+        .req("FA23")
+        .parsed()
+        .edit_event("2023-07-03 10:00:00.0", |_| {})
+        .await;
+    assert!(matches!(
+        res,
+        Err(WrapperError::InputError("event_timestamp", _))
+    ));
+
+    // Moving the start 75 minutes later without carrying into the hour is invalid.
+    let res = wrapper
+        .req("FA23")
+        .parsed()
+        .edit_event("2023-07-01 10:00:00.0", |e| e.start_min += 75)
+        .await;
+    assert!(matches!(res, Err(WrapperError::InputError(..))));
+    assert!(posted_sections(&transport).is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_import_events_continues_after_failure() {
+    let (wrapper, transport) = wrapper("Nap");
+    let report = wrapper
+        .req("FA23")
+        .parsed()
+        .import_events([
+            event_add("Lunch", 12),
+            event_add("Nap", 13),
+            event_add("Run", 17),
+        ])
+        .await;
+
+    assert!(report.removed.is_empty());
+    assert_eq!(vec!["Nap"], event_names(&report.added, true));
+    assert_eq!(vec!["Lunch", "Run"], event_names(&report.added, false));
+    assert_eq!(3, posted_sections(&transport).len());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_replace_events_continues_after_failure() {
+    let (wrapper, transport) = wrapper("2023-07-01 10:00:00.0");
+    let report = wrapper
+        .req("FA23")
+        .parsed()
+        .replace_events([event_add("Lunch", 12), event_add("Run", 17)])
+        .await
+        .unwrap();
+
+    assert!(!report.is_success());
+    assert_eq!(vec!["Gym"], event_names(&report.removed, true));
+    assert_eq!(vec!["Study"], event_names(&report.removed, false));
+    assert_eq!(vec!["Lunch", "Run"], event_names(&report.added, false));
+
+    let endpoints = posted(&transport)
+        .into_iter()
+        .map(|(endpoint, _)| endpoint)
+        .collect::<Vec<_>>();
+    assert_eq!(
+        vec!["event-remove", "event-remove", "event-add", "event-add"],
+        endpoints
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_replace_events_validates_first() {
+    let (wrapper, transport) = wrapper("000000");
+    let res = wrapper
+        .req("FA23")
+        .parsed()
+        .replace_events([event_add("Lunch", 12), event_add("Midnight snack", 23)])
+        .await;
+
+    // The invalid event is caught before any existing event is removed.
+    assert!(matches!(res, Err(WrapperError::InputError(..))));
+    assert!(posted_sections(&transport).is_empty());
+}
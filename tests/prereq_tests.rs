@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+
+use webweg::prereq::{plan_next_term, PrereqGraph};
+use webweg::types::{CompletedCourse, CoursePrerequisite, PrerequisiteInfo};
+
+fn catalog() -> HashMap<&'static str, PrerequisiteInfo> {
+    let mut map = HashMap::new();
+    map.insert(
+        "MATH 20C",
+        PrerequisiteInfo {
+            course_prerequisites: vec![vec![
+                CoursePrerequisite::new("MATH 20B", "Calculus for Science & Engineering"),
+                CoursePrerequisite::new("MATH 10B", "Calculus II"),
+            ]],
+            exam_prerequisites: vec!["MATH PLACEMENT EXAM".into()],
+        },
+    );
+    map.insert(
+        "MATH 20B",
+        PrerequisiteInfo {
+            course_prerequisites: vec![vec![CoursePrerequisite::new(
+                "MATH 20A",
+                "Calculus for Science & Engineering",
+            )]],
+            exam_prerequisites: vec![],
+        },
+    );
+    map
+}
+
+async fn build(course: &str, depth: usize) -> PrereqGraph {
+    let catalog = catalog();
+    let (subj, crsc) = course.split_once(' ').unwrap();
+    PrereqGraph::build(subj, crsc, depth, |s, c| {
+        let info = catalog
+            .get(format!("{s} {c}").as_str())
+            .cloned()
+            .unwrap_or(PrerequisiteInfo {
+                course_prerequisites: vec![],
+                exam_prerequisites: vec![],
+            });
+        async move { Ok(info) }
+    })
+    .await
+    .unwrap()
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_full_chain() {
+    let graph = build("math 20C", 10).await;
+    assert_eq!("MATH 20C", graph.root);
+    assert_eq!(4, graph.nodes.len());
+    assert!(!graph.has_cycles());
+
+    let root = &graph.nodes["MATH 20C"];
+    assert_eq!(
+        vec![vec!["MATH 10B".to_string(), "MATH 20B".to_string()]],
+        root.course_prerequisites
+    );
+    assert_eq!(vec!["MATH PLACEMENT EXAM"], root.exam_prerequisites);
+    assert_eq!(2, graph.nodes["MATH 20A"].depth);
+    assert!(graph.nodes["MATH 20A"].expanded);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_depth_limit() {
+    let graph = build("MATH 20C", 1).await;
+    assert_eq!(3, graph.nodes.len());
+    assert!(graph.nodes["MATH 20C"].expanded);
+    assert!(!graph.nodes["MATH 20B"].expanded);
+    assert!(!graph.nodes.contains_key("MATH 20A"));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_cycle_detection() {
+    let graph = PrereqGraph::build("CSE", "1", 5, |_, c| async move {
+        let next = if c == "1" { "CSE 2" } else { "CSE 1" };
+        Ok(PrerequisiteInfo {
+            course_prerequisites: vec![vec![CoursePrerequisite::new(next, "")]],
+            exam_prerequisites: vec![],
+        })
+    })
+    .await
+    .unwrap();
+
+    assert_eq!(
+        vec![vec!["CSE 1".to_string(), "CSE 2".to_string()]],
+        graph.cycles
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_dot_and_json() {
+    let graph = build("MATH 20C", 10).await;
+    let dot = graph.to_dot();
+    assert!(dot.starts_with("digraph prerequisites {"));
+    assert!(dot.contains("\"MATH 20C\" -> \"MATH 20C#or0\";"));
+    assert!(dot.contains("\"MATH 20C#or0\" -> \"MATH 20B\";"));
+    assert!(dot.contains("\"MATH 20B\" -> \"MATH 20A\";"));
+    assert!(dot.contains("[style=dashed]"));
+
+    let value: serde_json::Value = serde_json::from_str(&graph.to_json().unwrap()).unwrap();
+    assert_eq!("MATH 20C", value["root"]);
+    assert_eq!(
+        "MATH 20A",
+        value["nodes"]["MATH 20B"]["course_prerequisites"][0][0]
+    );
+}
+
+#[test]
+fn test_is_satisfied_by() {
+    let catalog = catalog();
+    let math_20c = &catalog["MATH 20C"];
+    let no_exams: [&str; 0] = [];
+
+    assert!(!math_20c.is_satisfied_by(&[], &no_exams));
+    assert!(math_20c.is_satisfied_by(&[CompletedCourse::new("math 10b")], &no_exams));
+    assert!(math_20c.is_satisfied_by(&[], &["Math Placement Exam"]));
+
+    let unmet = math_20c.unmet_requirements(&[CompletedCourse::new("MATH 20A")], &no_exams);
+    assert_eq!(math_20c.course_prerequisites, unmet);
+}
+
+#[test]
+fn test_min_grade() {
+    let prereqs = PrerequisiteInfo {
+        course_prerequisites: vec![vec![
+            CoursePrerequisite::new("MATH 20B", "Calculus").with_min_grade(450)
+        ]],
+        exam_prerequisites: vec![],
+    };
+    let no_exams: [&str; 0] = [];
+
+    assert!(prereqs.is_satisfied_by(
+        &[CompletedCourse::new("MATH 20B").with_grade(450)],
+        &no_exams
+    ));
+    assert!(!prereqs.is_satisfied_by(
+        &[CompletedCourse::new("MATH 20B").with_grade(400)],
+        &no_exams
+    ));
+    assert!(prereqs.is_satisfied_by(&[CompletedCourse::new("MATH 20B")], &no_exams));
+}
+
+#[test]
+fn test_below_min_grade() {
+    let prereq = CoursePrerequisite::new("CSE 8B", "Intro to Programming 2").with_min_grade(600);
+    let prereqs = PrerequisiteInfo {
+        course_prerequisites: vec![vec![prereq.clone()]],
+        exam_prerequisites: vec![],
+    };
+    let no_exams: [&str; 0] = [];
+
+    // 450 and 600 are both minimums that WebReg uses, and 450 is the lower of the two.
+    let below = [CompletedCourse::new("CSE 8B").with_grade(450)];
+    assert!(!CoursePrerequisite::meets_min_grade(450, 600));
+    assert!(!prereq.is_satisfied_by(&below[0]));
+    assert!(!prereqs.is_satisfied_by(&below, &no_exams));
+    assert_eq!(
+        prereqs.course_prerequisites,
+        prereqs.unmet_requirements(&below, &no_exams)
+    );
+
+    let above = CompletedCourse::new("CSE 8B").with_grade(650);
+    assert!(CoursePrerequisite::meets_min_grade(600, 600));
+    assert!(prereq.is_satisfied_by(&above));
+    assert!(prereqs.unmet_requirements(&[above], &no_exams).is_empty());
+}
+
+#[test]
+fn test_plan_next_term() {
+    let catalog = catalog();
+    let completed = [CompletedCourse::new("MATH 20A")];
+    let plan = plan_next_term(
+        [
+            ("MATH 20A", &catalog["MATH 20B"]),
+            ("MATH 20B", &catalog["MATH 20B"]),
+            ("MATH 20C", &catalog["MATH 20C"]),
+        ],
+        &completed,
+        &[] as &[&str],
+    );
+
+    assert!(plan[0].already_completed);
+    assert!(!plan[0].can_take());
+    assert!(plan[1].can_take());
+    assert!(!plan[2].can_take());
+    assert_eq!(1, plan[2].unmet.len());
+}
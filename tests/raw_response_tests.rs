@@ -0,0 +1,56 @@
+use webweg::types::RawResponse;
+
+#[test]
+fn test_is_success() {
+    let ok = RawResponse {
+        status: 200,
+        headers: vec![],
+        body: r#"{"OPS":"SUCCESS"}"#.into(),
+    };
+    assert!(ok.is_success());
+
+    for status in [199, 302, 404, 500] {
+        let res = RawResponse {
+            status,
+            headers: vec![],
+            body: String::new(),
+        };
+        assert!(!res.is_success());
+    }
+}
+
+#[test]
+fn test_json() {
+    let res = RawResponse {
+        status: 200,
+        headers: vec![],
+        body: r#"{"OPS":"FAIL","REASON":"<b>Section is full</b>"}"#.into(),
+    };
+    let json = res.json().unwrap();
+    assert_eq!("FAIL", json["OPS"]);
+    assert_eq!("<b>Section is full</b>", json["REASON"]);
+
+    let html = RawResponse {
+        status: 200,
+        headers: vec![],
+        body: "<html></html>".into(),
+    };
+    assert!(html.json().is_err());
+}
+
+#[test]
+fn test_header() {
+    let res = RawResponse {
+        status: 200,
+        headers: vec![
+            ("Content-Type".into(), "application/json".into()),
+            ("set-cookie".into(), "a=1".into()),
+            ("Set-Cookie".into(), "b=2".into()),
+        ],
+        body: String::new(),
+    };
+
+    assert_eq!(Some("application/json"), res.header("content-type"));
+    assert_eq!(Some("a=1"), res.header("SET-COOKIE"));
+    assert_eq!(None, res.header("location"));
+}
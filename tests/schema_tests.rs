@@ -0,0 +1,102 @@
+use serde_json::{json, Value};
+use webweg::raw_types::{
+    RawDepartmentElement, RawEvent, RawPrerequisite, RawScheduledMeeting, RawSubjectElement,
+    RawWebRegMeeting,
+};
+use webweg::schema::{check_schema, RawSchema, SchemaDrift};
+
+fn check<T: RawSchema>(json: &str) -> SchemaDrift {
+    check_schema::<T>("test", json).unwrap()
+}
+
+#[test]
+fn test_fixtures_have_no_drift() {
+    for json in [
+        include_str!("json/courseinfo1.json"),
+        include_str!("json/courseinfo2.json"),
+        include_str!("json/courseinfo3.json"),
+        include_str!("json/courseinfo4.json"),
+    ] {
+        let drift = check::<RawWebRegMeeting>(json);
+        assert!(drift.is_empty(), "{drift}");
+    }
+
+    for json in [
+        include_str!("json/schedule1.json"),
+        include_str!("json/schedule2.json"),
+    ] {
+        let drift = check::<RawScheduledMeeting>(json);
+        assert!(drift.is_empty(), "{drift}");
+    }
+
+    for json in [
+        include_str!("json/prereq1.json"),
+        include_str!("json/prereq4.json"),
+        include_str!("json/prereq5.json"),
+    ] {
+        let drift = check::<RawPrerequisite>(json);
+        assert!(drift.is_empty(), "{drift}");
+    }
+
+    assert!(check::<RawSubjectElement>(include_str!("json/subjects1.json")).is_empty());
+    assert!(check::<RawDepartmentElement>(include_str!("json/departments1.json")).is_empty());
+}
+
+#[test]
+fn test_unknown_and_missing_fields() {
+    let mut raw =
+        serde_json::from_str::<Vec<Value>>(include_str!("json/courseinfo1.json")).unwrap();
+    raw[0]["WAITLIST_LIMIT"] = json!(30);
+    raw[1]["WAITLIST_LIMIT"] = json!(40);
+    raw[1].as_object_mut().unwrap().remove("AVAIL_SEAT");
+
+    let drift = check::<RawWebRegMeeting>(&serde_json::to_string(&raw).unwrap());
+    assert_eq!(raw.len(), drift.records);
+    assert_eq!(1, drift.unknown_fields.len());
+    // Only the first value seen is kept.
+    assert_eq!(json!(30), drift.unknown_fields["WAITLIST_LIMIT"]);
+    assert_eq!(
+        vec!["AVAIL_SEAT"],
+        drift.missing_fields.iter().collect::<Vec<_>>()
+    );
+    assert!(drift
+        .to_string()
+        .contains("unknown: WAITLIST_LIMIT=30, missing: AVAIL_SEAT"));
+}
+
+#[test]
+fn test_optional_fields_are_not_missing() {
+    let mut raw = serde_json::from_str::<Vec<Value>>(include_str!("json/schedule1.json")).unwrap();
+    for record in &mut raw {
+        record.as_object_mut().unwrap().remove("COUNT_ON_WAITLIST");
+    }
+
+    assert!(check::<RawScheduledMeeting>(&serde_json::to_string(&raw).unwrap()).is_empty());
+}
+
+#[test]
+fn test_tagged_prerequisites() {
+    let drift = check::<RawPrerequisite>(
+        r#"[
+            {"TYPE": "TEST", "TEST_TITLE": "AP Calculus", "TEST_SCORE": 4},
+            {"TYPE": "COURSE", "SUBJECT_CODE": "MATH", "CRSE_TITLE": "Calculus",
+             "COURSE_CODE": "20A", "GRADE_SEQ_ID": "450"}
+        ]"#,
+    );
+
+    assert!(drift.unknown_fields.contains_key("TEST_SCORE"));
+    assert!(drift.missing_fields.contains("PREREQ_SEQ_ID"));
+    assert_eq!(2, drift.unknown_fields.len() + drift.missing_fields.len());
+}
+
+#[test]
+fn test_single_record_and_invalid_json() {
+    let drift = check::<RawEvent>(
+        r#"{"LOCATION": "", "START_TIME": "0900", "END_TIME": "1000",
+            "DESCRIPTION": "Study", "DAYS": "1010100", "TIME_STAMP": "abc"}"#,
+    );
+    assert_eq!(1, drift.records);
+    assert!(drift.is_empty());
+
+    assert!(check_schema::<RawEvent>("test", "not json").is_err());
+}
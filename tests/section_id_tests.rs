@@ -0,0 +1,40 @@
+use std::collections::HashSet;
+
+use webweg::types::SectionId;
+
+#[test]
+fn test_leading_zeros_are_equal() {
+    let padded: SectionId = "079911".parse().unwrap();
+    let unpadded: SectionId = "79911".parse().unwrap();
+    assert_eq!(padded, unpadded);
+    assert_eq!(padded, SectionId::try_from(79911_i64).unwrap());
+
+    let set = HashSet::from([padded, unpadded]);
+    assert_eq!(1, set.len());
+}
+
+#[test]
+fn test_display_forms() {
+    let id = SectionId::new(79911);
+    assert_eq!("079911", id.to_string());
+    assert_eq!("079911", id.padded());
+    assert_eq!("79911", id.unpadded());
+    assert_eq!(79911, id.value());
+}
+
+#[test]
+fn test_invalid_section_ids() {
+    for bad in ["", "abc", "12a45", "-1", "99999999999"] {
+        assert!(bad.parse::<SectionId>().is_err(), "{bad} should not parse");
+    }
+
+    assert!(SectionId::try_from(-1_i64).is_err());
+}
+
+#[test]
+fn test_serde() {
+    let id = SectionId::new(79911);
+    assert_eq!("\"079911\"", serde_json::to_string(&id).unwrap());
+    assert_eq!(id, serde_json::from_str::<SectionId>("\"79911\"").unwrap());
+    assert_eq!(id, serde_json::from_str::<SectionId>("79911").unwrap());
+}
@@ -0,0 +1,82 @@
+use std::sync::Arc;
+
+use webweg::wrapper::session_pool::{SelectionStrategy, SessionPool};
+use webweg::wrapper::transport::InMemoryTransport;
+use webweg::wrapper::WebRegWrapper;
+
+mod common;
+
+fn session(ok: bool) -> WebRegWrapper {
+    common::wrapper(&Arc::new(
+        InMemoryTransport::new()
+            .with_json("secure/ping-server", format!(r#"{{"SESSION_OK":{ok}}}"#)),
+    ))
+}
+
+fn pool(health: &[bool], strategy: SelectionStrategy) -> SessionPool {
+    SessionPool::new(health.iter().map(|&ok| session(ok)), strategy)
+}
+
+fn next_indices(pool: &SessionPool, n: usize) -> Vec<usize> {
+    (0..n).map(|_| pool.get().unwrap().index()).collect()
+}
+
+#[test]
+fn test_round_robin() {
+    let pool = pool(&[true, true, true], SelectionStrategy::RoundRobin);
+    assert_eq!(vec![0, 1, 2, 0, 1], next_indices(&pool, 5));
+
+    pool.mark_unhealthy(2);
+    assert_eq!(vec![0, 1, 0], next_indices(&pool, 3));
+    assert_eq!(2, pool.healthy_count());
+
+    pool.mark_healthy(2);
+    assert_eq!(vec![1, 2, 0], next_indices(&pool, 3));
+}
+
+#[test]
+fn test_least_recently_used() {
+    let pool = pool(&[true, true, true], SelectionStrategy::LeastRecentlyUsed);
+    assert_eq!(vec![0, 1, 2], next_indices(&pool, 3));
+
+    let health = pool.health();
+    assert!(health.iter().all(|h| h.uses == 1 && h.last_used.is_some()));
+    assert!(health[0].last_used <= health[1].last_used);
+
+    pool.mark_unhealthy(0);
+    assert_eq!(1, pool.get().unwrap().index());
+}
+
+#[test]
+fn test_empty_or_unhealthy_pool() {
+    let empty = SessionPool::new([], SelectionStrategy::RoundRobin);
+    assert!(empty.is_empty());
+    assert!(empty.get().is_none());
+
+    let pool = pool(&[true], SelectionStrategy::LeastRecentlyUsed);
+    pool.mark_unhealthy(0);
+    assert!(pool.get().is_none());
+    assert!(pool.session(0).is_some());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_check_health() {
+    let pool = pool(&[true, false, true], SelectionStrategy::RoundRobin);
+    assert_eq!(3, pool.healthy_count());
+
+    let health = pool.check_health().await;
+    assert_eq!(
+        vec![true, false, true],
+        health.iter().map(|h| h.healthy).collect::<Vec<_>>()
+    );
+    assert_eq!(1, health[1].consecutive_failures);
+    assert!(health.iter().all(|h| h.last_checked.is_some()));
+
+    assert_eq!(vec![0, 2, 0], next_indices(&pool, 3));
+
+    let health = pool.check_health().await;
+    assert_eq!(2, health[1].consecutive_failures);
+
+    // The pooled session can be used like a wrapper.
+    assert!(pool.get().unwrap().ping_server().await);
+}
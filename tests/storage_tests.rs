@@ -0,0 +1,188 @@
+#![cfg(feature = "storage-sqlite")]
+
+use webweg::export::SnapshotContext;
+use webweg::storage::SqliteStorage;
+use webweg::types::SectionId;
+
+mod common;
+
+use common::cse_101;
+
+#[test]
+fn test_ingest_dedupes_unchanged() {
+    let mut storage = SqliteStorage::open_in_memory().unwrap();
+    let courses = cse_101();
+
+    let summary = storage
+        .ingest_courses(&SnapshotContext::new("FA23", 1000), &courses)
+        .unwrap();
+    assert_eq!(1, summary.changed);
+
+    let summary = storage
+        .ingest_courses(&SnapshotContext::new("FA23", 2000), &courses)
+        .unwrap();
+    assert_eq!(0, summary.changed);
+    assert_eq!(1, summary.unchanged);
+
+    let series = storage.section_time_series("FA23", "260739", None).unwrap();
+    assert_eq!(1, series.len());
+    assert_eq!(1000, series[0].first_seen);
+    assert_eq!(2000, series[0].last_seen);
+    assert_eq!(329, series[0].enrolled);
+}
+
+#[test]
+fn test_time_series_records_changes() {
+    let mut storage = SqliteStorage::open_in_memory().unwrap();
+    let mut courses = cse_101();
+    storage
+        .ingest_courses(&SnapshotContext::new("FA23", 1000), &courses)
+        .unwrap();
+
+    courses[0].enrolled_ct += 1;
+    courses[0].waitlist_ct -= 1;
+    storage
+        .ingest_courses(&SnapshotContext::new("FA23", 2000), &courses)
+        .unwrap();
+
+    let series = storage.section_time_series("FA23", "260739", None).unwrap();
+    assert_eq!(2, series.len());
+    assert_eq!(330, series[1].enrolled);
+    assert_eq!(124, series[1].waitlist);
+
+    let recent = storage
+        .section_time_series("FA23", "260739", Some(1500))
+        .unwrap();
+    assert_eq!(1, recent.len());
+    assert_eq!(2000, recent[0].first_seen);
+
+    // Different term, so nothing should be found.
+    assert!(storage
+        .section_time_series("WI24", "260739", None)
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn test_latest_snapshot_round_trips() {
+    let mut storage = SqliteStorage::open_in_memory().unwrap();
+    let courses = cse_101();
+    storage
+        .ingest_courses(&SnapshotContext::new("FA23", 1000), &courses)
+        .unwrap();
+
+    assert_eq!(courses, storage.latest_snapshot("FA23").unwrap());
+    assert_eq!(
+        Some(courses[0].clone()),
+        storage.latest_section("FA23", "260739").unwrap()
+    );
+    assert_eq!(
+        Some(SectionId::new(260739)),
+        storage.find_section_id("FA23", "cse 101", "a01").unwrap()
+    );
+    assert_eq!(None, storage.latest_section("FA23", "000000").unwrap());
+}
+
+#[test]
+fn test_count_only_ingest_keeps_meetings() {
+    let mut storage = SqliteStorage::open_in_memory().unwrap();
+    let courses = cse_101();
+    storage
+        .ingest_courses(&SnapshotContext::new("FA23", 1000), &courses)
+        .unwrap();
+
+    // Enrollment counts (e.g., from `get_enrollment_count`) don't have meetings.
+    let mut counts = courses.clone();
+    counts[0].meetings.clear();
+    counts[0].enrolled_ct += 5;
+    storage
+        .ingest_courses(&SnapshotContext::new("FA23", 2000), &counts)
+        .unwrap();
+
+    let latest = storage.latest_section("FA23", "260739").unwrap().unwrap();
+    assert_eq!(courses[0].meetings, latest.meetings);
+    assert_eq!(courses[0].enrolled_ct + 5, latest.enrolled_ct);
+}
+
+#[test]
+fn test_duplicate_instructors_are_stored_once() {
+    let mut storage = SqliteStorage::open_in_memory().unwrap();
+    let mut courses = cse_101();
+    let names = ["Smith, John", "Doe, Jane", "Smith, John"].map(String::from);
+    courses[0].all_instructors = names.to_vec();
+    courses[0].meetings[0].instructors = names.to_vec();
+
+    for timestamp in [1000, 2000] {
+        storage
+            .ingest_courses(&SnapshotContext::new("FA23", timestamp), &courses)
+            .unwrap();
+    }
+
+    let latest = storage.latest_section("FA23", "260739").unwrap().unwrap();
+    let distinct = names[..2].to_vec();
+    assert_eq!(distinct, latest.all_instructors);
+    assert_eq!(distinct, latest.meetings[0].instructors);
+    assert_eq!(
+        1,
+        storage
+            .section_time_series("FA23", "260739", None)
+            .unwrap()
+            .len()
+    );
+}
+
+#[test]
+fn test_meeting_and_instructor_history() {
+    let mut storage = SqliteStorage::open_in_memory().unwrap();
+    let original = cse_101();
+    let mut moved = original.clone();
+    moved[0].meetings[0].room = "999".into();
+    moved[0].all_instructors = vec!["Doe, Jane".into()];
+
+    for (timestamp, courses) in [(1000, &original), (2000, &moved), (3000, &moved)] {
+        storage
+            .ingest_courses(&SnapshotContext::new("FA23", timestamp), courses)
+            .unwrap();
+    }
+
+    // The room and instructor change doesn't overwrite what the section used to look like.
+    let before = storage.section_at("FA23", "260739", 1500).unwrap().unwrap();
+    assert_eq!(original[0], before);
+    let after = storage.section_at("FA23", "260739", 2500).unwrap().unwrap();
+    assert_eq!(moved[0], after);
+    assert_eq!(
+        Some(moved[0].clone()),
+        storage.latest_section("FA23", "260739").unwrap()
+    );
+    assert_eq!(None, storage.section_at("FA23", "260739", 500).unwrap());
+}
+
+#[test]
+fn test_lookups_are_normalized() {
+    let mut storage = SqliteStorage::open_in_memory().unwrap();
+    let mut courses = cse_101();
+    courses[0].subj_course_id = " cse 101 ".into();
+    courses[0].section_code = "a01".into();
+    storage
+        .ingest_courses(&SnapshotContext::new(" fa23", 1000), &courses)
+        .unwrap();
+
+    for term in ["FA23", "fa23 "] {
+        assert_eq!(
+            Some(SectionId::new(260739)),
+            storage.find_section_id(term, "CSE 101", " A01").unwrap()
+        );
+        assert_eq!(
+            1,
+            storage
+                .section_time_series(term, "260739", None)
+                .unwrap()
+                .len()
+        );
+        assert_eq!(1, storage.latest_snapshot(term).unwrap().len());
+
+        let section = storage.latest_section(term, "260739").unwrap().unwrap();
+        assert_eq!("CSE 101", section.subj_course_id);
+        assert_eq!("A01", section.section_code);
+    }
+}
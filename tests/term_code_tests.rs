@@ -0,0 +1,73 @@
+use webweg::types::{Quarter, Term, TermCode, WrapperError};
+
+#[test]
+fn test_parse_and_display() {
+    let term: TermCode = "S123".parse().unwrap();
+    assert_eq!(Quarter::SummerSession1, term.quarter());
+    assert_eq!(2023, term.year());
+    assert_eq!("S123", term.to_string());
+
+    let term: TermCode = " fa05 ".parse().unwrap();
+    assert_eq!("FA05", term.to_string());
+    assert_eq!("Fall 2005", term.description());
+}
+
+#[test]
+fn test_parse_errors() {
+    for bad in ["XX24", "WI2T", "FA2023", "", "ＦＡ"] {
+        assert!(
+            matches!(
+                bad.parse::<TermCode>(),
+                Err(WrapperError::TermParseError(..))
+            ),
+            "{bad} should not parse"
+        );
+    }
+}
+
+#[test]
+fn test_seq_id_matches_util() {
+    for term in [
+        "SP22", "S122", "S222", "S322", "SU22", "FA22", "WI23", "FA23", "WI22",
+    ] {
+        assert_eq!(
+            webweg::util::get_term_seq_id(term),
+            term.parse::<TermCode>().unwrap().seq_id()
+        );
+    }
+
+    assert_eq!(5320, "FA23".parse::<TermCode>().unwrap().seq_id());
+}
+
+#[test]
+fn test_ordering_and_navigation() {
+    let mut terms = ["FA23", "WI23", "S123", "SP23", "WI24", "SU23"]
+        .map(|t| t.parse::<TermCode>().unwrap())
+        .to_vec();
+    terms.sort();
+    assert_eq!(
+        vec!["WI23", "SP23", "S123", "SU23", "FA23", "WI24"],
+        terms.iter().map(|t| t.to_string()).collect::<Vec<_>>()
+    );
+
+    let fa23: TermCode = "FA23".parse().unwrap();
+    assert_eq!("WI24", fa23.next().to_string());
+    assert_eq!("SU23", fa23.prev().to_string());
+    assert_eq!(fa23, fa23.next().prev());
+    assert_eq!(fa23.seq_id() + 10, fa23.next().seq_id());
+
+    assert_eq!(None, TermCode::new(Quarter::Fall, 1999));
+}
+
+#[test]
+fn test_serde_and_term() {
+    let term: TermCode = "SP24".parse().unwrap();
+    assert_eq!("\"SP24\"", serde_json::to_string(&term).unwrap());
+    assert_eq!(term, serde_json::from_str::<TermCode>("\"sp24\"").unwrap());
+    assert!(serde_json::from_str::<TermCode>("\"XX24\"").is_err());
+
+    let t = Term::from(term);
+    assert_eq!("SP24", t.term_code);
+    assert_eq!("Spring 2024", t.term_desc);
+    assert_eq!(term, t.code().unwrap());
+}
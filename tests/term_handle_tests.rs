@@ -0,0 +1,66 @@
+use std::sync::Arc;
+
+use webweg::types::TermCode;
+use webweg::wrapper::api::WebRegApi;
+use webweg::wrapper::term_handle::TermHandle;
+use webweg::wrapper::transport::InMemoryTransport;
+
+mod common;
+
+use common::{wrapper, COOKIES};
+
+#[test]
+fn test_handle_is_owned() {
+    fn assert_owned<T: Clone + Send + Sync + 'static>() {}
+    assert_owned::<TermHandle>();
+
+    let transport = Arc::new(InMemoryTransport::new());
+    let code: TermCode = "fa23".parse().unwrap();
+    let handle = wrapper(&transport).term_handle(code);
+    assert_eq!("FA23", handle.term());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_handle_in_spawned_task() {
+    let transport = Arc::new(
+        InMemoryTransport::new()
+            .with_json("secure/get-class", include_str!("json/schedule1.json"))
+            .with_json("secure/sched-get-schednames", r#"["My Schedule"]"#),
+    );
+    let handle = wrapper(&transport).term_handle("FA23");
+
+    let cloned = handle.clone();
+    let schedule = tokio::spawn(async move { cloned.parsed().get_schedule(None).await })
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(!schedule.is_empty());
+
+    // The handle can be used through the trait, and with per-request overrides.
+    assert_eq!(
+        schedule.len(),
+        WebRegApi::get_schedule(&handle, None).await.unwrap().len()
+    );
+    assert!(handle
+        .req()
+        .override_cookies("def")
+        .raw()
+        .get_schedule_list()
+        .await
+        .is_ok());
+
+    // Changing the wrapper's cookies is seen by the handle.
+    handle.wrapper().set_cookies("ghi");
+    handle.raw().get_schedule_list().await.unwrap();
+
+    let cookies = transport
+        .requests()
+        .iter()
+        .map(|r| r.header("cookie").unwrap().to_owned())
+        .collect::<Vec<_>>();
+    assert_eq!(vec![COOKIES, COOKIES, "def", "ghi"], cookies);
+    assert!(transport.requests().iter().all(|r| r
+        .url
+        .query_pairs()
+        .any(|(k, v)| k == "termcode" && v == "FA23")));
+}
@@ -0,0 +1,433 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+
+use reqwest::Client;
+use webweg::types::{RawResponse, WrapperError};
+use webweg::wrapper::input_types::HttpMethod;
+use webweg::wrapper::transport::{
+    HttpRequest, InMemoryTransport, ReqwestTransport, Transport, TransportFuture,
+};
+use webweg::wrapper::WebRegWrapper;
+
+mod common;
+
+use common::{wrapper, COOKIES, USER_AGENT};
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_parsed_requests_use_canned_responses() {
+    let transport = Arc::new(
+        InMemoryTransport::new()
+            .with_json("secure/get-class", include_str!("json/schedule1.json"))
+            .with_json(
+                "https://act.ucsd.edu/webreg2/svc/wradapter/secure/search-load-subject",
+                include_str!("json/subjects1.json"),
+            ),
+    );
+    let wrapper = wrapper(&transport);
+    let term = wrapper.req("FA23").parsed();
+
+    let schedule = term.get_schedule(None).await.unwrap();
+    assert!(!schedule.is_empty());
+    assert!(!term.get_subject_codes().await.unwrap().is_empty());
+
+    let requests = transport.requests();
+    assert_eq!(2, requests.len());
+    assert_eq!(HttpMethod::Get, requests[0].method);
+    assert!(requests[0]
+        .url
+        .query_pairs()
+        .any(|(k, v)| k == "termcode" && v == "FA23"));
+    assert_eq!(Some(COOKIES), requests[0].header("cookie"));
+    assert_eq!(Some(USER_AGENT), requests[0].header("User-Agent"));
+    assert_eq!(None, requests[0].body);
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_post_form() {
+    let transport =
+        Arc::new(InMemoryTransport::new().with_json("secure/event-remove", r#"{"OPS":"SUCCESS"}"#));
+    let wrapper = wrapper(&transport);
+
+    assert!(wrapper
+        .req("FA23")
+        .parsed()
+        .remove_event("2023-07-01 10:00:00.0")
+        .await
+        .unwrap());
+
+    let requests = transport.requests();
+    assert_eq!(HttpMethod::Post, requests[0].method);
+    assert_eq!(
+        Some("application/x-www-form-urlencoded"),
+        requests[0].header("content-type")
+    );
+    assert_eq!(
+        vec![
+            ("aetimestamp".to_owned(), "2023-07-01 10:00:00.0".to_owned()),
+            ("termcode".to_owned(), "FA23".to_owned()),
+        ],
+        requests[0].form()
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_error_responses() {
+    let transport = Arc::new(
+        InMemoryTransport::new()
+            .with_json(
+                "secure/plan-remove-all",
+                r#"{"OPS":"FAIL","REASON":"<b>Not in plan</b>"}"#,
+            )
+            .with_response(
+                "secure/sched-get-schednames",
+                RawResponse {
+                    status: 500,
+                    headers: vec![],
+                    body: "oops".into(),
+                },
+            ),
+    );
+    let wrapper = wrapper(&transport);
+    let term = wrapper.req("FA23");
+
+    let raw = term.raw();
+    let res = raw.remove_from_all_plans("123456").await.unwrap();
+    assert!(res.is_success());
+    assert_eq!("FAIL", res.json().unwrap()["OPS"]);
+
+    let parsed = wrapper.req("FA23").parsed();
+    assert!(matches!(
+        parsed.remove_from_plan("123456", None).await,
+        Err(WrapperError::BadStatusCode(404, _))
+    ));
+    assert!(matches!(
+        parsed.get_schedule_list().await,
+        Err(WrapperError::BadStatusCode(500, Some(ref body))) if body == "oops"
+    ));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_override_transport() {
+    let wrapper_transport = Arc::new(InMemoryTransport::new());
+    let request_transport = Arc::new(
+        InMemoryTransport::new().with_json("secure/sched-get-schednames", r#"["My Schedule"]"#),
+    );
+    let wrapper = wrapper(&wrapper_transport);
+
+    let schedules = wrapper
+        .req("FA23")
+        .override_transport(request_transport.clone())
+        .parsed()
+        .get_schedule_list()
+        .await
+        .unwrap();
+
+    assert_eq!(vec!["My Schedule"], schedules);
+    assert!(wrapper_transport.requests().is_empty());
+    assert_eq!(1, request_transport.requests().len());
+}
+
+type Sent = Arc<Mutex<Vec<(usize, String)>>>;
+
+/// A transport that records which instance sent each request, and creates a new instance
+/// whenever an isolated transport is needed.
+struct Tagged {
+    id: usize,
+    next_id: Arc<AtomicUsize>,
+    sent: Sent,
+}
+
+impl Tagged {
+    fn new(sent: &Sent) -> Self {
+        Self {
+            id: 0,
+            next_id: Arc::new(AtomicUsize::new(1)),
+            sent: sent.clone(),
+        }
+    }
+}
+
+impl Transport for Tagged {
+    fn send(&self, req: HttpRequest) -> TransportFuture<'_> {
+        let cookies = req.header("cookie").unwrap_or_default().to_owned();
+        self.sent.lock().unwrap().push((self.id, cookies));
+        Box::pin(async {
+            Ok(RawResponse {
+                status: 200,
+                headers: vec![],
+                body: "[]".into(),
+            })
+        })
+    }
+
+    fn isolated(&self) -> Option<Arc<dyn Transport>> {
+        Some(Arc::new(Tagged {
+            id: self.next_id.fetch_add(1, Ordering::SeqCst),
+            next_id: self.next_id.clone(),
+            sent: self.sent.clone(),
+        }))
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_override_cookies_uses_separate_transports() {
+    let sent = Sent::default();
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("main")
+        .with_transport(Tagged::new(&sent))
+        .with_max_override_transports(2)
+        .try_build_wrapper()
+        .unwrap();
+
+    for cookies in [None, Some("a"), Some("b"), Some("a"), Some("c"), Some("b")] {
+        let req = wrapper.req("FA23");
+        let req = match cookies {
+            Some(c) => req.override_cookies(c),
+            None => req,
+        };
+        req.raw().get_schedule(None).await.unwrap();
+    }
+
+    // "b" was the least recently used transport when "c" was added, so "b" gets a new one.
+    assert_eq!(
+        vec![
+            (0, "main".to_owned()),
+            (1, "a".to_owned()),
+            (2, "b".to_owned()),
+            (1, "a".to_owned()),
+            (3, "c".to_owned()),
+            (4, "b".to_owned()),
+        ],
+        *sent.lock().unwrap()
+    );
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_override_cookies_with_shared_transport() {
+    let sent = Sent::default();
+    let closing = WebRegWrapper::builder()
+        .with_cookies("main")
+        .with_transport(Tagged::new(&sent))
+        .should_close_after_request(true)
+        .try_build_wrapper()
+        .unwrap();
+    closing
+        .req("FA23")
+        .override_cookies("a")
+        .raw()
+        .get_schedule(None)
+        .await
+        .unwrap();
+
+    assert_eq!(vec![(0, "a".to_owned())], *sent.lock().unwrap());
+
+    // An overridden transport is used as-is, but it may be shared with other sessions, so the
+    // connection is closed unless the transport is shareable.
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("main")
+        .with_transport(Tagged::new(&sent))
+        .try_build_wrapper()
+        .unwrap();
+    let pooled = Arc::new(Pooled::default());
+    wrapper
+        .req("FA23")
+        .override_transport(pooled.clone())
+        .override_cookies("b")
+        .raw()
+        .get_schedule(None)
+        .await
+        .unwrap();
+    wrapper
+        .req("FA23")
+        .override_transport(pooled.clone())
+        .raw()
+        .get_schedule(None)
+        .await
+        .unwrap();
+
+    let shareable = Arc::new(InMemoryTransport::new().with_json("secure/get-class", "[]"));
+    wrapper
+        .req("FA23")
+        .override_transport(shareable.clone())
+        .override_cookies("c")
+        .raw()
+        .get_schedule(None)
+        .await
+        .unwrap();
+
+    assert_eq!(1, sent.lock().unwrap().len());
+    assert_eq!(
+        vec![Some("close".to_owned()), None],
+        *pooled.connection.lock().unwrap()
+    );
+    assert_eq!(None, shareable.requests()[0].header("connection"));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_override_cookies_without_cache() {
+    let sent = Sent::default();
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("main")
+        .with_transport(Tagged::new(&sent))
+        .with_max_override_transports(0)
+        .try_build_wrapper()
+        .unwrap();
+
+    for cookies in ["a", "a", "b"] {
+        wrapper
+            .req("FA23")
+            .override_cookies(cookies)
+            .raw()
+            .get_schedule(None)
+            .await
+            .unwrap();
+    }
+
+    // Nothing is cached, but the wrapper's transport is still never used.
+    assert_eq!(
+        vec![
+            (1, "a".to_owned()),
+            (2, "a".to_owned()),
+            (3, "b".to_owned()),
+        ],
+        *sent.lock().unwrap()
+    );
+}
+
+/// A transport that keeps connections open but can't be isolated, and records the
+/// `Connection` header of each request.
+#[derive(Default)]
+struct Pooled {
+    connection: Mutex<Vec<Option<String>>>,
+}
+
+impl Transport for Pooled {
+    fn send(&self, req: HttpRequest) -> TransportFuture<'_> {
+        let connection = req.header("connection").map(|c| c.to_owned());
+        self.connection.lock().unwrap().push(connection);
+        Box::pin(async {
+            Ok(RawResponse {
+                status: 200,
+                headers: vec![],
+                body: "[]".into(),
+            })
+        })
+    }
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_override_cookies_closes_unisolated_connections() {
+    let transport = Arc::new(Pooled::default());
+    let wrapper = WebRegWrapper::builder()
+        .with_cookies("main")
+        .with_transport(transport.clone())
+        .try_build_wrapper()
+        .unwrap();
+
+    wrapper.req("FA23").raw().get_schedule(None).await.unwrap();
+    wrapper
+        .req("FA23")
+        .override_cookies("a")
+        .raw()
+        .get_schedule(None)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        vec![None, Some("close".to_owned())],
+        *transport.connection.lock().unwrap()
+    );
+}
+
+#[test]
+fn test_reqwest_transport_isolation() {
+    assert!(ReqwestTransport::new(Client::new()).isolated().is_none());
+    assert!(ReqwestTransport::with_factory(Client::new)
+        .isolated()
+        .is_some());
+    assert!(ReqwestTransport::default().isolated().is_some());
+    assert!(InMemoryTransport::new().is_shareable());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_clones_share_cookies() {
+    fn assert_shareable<T: Clone + Send + Sync + 'static>() {}
+    assert_shareable::<WebRegWrapper>();
+
+    let transport = Arc::new(InMemoryTransport::new().with_json("secure/get-class", "[]"));
+    let wrapper = wrapper(&transport);
+    let clone = wrapper.clone();
+
+    clone.set_cookies("new cookies");
+    wrapper.req("FA23").raw().get_schedule(None).await.unwrap();
+    let handle = tokio::spawn(async move {
+        clone.req("FA23").raw().get_schedule(None).await.unwrap();
+    });
+    handle.await.unwrap();
+
+    let requests = transport.requests();
+    assert_eq!(2, requests.len());
+    assert!(requests
+        .iter()
+        .all(|r| r.header("cookie") == Some("new cookies")));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_call_relative_endpoint() {
+    let transport = Arc::new(InMemoryTransport::new().with_json("secure/get-class", "[]"));
+    let wrapper = wrapper(&transport);
+
+    let res = wrapper
+        .req("FA23")
+        .raw()
+        .call("/secure/get-class", HttpMethod::Get, &[("final", "")])
+        .await
+        .unwrap();
+
+    assert_eq!(200, res.status);
+    let url = transport.requests()[0].url.to_string();
+    assert!(url.starts_with("https://act.ucsd.edu/webreg2/svc/wradapter/secure/get-class?"));
+    assert!(url.contains("termcode=FA23"));
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_call_rejects_absolute_url() {
+    let transport = Arc::new(InMemoryTransport::new());
+    let wrapper = wrapper(&transport);
+
+    for endpoint in [
+        "https://example.com/steal",
+        "http://act.ucsd.edu/webreg2/svc/wradapter/secure/get-class",
+        "https://act.ucsd.edu.example.com/webreg2/svc/wradapter/secure/get-class",
+    ] {
+        let res = wrapper
+            .req("FA23")
+            .raw()
+            .call(endpoint, HttpMethod::Get, &[])
+            .await;
+        assert!(matches!(res, Err(WrapperError::InputError("endpoint", _))));
+    }
+
+    assert!(transport.requests().is_empty());
+}
+
+#[tokio::test(flavor = "current_thread")]
+async fn test_call_rejects_path_traversal() {
+    let transport = Arc::new(InMemoryTransport::new());
+    let wrapper = wrapper(&transport);
+
+    for endpoint in [
+        "../../other",
+        "secure/../../other",
+        "secure/%2e%2e/%2e%2e/other",
+    ] {
+        let res = wrapper
+            .req("FA23")
+            .raw()
+            .call(endpoint, HttpMethod::Post, &[])
+            .await;
+        assert!(matches!(res, Err(WrapperError::InputError("endpoint", _))));
+    }
+
+    assert!(transport.requests().is_empty());
+}
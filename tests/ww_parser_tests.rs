@@ -34,8 +34,9 @@ mod prerequisites_tests {
         let mut res = parse_prerequisites(raw_prereqs).unwrap();
         let mut expected = PrerequisiteInfo {
             course_prerequisites: vec![vec![
-                CoursePrerequisite::new("CSE 8B", "Intro to Programming 2"),
-                CoursePrerequisite::new("CSE 11", "Accel. Intro to Programming"),
+                CoursePrerequisite::new("CSE 8B", "Intro to Programming 2").with_min_grade(600),
+                CoursePrerequisite::new("CSE 11", "Accel. Intro to Programming")
+                    .with_min_grade(600),
             ]],
             exam_prerequisites: vec![],
         };
@@ -53,14 +54,14 @@ mod prerequisites_tests {
         let mut res = parse_prerequisites(raw_prereqs).unwrap();
         let mut expected = PrerequisiteInfo {
             course_prerequisites: vec![
-                vec![CoursePrerequisite::new(
-                    "CSE 12",
-                    "Basic Data Struct & OO Design",
-                )],
-                vec![CoursePrerequisite::new(
-                    "CSE 15L",
-                    "Software Tools&Techniques Lab",
-                )],
+                vec![
+                    CoursePrerequisite::new("CSE 12", "Basic Data Struct & OO Design")
+                        .with_min_grade(600),
+                ],
+                vec![
+                    CoursePrerequisite::new("CSE 15L", "Software Tools&Techniques Lab")
+                        .with_min_grade(600),
+                ],
             ],
             exam_prerequisites: vec![],
         };
@@ -79,23 +80,30 @@ mod prerequisites_tests {
         let mut expected = PrerequisiteInfo {
             course_prerequisites: vec![
                 vec![
-                    CoursePrerequisite::new("CSE 21", "Math/Algorithm&Systems Analys"),
-                    CoursePrerequisite::new("MATH 154", "Discrete Math & Graph Theory"),
-                    CoursePrerequisite::new("MATH 158", "Extremal Combinatorics/Graph"),
-                    CoursePrerequisite::new("MATH 184", "Enumerative Combinatorics"),
-                    CoursePrerequisite::new("MATH 188", "Algebraic Combinatorics"),
+                    CoursePrerequisite::new("CSE 21", "Math/Algorithm&Systems Analys")
+                        .with_min_grade(600),
+                    CoursePrerequisite::new("MATH 154", "Discrete Math & Graph Theory")
+                        .with_min_grade(600),
+                    CoursePrerequisite::new("MATH 158", "Extremal Combinatorics/Graph")
+                        .with_min_grade(600),
+                    CoursePrerequisite::new("MATH 184", "Enumerative Combinatorics")
+                        .with_min_grade(600),
+                    CoursePrerequisite::new("MATH 188", "Algebraic Combinatorics")
+                        .with_min_grade(600),
+                ],
+                vec![
+                    CoursePrerequisite::new("CSE 12", "Basic Data Struct & OO Design")
+                        .with_min_grade(600),
+                ],
+                vec![
+                    CoursePrerequisite::new("CSE 15L", "Software Tools&Techniques Lab")
+                        .with_min_grade(600),
                 ],
-                vec![CoursePrerequisite::new(
-                    "CSE 12",
-                    "Basic Data Struct & OO Design",
-                )],
-                vec![CoursePrerequisite::new(
-                    "CSE 15L",
-                    "Software Tools&Techniques Lab",
-                )],
                 vec![
-                    CoursePrerequisite::new("CSE 30", "Computer Organiz&Systms Progrm"),
-                    CoursePrerequisite::new("ECE 15", "Engineering Computation"),
+                    CoursePrerequisite::new("CSE 30", "Computer Organiz&Systms Progrm")
+                        .with_min_grade(600),
+                    CoursePrerequisite::new("ECE 15", "Engineering Computation")
+                        .with_min_grade(600),
                 ],
             ],
             exam_prerequisites: vec![],
@@ -116,7 +124,8 @@ mod prerequisites_tests {
             course_prerequisites: vec![vec![CoursePrerequisite::new(
                 "MATH 20B",
                 "Calculus/Science & Engineering",
-            )]],
+            )
+            .with_min_grade(450)]],
             exam_prerequisites: vec!["AP-Math BC".into()],
         };
 
@@ -149,7 +158,7 @@ mod prerequisites_tests {
 #[cfg(test)]
 mod schedule_tests {
     use webweg::raw_types::RawScheduledMeeting;
-    use webweg::types::{EnrollmentStatus, Meeting, MeetingDay, ScheduledSection};
+    use webweg::types::{EnrollmentStatus, Meeting, MeetingDay, ScheduledSection, SectionId};
     use webweg::ww_parser::parse_schedule;
 
     /// Sorts the schedule objects so that we can check equality without needing to use
@@ -161,7 +170,7 @@ mod schedule_tests {
     /// # Parameters
     /// - `sch`: The scheduled section objects.
     fn sort_schedules(sch: &mut [ScheduledSection]) {
-        sch.sort_unstable_by(|a, b| a.section_id.cmp(&b.section_id));
+        sch.sort_unstable_by_key(|a| a.section_id);
         sch.iter_mut().for_each(|s| {
             s.meetings
                 .sort_unstable_by(|a, b| a.meeting_type.cmp(&b.meeting_type))
@@ -175,7 +184,7 @@ mod schedule_tests {
 
         let res = parse_schedule(raw_schedule).unwrap();
         let expected = vec![ScheduledSection {
-            section_id: "290181".into(),
+            section_id: SectionId::new(290181),
             subject_code: "CSE".into(),
             course_code: "199".into(),
             course_title: "Independent Study".into(),
@@ -212,7 +221,7 @@ mod schedule_tests {
         let mut res = parse_schedule(raw_schedule).unwrap();
         let mut expected = vec![
             ScheduledSection {
-                section_id: "185826".into(),
+                section_id: SectionId::new(185826),
                 subject_code: "HILA".into(),
                 course_code: "102".into(),
                 course_title: "Latin America/Twentieth Centry".into(),
@@ -284,7 +293,7 @@ mod schedule_tests {
                 ],
             },
             ScheduledSection {
-                section_id: "184959".into(),
+                section_id: SectionId::new(184959),
                 subject_code: "COGS".into(),
                 course_code: "118B".into(),
                 course_title: "Intro to Machine Learning".into(),
@@ -366,7 +375,7 @@ mod schedule_tests {
 #[cfg(test)]
 mod course_info_tests {
     use webweg::raw_types::RawWebRegMeeting;
-    use webweg::types::{CourseSection, Meeting, MeetingDay};
+    use webweg::types::{CourseSection, Meeting, MeetingDay, SectionId};
     use webweg::ww_parser::parse_course_info;
 
     /// Sorts the course section objects so that we can check equality without needing to use
@@ -378,7 +387,7 @@ mod course_info_tests {
     /// # Parameters
     /// - `sch`: The course section objects.
     fn sort_course_sections(sections: &mut [CourseSection]) {
-        sections.sort_unstable_by(|a, b| a.section_id.cmp(&b.section_id));
+        sections.sort_unstable_by_key(|a| a.section_id);
         sections.iter_mut().for_each(|s| {
             s.meetings
                 .sort_unstable_by(|a, b| a.meeting_type.cmp(&b.meeting_type))
@@ -393,7 +402,7 @@ mod course_info_tests {
 
         let mut expected = vec![CourseSection {
             subj_course_id: "CSE 101".into(),
-            section_id: "260739".into(),
+            section_id: SectionId::new(260739),
             section_code: "A01".into(),
             all_instructors: vec!["Bach, Quang Tran".into()],
             available_seats: 0,
@@ -474,7 +483,7 @@ mod course_info_tests {
         let mut expected = vec![
             CourseSection {
                 subj_course_id: "CSE 30".into(),
-                section_id: "260735".into(),
+                section_id: SectionId::new(260735),
                 section_code: "A01".into(),
                 all_instructors: vec!["Chin, Bryan W.".into()],
                 available_seats: 0,
@@ -531,7 +540,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "CSE 30".into(),
-                section_id: "249208".into(),
+                section_id: SectionId::new(249208),
                 section_code: "B01".into(),
                 all_instructors: vec!["Cao, Yingjun".into()],
                 available_seats: 0,
@@ -602,7 +611,7 @@ mod course_info_tests {
         let mut expected = vec![
             CourseSection {
                 subj_course_id: "MATH 100C".into(),
-                section_id: "142034".into(),
+                section_id: SectionId::new(142034),
                 section_code: "A01".into(),
                 all_instructors: vec!["Pollack, Aaron".into()],
                 available_seats: 9,
@@ -652,7 +661,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "MATH 100C".into(),
-                section_id: "254672".into(),
+                section_id: SectionId::new(254672),
                 section_code: "A03".into(),
                 all_instructors: vec!["Pollack, Aaron".into()],
                 available_seats: 12,
@@ -716,7 +725,7 @@ mod course_info_tests {
         let mut expected = vec![
             CourseSection {
                 subj_course_id: "WCWP 10A".into(),
-                section_id: "144434".into(),
+                section_id: SectionId::new(144434),
                 section_code: "001".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
@@ -738,7 +747,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "WCWP 10A".into(),
-                section_id: "144435".into(),
+                section_id: SectionId::new(144435),
                 section_code: "002".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
@@ -760,7 +769,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "WCWP 10A".into(),
-                section_id: "144437".into(),
+                section_id: SectionId::new(144437),
                 section_code: "003".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
@@ -782,7 +791,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "WCWP 10A".into(),
-                section_id: "144438".into(),
+                section_id: SectionId::new(144438),
                 section_code: "004".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
@@ -804,7 +813,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "WCWP 10A".into(),
-                section_id: "144439".into(),
+                section_id: SectionId::new(144439),
                 section_code: "005".into(),
                 all_instructors: vec!["Susi, Natalie".into()],
                 available_seats: 1,
@@ -826,7 +835,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "WCWP 10A".into(),
-                section_id: "144440".into(),
+                section_id: SectionId::new(144440),
                 section_code: "006".into(),
                 all_instructors: vec!["Gagnon, Jeffrey C".into()],
                 available_seats: 0,
@@ -848,7 +857,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "WCWP 10A".into(),
-                section_id: "144441".into(),
+                section_id: SectionId::new(144441),
                 section_code: "007".into(),
                 all_instructors: vec!["Ornelas, Tricia".into()],
                 available_seats: 0,
@@ -870,7 +879,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "WCWP 10A".into(),
-                section_id: "144442".into(),
+                section_id: SectionId::new(144442),
                 section_code: "008".into(),
                 all_instructors: vec!["Ornelas, Tricia".into()],
                 available_seats: 0,
@@ -892,7 +901,7 @@ mod course_info_tests {
             },
             CourseSection {
                 subj_course_id: "WCWP 10A".into(),
-                section_id: "144443".into(),
+                section_id: SectionId::new(144443),
                 section_code: "009".into(),
                 all_instructors: vec!["Ornelas, Tricia".into()],
                 available_seats: 0,
@@ -919,3 +928,53 @@ mod course_info_tests {
         assert_eq!(expected, res);
     }
 }
+
+#[cfg(test)]
+mod subject_department_tests {
+    use webweg::raw_types::{RawDepartmentElement, RawSubjectElement};
+    use webweg::types::{Department, Subject};
+    use webweg::ww_parser::{parse_departments, parse_subjects};
+
+    #[test]
+    pub fn test_parse_subjects() {
+        let subj_str = include_str!("json/subjects1.json");
+        let raw_subjects = serde_json::from_str::<Vec<RawSubjectElement>>(subj_str).unwrap();
+
+        let subjects = parse_subjects(raw_subjects);
+        assert_eq!(
+            vec![
+                Subject {
+                    code: "CSE".into(),
+                    description: "Computer Science & Engineering".into(),
+                },
+                Subject {
+                    code: "MATH".into(),
+                    description: "Mathematics".into(),
+                },
+            ],
+            subjects
+        );
+
+        assert_eq!(
+            Some("Mathematics"),
+            Subject::find(&subjects, "math").map(|s| s.description.as_str())
+        );
+        assert_eq!(None, Subject::find(&subjects, "ECE"));
+    }
+
+    #[test]
+    pub fn test_parse_departments() {
+        let dept_str = include_str!("json/departments1.json");
+        let raw_depts = serde_json::from_str::<Vec<RawDepartmentElement>>(dept_str).unwrap();
+
+        let departments = parse_departments(raw_depts);
+        assert_eq!(2, departments.len());
+        assert_eq!(
+            Some(&Department {
+                code: "CSE".into(),
+                description: "Computer Science & Engineering".into(),
+            }),
+            Department::find(&departments, "cse ")
+        );
+    }
+}